@@ -1,6 +1,6 @@
 use crate::core::geom::{
-    coordinate::Coordinate, line_string::LineString, multi_polygon::MultiPolygon, point::Point,
-    polygon::Polygon,
+    coordinate::Coordinate, geometry::Geometry, geometry_collection::GeometryCollection,
+    line_string::LineString, multi_polygon::MultiPolygon, point::Point, polygon::Polygon,
 };
 
 use super::orientation::Orientation;
@@ -62,6 +62,19 @@ impl Centroid {
         return cent.get_centroid();
     }
 
+    /**
+     * Computes the centroid of a line,
+     * as the average of the segment midpoints weighted by segment length.
+     * A line of zero length has the centroid of its vertices.
+     *
+     * @param line_string the line to use
+     * @return the centroid point, or null if the line is empty
+     */
+    pub fn get_centroid_from_line_string(line_string: &LineString) -> Option<Coordinate> {
+        let cent = Centroid::new_from_line_string(line_string);
+        return cent.get_centroid();
+    }
+
     pub fn get_centroid_from_polygon(polygon: &Polygon) -> Option<Coordinate> {
         let cent = Centroid::new_from_polygon(polygon);
         return cent.get_centroid();
@@ -72,6 +85,22 @@ impl Centroid {
         return cent.get_centroid();
     }
 
+    pub fn get_centroid_from_geometry_collection(gc: &GeometryCollection) -> Option<Coordinate> {
+        let cent = Centroid::new_from_geometry_collection(gc);
+        return cent.get_centroid();
+    }
+
+    /**
+     * Computes the centroid point of a geometry.
+     *
+     * @param geom the geometry to use
+     * @return the centroid point, or null if the geometry is empty
+     */
+    pub fn get_centroid_from_geometry(geom: &Geometry) -> Option<Coordinate> {
+        let cent = Centroid::new_from_geometry(geom);
+        return cent.get_centroid();
+    }
+
     pub fn default() -> Self {
         Self {
             area_base_pt: None,
@@ -108,6 +137,73 @@ impl Centroid {
         new
     }
 
+    /**
+     * Creates a new instance for computing the centroid of a geometry
+     */
+    pub fn new_from_geometry(geom: &Geometry) -> Self {
+        let mut new = Centroid::default();
+        new.add_geometry(geom);
+
+        new
+    }
+
+    /**
+     * Adds a Geometry to the centroid total.
+     *
+     * @param geom the geometry to add
+     */
+    fn add_geometry(&mut self, geom: &Geometry) {
+        if geom.is_empty() {
+            return;
+        }
+        match geom {
+            Geometry::Point(point) => {
+                if let Some(coordinate) = point.get_coordinate() {
+                    self.add_point(&coordinate);
+                }
+            }
+            Geometry::LineString(line) => self.add_line_segments(&line.get_coordinates()),
+            Geometry::LinearRing(ring) => self.add_line_segments(&ring.get_coordinates()),
+            Geometry::Polygon(poly) => self.add_polygon(poly),
+            Geometry::MultiPoint(multi_point) => {
+                for i in 0..multi_point.get_num_points() {
+                    if let Some(coordinate) = multi_point.get_coordinate(i) {
+                        self.add_point(&coordinate);
+                    }
+                }
+            }
+            Geometry::MultiLineString(multi_line) => {
+                for i in 0..multi_line.get_num_line_strings() {
+                    let line = multi_line.get_line_string_at_index(i);
+                    self.add_line_segments(&line.get_coordinates());
+                }
+            }
+            Geometry::MultiPolygon(multi_polygon) => {
+                for i in 0..multi_polygon.get_num_polygons() {
+                    self.add_polygon(&multi_polygon.get_polygon_at_index(i));
+                }
+            }
+            Geometry::GeometryCollection(gc) => {
+                for i in 0..gc.get_num_geometries() {
+                    self.add_geometry(&gc.get_geometry_n(i));
+                }
+            }
+        }
+    }
+
+    /**
+     * Creates a new instance for computing the centroid of a collection,
+     * from its elements of highest dimension.
+     */
+    pub fn new_from_geometry_collection(gc: &GeometryCollection) -> Self {
+        let mut new = Centroid::default();
+        for i in 0..gc.get_num_geometries() {
+            new.add_geometry(&gc.get_geometry_n(i));
+        }
+
+        new
+    }
+
     pub fn new_from_multi_polygon(multi_polygon: &MultiPolygon) -> Self {
         let mut new = Centroid::default();
         for i in 0..multi_polygon.get_num_polygons() {
@@ -166,9 +262,10 @@ impl Centroid {
     }
 
     pub fn add_shell(&mut self, pts: &Vec<Coordinate>) {
-        if pts.len() > 0 {
-            self.set_area_base_point(&pts[0]);
+        if pts.is_empty() {
+            return;
         }
+        self.set_area_base_point(&pts[0]);
         let is_positive_area = !Orientation::is_ccw_vec(pts);
         for i in 0..(pts.len() - 1) {
             if let Some(area_base_pt) = self.area_base_pt {
@@ -179,6 +276,9 @@ impl Centroid {
     }
 
     pub fn add_hole(&mut self, pts: &Vec<Coordinate>) {
+        if pts.is_empty() {
+            return;
+        }
         let is_positive_area = Orientation::is_ccw_vec(pts);
         for i in 0..(pts.len() - 1) {
             if let Some(area_base_pt) = self.area_base_pt {
@@ -233,7 +333,7 @@ impl Centroid {
      */
     pub fn add_line_segments(&mut self, pts: &Vec<Coordinate>) {
         let mut line_len = 0.0;
-        for i in 0..(pts.len() - 1) {
+        for i in 0..pts.len().saturating_sub(1) {
             let segment_len = pts[i].distance(&pts[i + 1]);
             if segment_len == 0.0 {
                 continue;
@@ -252,6 +352,17 @@ impl Centroid {
         }
     }
 
+    /**
+     * Adds a single line segment to the linear centroid accumulators.
+     * A zero-length segment is added as a point.
+     *
+     * @param p0 the start point of the segment
+     * @param p1 the end point of the segment
+     */
+    pub fn add_line_segment(&mut self, p0: &Coordinate, p1: &Coordinate) {
+        self.add_line_segments(&vec![*p0, *p1]);
+    }
+
     /**
      * Adds a point to the point centroid accumulator.
      * @param pt a {@link Coordinate}
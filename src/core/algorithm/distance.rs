@@ -3,6 +3,8 @@ use crate::core::{
     util::math_util::MathUtil,
 };
 
+use super::cg_algorithms_3d::CGAlgorithms3D;
+
 /**
  * Functions to compute distance between basic geometric structures.
  *
@@ -209,6 +211,25 @@ impl Distance {
         return f64::abs(s) * f64::sqrt(len2);
     }
 
+    /**
+     * Computes the signed perpendicular distance from a point p to the (infinite) line
+     * containing the points AB.
+     * The distance is positive if p is on the left of AB, and negative if it is on the right,
+     * as for {@link LineSegment#distancePerpendicularOriented(Coordinate)}.
+     * <p>
+     * Note that this is the opposite sign to JTS, whose
+     * <code>Distance.pointToLinePerpendicularSigned</code> is negative on the left.
+     * Earlier versions of this function followed JTS, so callers relying on
+     * the previous sign must negate the result.
+     *
+     * @param p
+     *          the point to compute the distance for
+     * @param A
+     *          one point of the line
+     * @param B
+     *          another point of the line (must be different to A)
+     * @return the signed distance from p to line AB
+     */
     pub fn point_to_line_perpendicular_signed(
         p: &Coordinate,
         a: &Coordinate,
@@ -225,6 +246,50 @@ impl Distance {
         let len2 = (b.x - a.x) * (b.x - a.x) + (b.y - a.y) * (b.y - a.y);
         let s = ((a.y - p.y) * (b.x - a.x) - (a.x - p.x) * (b.y - a.y)) / len2;
 
-        return s * f64::sqrt(len2);
+        // s is negative for points on the left of AB
+        return -s * f64::sqrt(len2);
+    }
+
+    /**
+     * Computes the 3D distance from a point p to a line segment AB.
+     * All three points must have a Z value;
+     * if the segment endpoints do not, the distance is NaN.
+     *
+     * @param p
+     *          the point to compute the distance for
+     * @param A
+     *          one point of the segment
+     * @param B
+     *          another point of the segment
+     * @return the 3D distance from p to segment AB
+     * @see CGAlgorithms3D#distancePointSegment(Coordinate, Coordinate, Coordinate)
+     */
+    pub fn point_to_segment_3d(p: &Coordinate, a: &Coordinate, b: &Coordinate) -> f64 {
+        return CGAlgorithms3D::distance_point_segment(p, a, b);
+    }
+
+    /**
+     * Computes the 3D distance between a line segment AB and a line segment CD.
+     * All four points must have a Z value;
+     * otherwise the distance may be NaN.
+     *
+     * @param A
+     *          the start point of the first segment
+     * @param B
+     *          the end point of the first segment
+     * @param C
+     *          the start point of the second segment
+     * @param D
+     *          the end point of the second segment
+     * @return the 3D distance between the segments
+     * @see CGAlgorithms3D#distanceSegmentSegment(Coordinate, Coordinate, Coordinate, Coordinate)
+     */
+    pub fn segment_to_segment_3d(
+        a: &Coordinate,
+        b: &Coordinate,
+        c: &Coordinate,
+        d: &Coordinate,
+    ) -> f64 {
+        return CGAlgorithms3D::distance_segment_segment(a, b, c, d);
     }
 }
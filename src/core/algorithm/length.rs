@@ -1,4 +1,6 @@
-use crate::core::geom::implementation::coordinate_array_sequence::CoordinateArraySequence;
+use crate::core::geom::{
+    coordinate::Coordinate, implementation::coordinate_array_sequence::CoordinateArraySequence,
+};
 
 /**
  * Functions for computing length.
@@ -44,4 +46,18 @@ impl Length {
         }
         return len;
     }
+
+    /**
+     * Computes the length of a linestring specified by an array of points.
+     *
+     * @param pts the points specifying the linestring
+     * @return the length of the linestring
+     */
+    pub fn of_line_vec(pts: &Vec<Coordinate>) -> f64 {
+        let mut len = 0.0;
+        for i in 1..pts.len() {
+            len += pts[i - 1].distance(&pts[i]);
+        }
+        return len;
+    }
 }
@@ -1,5 +1,8 @@
-use crate::core::geom::{
-    coordinate::Coordinate, implementation::coordinate_array_sequence::CoordinateArraySequence,
+use crate::core::{
+    geom::{
+        coordinate::Coordinate, implementation::coordinate_array_sequence::CoordinateArraySequence,
+    },
+    math::dd::DD,
 };
 
 use super::{area::Area, cg_algorithms_dd::CGAlgorithmsDD};
@@ -79,6 +82,28 @@ impl Orientation {
         //return RobustDeterminant.orientationIndex(p1, p2, q);
     }
 
+    /**
+     * Returns the orientation index of the direction of the point <code>q</code> relative to
+     * a directed infinite line specified by <code>p1-p2</code>,
+     * always computing the sign of the determinant in extended precision.
+     * <p>
+     * {@link #index} uses a fast floating-point filter and only falls back
+     * to this computation when the filter cannot determine the sign.
+     *
+     * @param p1 the origin point of the line vector
+     * @param p2 the final point of the line vector
+     * @param q the point to compute the direction to
+     *
+     * @return the orientation index of q relative to p1-p2
+     */
+    pub fn index_dd(p1: &Coordinate, p2: &Coordinate, q: &Coordinate) -> i32 {
+        let dx1 = DD::value_of_f64(p2.x).subtract_f64(p1.x);
+        let dy1 = DD::value_of_f64(p2.y).subtract_f64(p1.y);
+        let dx2 = DD::value_of_f64(q.x).subtract_f64(p1.x);
+        let dy2 = DD::value_of_f64(q.y).subtract_f64(p1.y);
+        return CGAlgorithmsDD::sign_of_det2x_2dd(&dx1, &dy1, &dx2, &dy2);
+    }
+
     /**
      * Tests if a ring defined by an array of {@link Coordinate}s is
      * oriented counter-clockwise.
@@ -209,6 +234,66 @@ impl Orientation {
         }
     }
 
+    /**
+     * Tests if a ring defined by an array of {@link Coordinate}s is
+     * oriented counter-clockwise, using the orientation of the ring
+     * at its lowest-rightmost vertex.
+     * <ul>
+     * <li>The list of points is assumed to have the first and last points equal.
+     * <li>Points repeated at the extreme vertex are skipped when finding its neighbours.
+     * <li>The ring may contain collinear segments at the extreme vertex.
+     * </ul>
+     * The lowest-rightmost vertex is always a convex vertex of a valid ring,
+     * so the orientation of the triangle formed by it and its distinct neighbours
+     * (computed robustly using DD arithmetic) gives the orientation of the ring.
+     *
+     * @param ring an array of Coordinates forming a ring (with first and last point identical)
+     * @return true if the ring is oriented counter-clockwise.
+     */
+    pub fn is_ccw_robust(ring: &Vec<Coordinate>) -> bool {
+        // # of points without closing endpoint
+        let n_pts = ring.len().saturating_sub(1);
+        // return default value if ring is flat
+        if n_pts < 3 {
+            return false;
+        }
+
+        // find the lowest point, taking the rightmost one if there are several
+        let mut i_lo = 0;
+        for i in 1..n_pts {
+            let p = &ring[i];
+            let lo = &ring[i_lo];
+            if p.y < lo.y || (p.y == lo.y && p.x > lo.x) {
+                i_lo = i;
+            }
+        }
+        let lo_pt = &ring[i_lo];
+
+        // find the nearest distinct points before and after the lowest point
+        let mut i_prev = i_lo;
+        loop {
+            i_prev = (i_prev + n_pts - 1) % n_pts;
+            if i_prev == i_lo || !ring[i_prev].equals_2d(lo_pt) {
+                break;
+            }
+        }
+        let mut i_next = i_lo;
+        loop {
+            i_next = (i_next + 1) % n_pts;
+            if i_next == i_lo || !ring[i_next].equals_2d(lo_pt) {
+                break;
+            }
+        }
+        // all points are coincident
+        if i_prev == i_lo || i_next == i_lo {
+            return false;
+        }
+
+        let index =
+            CGAlgorithmsDD::orientation_index_coordinates(&ring[i_prev], lo_pt, &ring[i_next]);
+        return index == Orientation::COUNTERCLOCKWISE;
+    }
+
     /**
      * Tests if a ring defined by an array of {@link Coordinate}s is
      * oriented counter-clockwise, using the signed area of the ring.
@@ -3,7 +3,7 @@ use crate::core::geom::{
     location::Location,
 };
 
-use super::orientation::Orientation;
+use super::{distance::Distance, orientation::Orientation};
 
 /**
  * Counts the number of segments crossed by a horizontal ray extending to the right
@@ -41,14 +41,42 @@ pub struct RayCrossingCounter {
     crossing_count: i32,
     // true if the test point lies on an input segment
     is_point_on_segment: bool,
+    // distance within which the test point is considered to lie on a segment
+    tolerance: f64,
 }
 
 impl RayCrossingCounter {
+    /**
+     * The relative distance (as a fraction of the magnitude of the ordinates)
+     * within which a point is considered to lie on a ring edge
+     * by {@link #locatePointInRingRobust}.
+     */
+    pub const ROBUST_EPSILON: f64 = 1e-12;
+
     pub fn new_with_coordinate(p: &Coordinate) -> Self {
         Self {
             p: Coordinate::from_coordinate(p),
             crossing_count: 0,
             is_point_on_segment: false,
+            tolerance: 0.,
+        }
+    }
+
+    /**
+     * Creates a counter which treats the test point as lying on a segment
+     * if it is within a given distance of it.
+     * This resolves the ambiguous crossings which occur when the point
+     * is closer to a segment than the precision of the input allows to represent.
+     *
+     * @param p the point to test
+     * @param tolerance the distance within which the point is on a segment
+     */
+    pub fn new_with_coordinate_tolerance(p: &Coordinate, tolerance: f64) -> Self {
+        Self {
+            p: Coordinate::from_coordinate(p),
+            crossing_count: 0,
+            is_point_on_segment: false,
+            tolerance,
         }
     }
 
@@ -74,6 +102,38 @@ impl RayCrossingCounter {
         return counter.get_location();
     }
 
+    /**
+     * Determines the {@link Location} of a point in a ring,
+     * reporting points which lie within {@link #ROBUST_EPSILON}
+     * (relative to the magnitude of the ordinates) of an edge
+     * as being on the {@link Location#BOUNDARY}.
+     * Such points are too close to an edge for the side they
+     * lie on to be meaningful, so this gives a consistent answer
+     * for points computed to lie on an edge.
+     *
+     * @param p the point to test
+     * @param ring an array of Coordinates forming a ring
+     * @return the location of the point in the ring
+     */
+    pub fn locate_point_in_ring_robust(p: &Coordinate, ring: &Vec<Coordinate>) -> i32 {
+        let mut max_ord = f64::max(f64::abs(p.x), f64::abs(p.y));
+        for pt in ring {
+            max_ord = f64::max(max_ord, f64::max(f64::abs(pt.x), f64::abs(pt.y)));
+        }
+        let tolerance = RayCrossingCounter::ROBUST_EPSILON * max_ord;
+        let mut counter = RayCrossingCounter::new_with_coordinate_tolerance(p, tolerance);
+
+        for i in 1..ring.len() {
+            let p1 = ring[i];
+            let p2 = ring[i - 1];
+            counter.count_segment(&p1, &p2);
+            if counter.is_on_segment() {
+                return counter.get_location();
+            }
+        }
+        return counter.get_location();
+    }
+
     /**
      * Determines the {@link Location} of a point in a ring.
      *
@@ -148,6 +208,13 @@ impl RayCrossingCounter {
         // For each segment, check if it crosses
         // a horizontal ray running from the test point in the positive x direction.
 
+        // If the point is within the tolerance distance of the segment
+        // the crossing is ambiguous, so treat the point as lying on it
+        if self.tolerance > 0. && self.is_near_segment(p1, p2) {
+            self.is_point_on_segment = true;
+            return;
+        }
+
         // check if the segment is strictly to the left of the test point
         if p1.x < self.p.x && p2.x < self.p.x {
             return;
@@ -198,6 +265,26 @@ impl RayCrossingCounter {
         }
     }
 
+    /**
+     * Tests whether the test point lies within the tolerance distance of a segment.
+     * Points exactly on the segment line are detected using the
+     * robust orientation test.
+     */
+    fn is_near_segment(&self, p1: &Coordinate, p2: &Coordinate) -> bool {
+        //-- test the expanded segment envelope first since it's faster
+        if self.p.x < f64::min(p1.x, p2.x) - self.tolerance
+            || self.p.x > f64::max(p1.x, p2.x) + self.tolerance
+            || self.p.y < f64::min(p1.y, p2.y) - self.tolerance
+            || self.p.y > f64::max(p1.y, p2.y) + self.tolerance
+        {
+            return false;
+        }
+        if Orientation::index(p1, p2, &self.p) == Orientation::COLLINEAR {
+            return true;
+        }
+        return Distance::point_to_segment(&self.p, p1, p2) <= self.tolerance;
+    }
+
     /**
      * Gets the count of crossings.
      *
@@ -63,8 +63,19 @@ impl RobustLineIntersector {
         new
     }
 
+    /**
+     * Clears the result of the last computation,
+     * so that this intersector can be reused for another pair of segments.
+     * The precision model is retained.
+     */
+    pub fn reset(&mut self) {
+        self.result = RobustLineIntersector::NO_INTERSECTION;
+        self.is_proper = false;
+    }
+
     /**
      * Force computed intersection to be rounded to a given precision model.
+     * This applies to both proper and collinear intersection points.
      * No getter is provided, because the precision model is not required to be specified.
      * @param precisionModel
      */
@@ -81,6 +92,15 @@ impl RobustLineIntersector {
         return self.result != RobustLineIntersector::NO_INTERSECTION;
     }
 
+    /**
+     * Returns the number of intersection points found.  This will be either 0, 1 or 2.
+     *
+     * @return the number of intersection points found (0, 1, or 2)
+     */
+    pub fn get_intersection_num(&self) -> i32 {
+        return self.result;
+    }
+
     /**
      * Returns the intIndex'th intersection point
      *
@@ -92,6 +112,119 @@ impl RobustLineIntersector {
         return self.int_pt[int_index];
     }
 
+    /**
+     * Computes the "edge distance" of an intersection point p along a segment.
+     * The edge distance is a metric of the point along the edge.
+     * The metric used is a robust and easy to compute metric function.
+     * It is <b>not</b> equivalent to the usual Euclidean metric.
+     * It relies on the fact that either the x or the y ordinates of the
+     * points in the edge are unique, depending on whether the edge is longer in
+     * the horizontal or vertical direction.
+     * <p>
+     * NOTE: This function may produce incorrect distances
+     *  for inputs where p is not precisely on p1-p2
+     * (E.g. p = (139,9) p1 = (139,10), p2 = (280,1) produces distance 0.0, which is incorrect.
+     * <p>
+     * My hypothesis is that the function is safe to use for points which are the
+     * result of <b>rounding</b> points which lie on the line,
+     * but not safe to use for <b>truncated</b> points.
+     */
+    pub fn compute_edge_distance(p: &Coordinate, p0: &Coordinate, p1: &Coordinate) -> f64 {
+        let dx = f64::abs(p1.x - p0.x);
+        let dy = f64::abs(p1.y - p0.y);
+
+        let mut dist;
+        if p.equals_2d(p0) {
+            dist = 0.0;
+        } else if p.equals_2d(p1) {
+            if dx > dy {
+                dist = dx;
+            } else {
+                dist = dy;
+            }
+        } else {
+            let pdx = f64::abs(p.x - p0.x);
+            let pdy = f64::abs(p.y - p0.y);
+            if dx > dy {
+                dist = pdx;
+            } else {
+                dist = pdy;
+            }
+            // <FIX>
+            // hack to ensure that non-endpoints always have a non-zero distance
+            if dist == 0.0 && !p.equals_2d(p0) {
+                dist = f64::max(pdx, pdy);
+            }
+        }
+        assert!(dist != 0.0 || p.equals_2d(p0), "Bad distance calculation");
+        return dist;
+    }
+
+    /**
+     * Computes the "edge distance" of an intersection point along the specified input line segment.
+     *
+     * @param segmentIndex is 0 or 1
+     * @param intIndex is 0 or 1
+     *
+     * @return the edge distance of the intersection point
+     */
+    pub fn get_edge_distance(&self, segment_index: usize, int_index: usize) -> f64 {
+        return RobustLineIntersector::compute_edge_distance(
+            &self.int_pt[int_index],
+            &self.input_lines[segment_index][0],
+            &self.input_lines[segment_index][1],
+        );
+    }
+
+    /**
+     * Tests whether an intersection is proper.
+     * <br>
+     * The intersection between two line segments is considered proper if
+     * they intersect in a single point in the interior of both segments
+     * (e.g. the intersection is a single point and is not equal to any of the
+     * endpoints).
+     * <p>
+     * The intersection between a point and a line segment is considered proper
+     * if the point lies in the interior of the segment (e.g. is not equal to
+     * either of the endpoints).
+     *
+     * @return true if the intersection is proper
+     */
+    pub fn is_proper(&self) -> bool {
+        return self.has_intersection() && self.is_proper;
+    }
+
+    /**
+     * Tests whether either intersection point is an interior point of one of the input segments.
+     *
+     * @return <code>true</code> if either intersection point is in the interior of one of the input segments
+     */
+    pub fn is_interior_intersection(&self) -> bool {
+        if self.is_interior_intersection_for(0) {
+            return true;
+        }
+        if self.is_interior_intersection_for(1) {
+            return true;
+        }
+        return false;
+    }
+
+    /**
+     * Tests whether either intersection point is an interior point of the specified input segment.
+     *
+     * @return <code>true</code> if either intersection point is in the interior of the input segment
+     */
+    pub fn is_interior_intersection_for(&self, input_line_index: usize) -> bool {
+        for i in 0..self.result as usize {
+            if !(self.int_pt[i].equals_2d(&self.input_lines[input_line_index][0])
+                || self.int_pt[i].equals_2d(&self.input_lines[input_line_index][1]))
+            {
+                return true;
+            }
+        }
+        return false;
+    }
+
     pub fn compute_intersection_3(&mut self, p: &Coordinate, p1: &Coordinate, p2: &Coordinate) {
         self.is_proper = false;
         // do between check first, since it is faster than the orientation test
@@ -290,6 +423,10 @@ impl RobustLineIntersector {
         return RobustLineIntersector::NO_INTERSECTION;
     }
 
+    /**
+     * Copies a collinear intersection point, with a Z value interpolated along a segment.
+     * The copy is rounded to the precision model if being used.
+     */
     pub fn copy_with_z_interpolate(
         &mut self,
         p: &Coordinate,
@@ -297,7 +434,11 @@ impl RobustLineIntersector {
         p2: &Coordinate,
     ) -> Coordinate {
         let z = self.get_z_or_interpolate(p, p1, p2);
-        return self.copy_with_z(p, z);
+        let mut p_copy = self.copy_with_z(p, z);
+        if let Some(mut precision_model) = self.precision_model {
+            precision_model.make_precise_coordinate(&mut p_copy);
+        }
+        return p_copy;
     }
 
     pub fn copy_with_z(&mut self, p: &Coordinate, z: f64) -> Coordinate {
@@ -3,25 +3,30 @@ use std::fmt;
 use crate::core::util::number_util::NumberUtil;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinate {
     /**
      * The x-ordinate.
      */
+    #[cfg_attr(feature = "serde", serde(with = "ordinate_serde"))]
     pub x: f64,
 
     /**
      * The y-ordinate.
      */
+    #[cfg_attr(feature = "serde", serde(with = "ordinate_serde"))]
     pub y: f64,
 
     /**
      * The z-ordinate.
      */
+    #[cfg_attr(feature = "serde", serde(with = "ordinate_serde"))]
     pub z: f64,
 
     /**
      * The m-ordinate.
      */
+    #[cfg_attr(feature = "serde", serde(with = "ordinate_serde"))]
     pub m: f64,
 }
 
@@ -338,6 +343,22 @@ impl Coordinate {
         }
         return true;
     }
+
+    /**
+     * Tests if the coordinate has valid X, Y and Z ordinate values.
+     * The X and Y ordinates must be finite.
+     * The Z ordinate may be NaN (indicating that it is not present),
+     * but otherwise must be finite.
+     *
+     * @return true if the coordinate is valid in 3D
+     * @see #isValid()
+     */
+    pub fn is_valid_3d(&self) -> bool {
+        if !self.is_valid() {
+            return false;
+        }
+        return f64::is_nan(self.z) || f64::is_finite(self.z);
+    }
     
     pub fn is_xy(&self) -> bool {
         !f64::is_nan(self.x) && !f64::is_nan(self.y) && f64::is_nan(self.z) && f64::is_nan(self.m)
@@ -473,17 +494,78 @@ impl Coordinate {
         f64::hypot(dx, dy)
     }
 
+    /**
+     * Computes the square of the 2-dimensional Euclidean distance to another location.
+     * The Z-ordinate is ignored.
+     * This avoids a square root when only comparing distances.
+     *
+     * @param c a point
+     * @return the square of the 2-dimensional Euclidean distance between the locations
+     */
+    pub fn distance_squared(&self, c: &Coordinate) -> f64 {
+        let dx = self.x - c.x;
+        let dy = self.y - c.y;
+        dx * dx + dy * dy
+    }
+
     /**
      * Computes the 3-dimensional Euclidean distance to another location.
+     * If either location has no Z value the distance is 2-dimensional.
      *
      * @param c a coordinate
      * @return the 3-dimensional Euclidean distance between the locations
      */
     pub fn distance_3d(&self, c: &Coordinate) -> f64 {
-        let dx = self.x - c.x;
-        let dy = self.y - c.y;
-        let dz = self.get_z() - c.get_z();
-        f64::sqrt(dx * dx + dy * dy + dz * dz)
+        f64::sqrt(self.distance_3d_squared(c))
+    }
+
+    /**
+     * Computes the square of the 3-dimensional Euclidean distance to another location.
+     * If either location has no Z value the distance is 2-dimensional.
+     *
+     * @param c a coordinate
+     * @return the square of the 3-dimensional Euclidean distance between the locations
+     */
+    pub fn distance_3d_squared(&self, c: &Coordinate) -> f64 {
+        let mut dz = self.get_z() - c.get_z();
+        if f64::is_nan(dz) {
+            dz = 0.;
+        }
+        self.distance_squared(c) + dz * dz
+    }
+
+    /**
+     * Computes the midpoint between this coordinate and another.
+     * The Z-ordinate is averaged if both coordinates have one,
+     * and is NaN otherwise.
+     *
+     * @param c a coordinate
+     * @return the midpoint of the two coordinates
+     */
+    pub fn midpoint(&self, c: &Coordinate) -> Coordinate {
+        return Coordinate::new_xyz(
+            (self.x + c.x) / 2.,
+            (self.y + c.y) / 2.,
+            (self.get_z() + c.get_z()) / 2.,
+        );
+    }
+
+    /**
+     * Computes the coordinate which lies a given fraction of the way
+     * from this coordinate to another.
+     * The Z-ordinate is interpolated if both coordinates have one,
+     * and is NaN otherwise.
+     *
+     * @param c a coordinate
+     * @param frac the fraction of the way to <code>c</code> (0 gives this coordinate, 1 gives <code>c</code>)
+     * @return the interpolated coordinate
+     */
+    pub fn interpolate(&self, c: &Coordinate, frac: f64) -> Coordinate {
+        return Coordinate::new_xyz(
+            (1. - frac) * self.x + frac * c.x,
+            (1. - frac) * self.y + frac * c.y,
+            (1. - frac) * self.get_z() + frac * c.get_z(),
+        );
     }
 }
 
@@ -492,3 +574,23 @@ impl fmt::Display for Coordinate {
         write!(f, "({}, {}, {})", self.x, self.y, self.get_z())
     }
 }
+
+/**
+ * Serializes a null (NaN) ordinate as a missing value,
+ * since formats such as JSON cannot represent NaN.
+ */
+#[cfg(feature = "serde")]
+mod ordinate_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(ordinate: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if ordinate.is_nan() {
+            return serializer.serialize_none();
+        }
+        return serializer.serialize_some(ordinate);
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        return Ok(Option::<f64>::deserialize(deserializer)?.unwrap_or(f64::NAN));
+    }
+}
@@ -246,7 +246,7 @@ impl CoordinateArraySequences {
         return CoordinateArraySequences::min_coordinate_index_sequence_from_to(
             seq,
             0,
-            seq.size() - 1,
+            seq.size().saturating_sub(1),
         );
     }
 
@@ -258,7 +258,8 @@ impl CoordinateArraySequences {
      *@param  seq   the coordinate sequence to search
      *@param  from  the lower search index
      *@param  to    the upper search index
-     *@return  the index of the minimum coordinate in the sequence, found using <code>compareTo</code>
+     *@return  the index of the minimum coordinate in the sequence, found using <code>compareTo</code>,
+     * or 0 if the range contains no coordinates
      *@see Coordinate#compareTo(Object)
      */
     pub fn min_coordinate_index_sequence_from_to(
@@ -266,6 +267,13 @@ impl CoordinateArraySequences {
         from: usize,
         to: usize,
     ) -> usize {
+        if seq.size() == 0 {
+            return 0;
+        }
+        let to = usize::min(to, seq.size() - 1);
+        if from > to {
+            return 0;
+        }
         let mut min_coord_index: i32 = -1;
         let mut min_coord: Option<Coordinate> = None;
         for i in from..=to {
@@ -1,6 +1,4 @@
-use crate::core::util::math_util::MathUtil;
-
-use super::{coordinate::Coordinate, coordinate_list::CoordinateList, coordinates::Coordinates, envelope::Envelope};
+use super::{coordinate::Coordinate, coordinate_list::CoordinateList, coordinates::Coordinates, envelope::Envelope, line_segment::LineSegment};
 
 
 
@@ -108,16 +106,15 @@ impl CoordinateArrays {
      * @return array returned, or copy created if required to enforce consistency.
      */
     pub fn enforce_consistency_dim_measures(
-        array: &Vec<Coordinate>,
+        array: &[Coordinate],
         dimension: i32,
         measures: i32,
     ) -> Vec<Coordinate> {
-        let mut copy = array.to_vec();
-        for i in 0..copy.len() {
-            let coordinate = array[i];
+        let mut copy: Vec<Coordinate> = Vec::with_capacity(array.len());
+        for coordinate in array {
             let mut duplicate = Coordinates::create_dim_measures(dimension, measures);
-            duplicate.set_coordinate(&coordinate);
-            copy[i] = duplicate;
+            duplicate.set_coordinate(coordinate);
+            copy.push(duplicate);
         }
         return copy;
     }
@@ -131,6 +128,18 @@ impl CoordinateArrays {
      * @return true if the coordinate form a ring.
      */
     pub fn is_ring(pts: &Vec<Coordinate>) -> bool {
+        return CoordinateArrays::is_ring_slice(pts);
+    }
+
+    /**
+     * Tests whether a slice of {@link Coordinate}s forms a ring,
+     * by checking length and closure.
+     * This allows testing part of an array without copying it.
+     *
+     * @param pts a slice of Coordinates
+     * @return true if the coordinate form a ring.
+     */
+    pub fn is_ring_slice(pts: &[Coordinate]) -> bool {
         if pts.len() < 4 {
             return false;
         }
@@ -168,6 +177,19 @@ impl CoordinateArrays {
      * @return an integer indicating the order
      */
     pub fn compare(pts1: &Vec<Coordinate>, pts2: &Vec<Coordinate>) -> i32 {
+        return CoordinateArrays::compare_slice(pts1, pts2);
+    }
+
+    /**
+     * Compares two {@link Coordinate} slices
+     * in the forward direction of their coordinates,
+     * using lexicographic ordering.
+     *
+     * @param pts1
+     * @param pts2
+     * @return an integer indicating the order
+     */
+    pub fn compare_slice(pts1: &[Coordinate], pts2: &[Coordinate]) -> i32 {
         let mut i = 0;
         while i < pts1.len() && i < pts2.len() {
             let compare = pts1[i].compare_to(&pts2[i]);
@@ -208,17 +230,19 @@ impl CoordinateArrays {
      * is (overall) increasing.
      * In other words, determines which end of the array is "smaller"
      * (using the standard ordering on {@link Coordinate}).
-     * Returns an integer indicating the increasing direction.
+     * Returns an integer indicating the increasing direction,
+     * which is the comparison of the first pair of points
+     * from opposite ends of the array which differ.
      * If the sequence is a palindrome, it is defined to be
      * oriented in a positive direction.
      *
      * @param pts the array of Coordinates to test
-     * @return <code>1</code> if the array is smaller at the start
-     * or is a palindrome,
-     * <code>-1</code> if smaller at the end
+     * @return <code>-1</code> if the array is smaller at the start,
+     * <code>1</code> if smaller at the end
+     * or is a palindrome
      */
     pub fn increasing_direction(pts: &Vec<Coordinate>) -> i32 {
-        for i in 0..(pts.len() / 1) {
+        for i in 0..(pts.len() / 2) {
             let j = pts.len() - 1 - i;
             // skip equal points on both ends
             let comp = pts[i].compare_to(&pts[j]);
@@ -340,12 +364,23 @@ impl CoordinateArrays {
         }
     }
 
-    //   /**
-    //    * Converts the given Collection of Coordinates into a Coordinate array.
-    //    */
-    //   public static Coordinate[] toCoordinateArray(Collection coordList) {
-    //     return (Coordinate[]) coordList.toArray(coordArrayType);
-    //   }
+    /**
+     * Converts the given Collection of Coordinates into a Coordinate array.
+     */
+    pub fn from_iter<I: IntoIterator<Item = Coordinate>>(coord_list: I) -> Vec<Coordinate> {
+        return coord_list.into_iter().collect();
+    }
+
+    /**
+     * Concatenates the given Coordinate arrays into a single array,
+     * in the order given.
+     *
+     * @param arrays the arrays to concatenate
+     * @return an array containing the coordinates of each input array
+     */
+    pub fn concatenate(arrays: &[Vec<Coordinate>]) -> Vec<Coordinate> {
+        return arrays.concat();
+    }
 
     /**
      * Tests whether {@link Coordinate#equals(Object)} returns true for any two consecutive Coordinates
@@ -355,6 +390,17 @@ impl CoordinateArrays {
      * @return true if the array has repeated points
      */
     pub fn has_repeated_points(coord: &Vec<Coordinate>) -> bool {
+        return CoordinateArrays::has_repeated_points_slice(coord);
+    }
+
+    /**
+     * Tests whether {@link Coordinate#equals(Object)} returns true for any two consecutive Coordinates
+     * in the given slice.
+     *
+     * @param coord a slice of coordinates
+     * @return true if the slice has repeated points
+     */
+    pub fn has_repeated_points_slice(coord: &[Coordinate]) -> bool {
         for i in 1..coord.len() {
             if coord[i - 1].equals_2d(&coord[i]) {
                 return true;
@@ -367,10 +413,9 @@ impl CoordinateArrays {
      * Returns either the given coordinate array if its length is greater than the
      * given amount, or an empty coordinate array.
      */
-    pub fn at_least_n_coordinates_or_nothing(n: usize, c: &Vec<Coordinate>) -> Vec<Coordinate> {
-        let copy = c.to_vec();
-        if copy.len() >= n {
-            return copy;
+    pub fn at_least_n_coordinates_or_nothing(n: usize, c: &[Coordinate]) -> Vec<Coordinate> {
+        if c.len() >= n {
+            return c.to_vec();
         } else {
             return vec![];
         }
@@ -385,15 +430,65 @@ impl CoordinateArrays {
      * @return the array with repeated coordinates removed
      * @see #hasRepeatedPoints(Coordinate[])
      */
-    pub fn remove_repeated_points(coord: &Vec<Coordinate>) -> Vec<Coordinate> {
-        let copy = coord.to_vec();
-        if !CoordinateArrays::has_repeated_points(&copy) {
-            return copy;
+    pub fn remove_repeated_points(coord: &[Coordinate]) -> Vec<Coordinate> {
+        if !CoordinateArrays::has_repeated_points_slice(coord) {
+            return coord.to_vec();
+        }
+        let mut coord_list = CoordinateList::default();
+        for pt in coord {
+            coord_list.add_coordinate_repeated(*pt, false);
         }
-        let coord_list = CoordinateList::new_with_repeated(copy, false);
         return coord_list.to_coordinate_array();
     }
 
+    /**
+     * Constructs a new array with runs of consecutive points which are
+     * within a distance tolerance of each other collapsed to the first point of the run.
+     * If the array is closed, the result is also closed.
+     *
+     * @param coord an array of coordinates
+     * @param tolerance the distance within which points are considered repeated
+     * @return the array with repeated coordinates removed
+     */
+    pub fn remove_repeated_points_tolerance(
+        coord: &Vec<Coordinate>,
+        tolerance: f64,
+    ) -> Vec<Coordinate> {
+        let mut result: Vec<Coordinate> = vec![];
+        for pt in coord {
+            if let Some(last) = result.last() {
+                if last.distance(pt) <= tolerance {
+                    continue;
+                }
+            }
+            result.push(*pt);
+        }
+
+        // preserve ring closure
+        let is_closed = coord.len() > 1 && coord[0].equals_2d(&coord[coord.len() - 1]);
+        if is_closed && result.len() > 1 {
+            let start = result[0];
+            let last = result.len() - 1;
+            if result[last].distance(&start) <= tolerance {
+                result[last] = start;
+            } else {
+                result.push(start);
+            }
+        }
+        return result;
+    }
+
+    /**
+     * Tests whether all coordinates in an array are valid.
+     *
+     * @param coord an array of coordinates
+     * @return true if every coordinate is valid
+     * @see Coordinate#isValid()
+     */
+    pub fn all_valid(coord: &Vec<Coordinate>) -> bool {
+        return coord.iter().all(|c| c.is_valid());
+    }
+
     /**
      * Tests whether an array has any repeated or invalid coordinates.
      *
@@ -424,58 +519,47 @@ impl CoordinateArrays {
      * @see Coordinate#isValid()
      */
     pub fn remove_repeated_or_invalid_points(coord: &Vec<Coordinate>) -> Vec<Coordinate> {
-        let copy = coord.to_vec();
-        if !CoordinateArrays::has_repeated_or_invalid_points(&copy) {
-            return copy;
+        if !CoordinateArrays::has_repeated_or_invalid_points(coord) {
+            return coord.to_vec();
         }
         let mut coord_list = CoordinateList::default();
-        for i in 0..copy.len() {
-            if !copy[i].is_valid() {
+        for pt in coord {
+            if !pt.is_valid() {
                 continue;
             }
-            coord_list.add_coordinate_repeated(copy[i], false);
+            coord_list.add_coordinate_repeated(*pt, false);
         }
         return coord_list.to_coordinate_array();
     }
 
-    //   /**
-    //    * Collapses a coordinate array to remove all null elements.
-    //    *
-    //    * @param coord the coordinate array to collapse
-    //    * @return an array containing only non-null elements
-    //    */
-    //   public static Coordinate[] removeNull(Coordinate[] coord) {
-    //     int nonNull = 0;
-    //     for (int i = 0; i < coord.length; i++) {
-    //       if (coord[i] != null) nonNull++;
-    //     }
-    //     Coordinate[] newCoord = new Coordinate[nonNull];
-    //     // empty case
-    //     if (nonNull == 0) return newCoord;
-
-    //     int j = 0;
-    //     for (int i = 0; i < coord.length; i++) {
-    //       if (coord[i] != null) newCoord[j++] = coord[i];
-    //     }
-    //     return newCoord;
-    //   }
+    /**
+     * Collapses a coordinate array to remove all invalid elements
+     * (those with a non-finite X or Y ordinate).
+     * This is the equivalent of removing null elements,
+     * since coordinates cannot be null.
+     * Unlike {@link #removeRepeatedOrInvalidPoints}, repeated points are kept.
+     *
+     * @param coord the coordinate array to collapse
+     * @return an array containing only valid elements
+     */
+    pub fn remove_invalid(coord: &Vec<Coordinate>) -> Vec<Coordinate> {
+        return coord.iter().filter(|pt| pt.is_valid()).copied().collect();
+    }
 
-    // /**
-    //  * Reverses the coordinates in an array in-place.
-    //  */
-    // pub fn reverse(coord: &mut Vec<Coordinate>) {
-    //     if coord.len() <= 1 {
-    //         return;
-    //     }
+    /**
+     * Reverses the coordinates in an array in-place.
+     */
+    pub fn reverse(coord: &mut [Coordinate]) {
+        if coord.len() <= 1 {
+            return;
+        }
 
-    //     let last = coord.len() - 1;
-    //     let mid = last / 2;
-    //     for i in 0..mid {
-    //         let tmp = coord[i];
-    //         coord[i] = coord[last - i];
-    //         coord[last - i] = tmp;
-    //     }
-    // }
+        let last = coord.len() - 1;
+        let mid = coord.len() / 2;
+        for i in 0..mid {
+            coord.swap(i, last - i);
+        }
+    }
 
     /**
      * Returns true if the two arrays are identical, both null, or pointwise
@@ -496,28 +580,32 @@ impl CoordinateArrays {
         return true;
     }
 
-    //   /**
-    //    * Returns true if the two arrays are identical, both null, or pointwise
-    //    * equal, using a user-defined {@link Comparator} for {@link Coordinate} s
-    //    *
-    //    * @param coord1               an array of Coordinates
-    //    * @param coord2               an array of Coordinates
-    //    * @param coordinateComparator a Comparator for Coordinates
-    //    */
-    //   pub fn equals(
-    //     coord1: Vec<Coordinate>,
-    //     coord2: Vec<Coordinate>,
-    //     Comparator coordinateComparator) -> bool {
-    //     if coord1.len() != coord2.len() {
-    //          return false;
-    //     }
+    /**
+     * Tests whether two arrays are pointwise equal,
+     * using a user-defined comparator for {@link Coordinate}s.
+     * Arrays of different lengths are not equal.
+     *
+     * @param coord1               an array of Coordinates
+     * @param coord2               an array of Coordinates
+     * @param coordinateComparator a comparator for Coordinates, returning 0 if they are equal
+     * @return true if the comparator returns 0 for every pair of corresponding coordinates
+     */
+    pub fn equals_by<F: Fn(&Coordinate, &Coordinate) -> i32>(
+        coord1: &Vec<Coordinate>,
+        coord2: &Vec<Coordinate>,
+        coordinate_comparator: F,
+    ) -> bool {
+        if coord1.len() != coord2.len() {
+            return false;
+        }
 
-    //     for i in 0..coord1.len() {
-    //       if (coordinateComparator.compare(coord1[i], coord2[i]) != 0)
-    //         return false;
-    //     }
-    //     return true;
-    //   }
+        for i in 0..coord1.len() {
+            if coordinate_comparator(&coord1[i], &coord2[i]) != 0 {
+                return false;
+            }
+        }
+        return true;
+    }
 
     /**
      * Returns the minimum coordinate, using the usual lexicographic comparison.
@@ -527,6 +615,17 @@ impl CoordinateArrays {
      * @see Coordinate#compareTo(Coordinate)
      */
     pub fn min_coordinate(coordinates: &Vec<Coordinate>) -> Option<Coordinate> {
+        return CoordinateArrays::min_coordinate_slice(coordinates);
+    }
+
+    /**
+     * Returns the minimum coordinate of a slice, using the usual lexicographic comparison.
+     *
+     * @param coordinates the slice to search
+     * @return the minimum coordinate in the slice, or None if it is empty
+     * @see Coordinate#compareTo(Coordinate)
+     */
+    pub fn min_coordinate_slice(coordinates: &[Coordinate]) -> Option<Coordinate> {
         let mut min_coord: Option<Coordinate> = None;
         for i in 0..coordinates.len() {
             if min_coord.is_none() || min_coord.unwrap().compare_to(&coordinates[i]) > 0 {
@@ -650,6 +749,47 @@ impl CoordinateArrays {
         return -1;
     }
 
+    /**
+     * Densifies a {@link Coordinate} array by inserting vertices
+     * along each segment longer than a maximum length,
+     * so that no segment of the result is longer than it.
+     * The inserted vertices are evenly spaced along the segment,
+     * with Z values interpolated from the segment endpoints.
+     *
+     * @param pts                the input array
+     * @param maxSegmentLength   the maximum length of a segment in the result
+     * @return the densified array
+     *
+     * @throws IllegalArgumentException if the maximum segment length is not positive
+     */
+    pub fn densify(pts: &Vec<Coordinate>, max_segment_length: f64) -> Vec<Coordinate> {
+        assert!(
+            max_segment_length > 0.0,
+            "Maximum segment length must be positive"
+        );
+        let mut densified: Vec<Coordinate> = vec![];
+        for i in 0..pts.len().saturating_sub(1) {
+            let seg = LineSegment::new_from_coordinates(&pts[i], &pts[i + 1]);
+            densified.push(pts[i]);
+
+            let len = seg.get_length();
+            if len <= max_segment_length {
+                continue;
+            }
+            let densified_seg_count = f64::ceil(len / max_segment_length) as usize;
+            for j in 1..densified_seg_count {
+                let seg_fract = j as f64 / densified_seg_count as f64;
+                let mut p = seg.point_along(seg_fract);
+                p.z = seg.p0.z + seg_fract * (seg.p1.z - seg.p0.z);
+                densified.push(p);
+            }
+        }
+        if let Some(last) = pts.last() {
+            densified.push(*last);
+        }
+        return densified;
+    }
+
     /**
      * Extracts a subsequence of the input {@link Coordinate} array
      * from indices <code>start</code> to
@@ -663,33 +803,15 @@ impl CoordinateArrays {
      * @param end   the index of the end of the subsequence to extract
      * @return a subsequence of the input array
      */
-    pub fn extract(pts: &Vec<Coordinate>, mut start: usize, mut end: usize) -> Vec<Coordinate> {
-        start = MathUtil::clamp_i32(start as i32, 0, pts.len() as i32) as usize;
-        end = MathUtil::clamp_i32(end as i32, -1, pts.len() as i32) as usize;
-
-        let mut npts = end - start + 1;
-        #[allow(unused_comparisons)]
-        if end < 0 {
-            npts = 0;
-        }
+    pub fn extract(pts: &Vec<Coordinate>, start: usize, end: usize) -> Vec<Coordinate> {
         if start >= pts.len() {
-            npts = 0;
+            return vec![];
         }
+        let end = usize::min(end, pts.len() - 1);
         if end < start {
-            npts = 0;
-        }
-
-        let mut extract_pts: Vec<Coordinate> = vec![];
-        if npts == 0 {
-            return extract_pts;
-        }
-
-        let mut i_pts = 0;
-        for i in start..end {
-            i_pts = i_pts + 1;
-            extract_pts[i_pts] = pts[i];
+            return vec![];
         }
-        return extract_pts;
+        return pts[start..=end].to_vec();
     }
 
     /**
@@ -699,6 +821,17 @@ impl CoordinateArrays {
      * @return the envelope of the coordinates
      */
     pub fn envelope(coordinates: &Vec<Coordinate>) -> Envelope {
+        return CoordinateArrays::envelope_slice(coordinates);
+    }
+
+    /**
+     * Computes the envelope of a slice of coordinates.
+     * This allows computing the envelope of part of an array without copying it.
+     *
+     * @param coordinates the coordinates to scan
+     * @return the envelope of the coordinates
+     */
+    pub fn envelope_slice(coordinates: &[Coordinate]) -> Envelope {
         let mut env = Envelope::default();
         for i in 0..coordinates.len() {
             env.expand_to_include_coordinate(&coordinates[i]);
@@ -722,4 +855,17 @@ impl CoordinateArrays {
         }
         return coord_list.to_coordinate_array();
     }
+
+    /**
+     * Finds the indices of the coordinates which intersect an {@link Envelope}.
+     *
+     * @param coordinates the coordinates to scan
+     * @param env         the envelope to intersect with
+     * @return the indices of the coordinates which intersect the envelope, in increasing order
+     */
+    pub fn indices_intersecting(coordinates: &Vec<Coordinate>, env: &Envelope) -> Vec<usize> {
+        return (0..coordinates.len())
+            .filter(|&i| env.intersects_coordinate(&coordinates[i]))
+            .collect();
+    }
 }
@@ -0,0 +1,25 @@
+use super::coordinate::Coordinate;
+
+/**
+ *  An interface for classes which use the values of the coordinates in a {@link Geometry}.
+ * Coordinate filters can be used to implement centroid and
+ * envelope computation, and many other functions.
+ * <p>
+ * <code>CoordinateFilter</code> is
+ * an example of the Gang-of-Four Visitor pattern.
+ * <p>
+ * A filter may modify the coordinates it is passed,
+ * for instance to translate or round a geometry.
+ * Geometries which apply the filter recompute their envelope
+ * if any coordinate is changed.
+ *
+ *@version 1.7
+ */
+pub trait CoordinateFilter {
+    /**
+     *  Performs an operation with the provided <code>coord</code>.
+     *
+     *@param  coord  a <code>Coordinate</code> to which the filter is applied.
+     */
+    fn filter(&mut self, coord: &mut Coordinate);
+}
@@ -1,8 +1,13 @@
-use std::fmt;
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
 
 use super::coordinate::Coordinate;
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Envelope {
     /**
      *  the minimum x-coordinate
@@ -379,6 +384,38 @@ impl Envelope {
         return h;
     }
 
+    /**
+     * Gets the ratio of the width to the height of this envelope.
+     *
+     * @return the aspect ratio of this envelope, or NaN if it is null
+     */
+    pub fn aspect_ratio(&self) -> f64 {
+        if self.is_null() {
+            return f64::NAN;
+        }
+        return self.get_width() / self.get_height();
+    }
+
+    /**
+     * Creates the square envelope with the same centre as this envelope
+     * and sides equal to its maximum extent.
+     *
+     * @return the square envelope containing this envelope,
+     * or a null envelope if this envelope is null
+     */
+    pub fn to_square(self) -> Envelope {
+        let Some(centre) = self.centre() else {
+            return Envelope::default();
+        };
+        let half = self.max_extent() / 2.;
+        return Envelope::new_xy(
+            centre.x - half,
+            centre.x + half,
+            centre.y - half,
+            centre.y + half,
+        );
+    }
+
     /**
      *  Enlarges this <code>Envelope</code> so that it contains
      *  the given {@link Coordinate}.
@@ -453,6 +490,38 @@ impl Envelope {
         }
     }
 
+    /**
+     * Computes a copy of this envelope expanded by a given distance in all directions.
+     * Both positive and negative distances are supported.
+     * The copy of a null envelope is null,
+     * and so is the copy of an envelope which a negative distance collapses.
+     *
+     * @param distance the distance to expand the envelope
+     * @return the expanded envelope
+     */
+    pub fn buffer(&self, distance: f64) -> Envelope {
+        let mut env = *self;
+        env.expand_by(distance);
+        return env;
+    }
+
+    /**
+     * Computes a copy of this envelope expanded by given distances
+     * along the X and Y axes.
+     * Both positive and negative distances are supported.
+     * The copy of a null envelope is null,
+     * and so is the copy of an envelope which a negative distance collapses.
+     *
+     * @param deltaX the distance to expand the envelope along the the X axis
+     * @param deltaY the distance to expand the envelope along the the Y axis
+     * @return the expanded envelope
+     */
+    pub fn buffer_xy(&self, delta_x: f64, delta_y: f64) -> Envelope {
+        let mut env = *self;
+        env.expand_by_delta_xy(delta_x, delta_y);
+        return env;
+    }
+
     /**
      *  Enlarges this <code>Envelope</code> so that it contains
      *  the given point.
@@ -575,6 +644,24 @@ impl Envelope {
         ));
     }
 
+    /**
+     * Splits this envelope into four quadrants around its centre.
+     * The quadrants share their edges, so they do not overlap
+     * and their union is this envelope.
+     *
+     * @return the SW, SE, NW and NE quadrants of this envelope,
+     * or <code>None</code> if the envelope is null
+     */
+    pub fn split_quadrants(&self) -> Option<[Envelope; 4]> {
+        let centre = self.centre()?;
+        return Some([
+            Envelope::new_xy(self.minx, centre.x, self.miny, centre.y),
+            Envelope::new_xy(centre.x, self.maxx, self.miny, centre.y),
+            Envelope::new_xy(self.minx, centre.x, centre.y, self.maxy),
+            Envelope::new_xy(centre.x, self.maxx, centre.y, self.maxy),
+        ]);
+    }
+
     /**
      * Computes the intersection of two {@link Envelope}s.
      *
@@ -610,6 +697,22 @@ impl Envelope {
         return Envelope::new_xy(int_min_x, int_max_x, int_min_y, int_max_y);
     }
 
+    /**
+     * Computes the intersection of two {@link Envelope}s.
+     * Envelopes which only touch have a zero-area intersection,
+     * which is still returned.
+     *
+     * @param env the envelope to intersect with
+     * @return the intersection of the envelopes,
+     * or <code>None</code> if either is null or they do not intersect
+     */
+    pub fn try_intersection(&self, env: &Envelope) -> Option<Envelope> {
+        if !self.intersects_envelope(env) {
+            return None;
+        }
+        return Some(self.intersection_envelope(env));
+    }
+
     /**
      * Tests if the region defined by <code>other</code>
      * intersects the region of this <code>Envelope</code>.
@@ -859,9 +962,10 @@ impl Envelope {
      * <code>Envelope</code>.
      * The distance between overlapping Envelopes is 0.  Otherwise, the
      * distance is the Euclidean distance between the closest points.
+     * The distance to a null envelope is 0.
      */
     pub fn distance_envelope(&self, env: &Envelope) -> f64 {
-        if self.intersects_envelope(env) {
+        if self.is_null() || env.is_null() || self.intersects_envelope(env) {
             return 0.;
         }
 
@@ -889,6 +993,41 @@ impl Envelope {
         return f64::hypot(dx, dy);
     }
 
+    /**
+     * Computes the maximum distance between any point in this
+     * <code>Envelope</code> and any point in another.
+     * This is the distance between the farthest pair of corners.
+     * The maximum distance to a null envelope is 0.
+     */
+    pub fn max_distance(&self, env: &Envelope) -> f64 {
+        if self.is_null() || env.is_null() {
+            return 0.;
+        }
+        let dx = f64::max(self.maxx - env.minx, env.maxx - self.minx);
+        let dy = f64::max(self.maxy - env.miny, env.maxy - self.miny);
+        return f64::hypot(dx, dy);
+    }
+
+    /**
+     * Computes the distance between this <code>Envelope</code>
+     * and a point.
+     * The distance to a point lying inside or on the boundary of the envelope is 0.
+     * Otherwise, the distance is the Euclidean distance to the closest point
+     * of the envelope.
+     * The distance to a null envelope is 0.
+     *
+     * @param p the point to compute the distance to
+     * @return the distance between the envelope and the point
+     */
+    pub fn distance_to_coordinate(&self, p: &Coordinate) -> f64 {
+        if self.is_null() {
+            return 0.;
+        }
+        let dx = f64::max(f64::max(self.minx - p.x, p.x - self.maxx), 0.0);
+        let dy = f64::max(f64::max(self.miny - p.y, p.y - self.maxy), 0.0);
+        return f64::hypot(dx, dy);
+    }
+
     pub fn equals(&self, other: &Envelope) -> bool {
         if self.is_null() {
             return other.is_null();
@@ -899,6 +1038,26 @@ impl Envelope {
             && self.miny == other.get_min_y();
     }
 
+    /**
+     * Tests if two envelopes are equal up to a tolerance.
+     * Two non-null envelopes are equal if each of their ordinates
+     * differ by no more than the tolerance.
+     * Two null envelopes are equal.
+     *
+     * @param other the envelope to compare to
+     * @param tolerance the maximum difference allowed between ordinates
+     * @return true if the envelopes are equal within the tolerance
+     */
+    pub fn equals_with_tolerance(&self, other: &Envelope, tolerance: f64) -> bool {
+        if self.is_null() || other.is_null() {
+            return self.is_null() && other.is_null();
+        }
+        return (self.maxx - other.maxx).abs() <= tolerance
+            && (self.maxy - other.maxy).abs() <= tolerance
+            && (self.minx - other.minx).abs() <= tolerance
+            && (self.miny - other.miny).abs() <= tolerance;
+    }
+
     /**
      * Compares two envelopes using lexicographic ordering.
      * The ordering comparison is based on the usual numerical
@@ -957,3 +1116,57 @@ impl fmt::Display for Envelope {
         )
     }
 }
+
+impl PartialEq for Envelope {
+    /**
+     * Envelopes are equal if they have the same ordinates,
+     * or are both null.
+     */
+    fn eq(&self, other: &Self) -> bool {
+        return self.equals(other);
+    }
+}
+
+/**
+ * Envelopes with NaN ordinates are not equal to themselves,
+ * so they must not be used as keys.
+ */
+impl Eq for Envelope {}
+
+impl PartialOrd for Envelope {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for Envelope {
+    /**
+     * Orders envelopes lexicographically by their ordinates,
+     * with null envelopes before all non-null envelopes.
+     * <p>
+     * An envelope with NaN ordinates compares as equal to itself,
+     * although it is not equal to itself under <code>==</code>,
+     * so such envelopes must not be sorted and deduplicated
+     * or stored in ordered collections.
+     *
+     * @see #compare_to_envelope
+     */
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self.compare_to_envelope(other).cmp(&0);
+    }
+}
+
+impl Hash for Envelope {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // all null envelopes are equal, so they hash to the same value
+        if self.is_null() {
+            state.write_u8(0);
+            return;
+        }
+        state.write_u8(1);
+        for ordinate in [self.minx, self.maxx, self.miny, self.maxy] {
+            // adding 0.0 turns -0.0 into 0.0, since they are equal
+            (ordinate + 0.0).to_bits().hash(state);
+        }
+    }
+}
@@ -1,4 +1,15 @@
-use super::{coordinate::Coordinate, envelope::Envelope, geometry_factory::GeometryFactory};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use crate::core::algorithm::centroid::Centroid;
+
+use super::{
+    coordinate::Coordinate, envelope::Envelope, geometry_collection::GeometryCollection,
+    geometry_factory::GeometryFactory, line_string::LineString, linear_ring::LinearRing, multi_line_string::MultiLineString,
+    multi_point::MultiPoint, multi_polygon::MultiPolygon, point::Point, polygon::Polygon,
+};
 
 
 /**
@@ -118,22 +129,16 @@ use super::{coordinate::Coordinate, envelope::Envelope, geometry_factory::Geomet
  *@version 1.7
  */
 
-#[derive(Clone, Copy)]
-pub struct Geometry {
-    /**
-     *  The bounding box of this <code>Geometry</code>.
-     */
-    envelope: Envelope,
-
-    /**
-     * The {@link GeometryFactory} used to create this Geometry
-     */
-    factory: GeometryFactory,
-
-    /**
-     *  The ID of the Spatial Reference System used by this <code>Geometry</code>
-     */
-    srid: i32,
+#[derive(Clone)]
+pub enum Geometry {
+    Point(Point),
+    LineString(LineString),
+    LinearRing(LinearRing),
+    Polygon(Polygon),
+    MultiPoint(MultiPoint),
+    MultiLineString(MultiLineString),
+    MultiPolygon(MultiPolygon),
+    GeometryCollection(GeometryCollection),
 }
 
 impl Geometry {
@@ -155,6 +160,657 @@ impl Geometry {
     pub const TYPENAME_MULTIPOLYGON: &'static str = "MultiPolygon";
     pub const TYPENAME_GEOMETRYCOLLECTION: &'static str = "GeometryCollection";
 
+    /**
+     * Gets the code identifying the class of this geometry.
+     * The codes give the ordering used by {@link #compareTo(Object)}.
+     *
+     * @return the type code of this geometry
+     */
+    pub fn get_type_code(&self) -> i32 {
+        return match self {
+            Geometry::Point(_) => Point::get_type_code(),
+            Geometry::LineString(_) => LineString::get_type_code(),
+            Geometry::LinearRing(g) => g.get_type_code(),
+            Geometry::Polygon(_) => Polygon::get_type_code(),
+            Geometry::MultiPoint(_) => MultiPoint::get_type_code(),
+            Geometry::MultiLineString(_) => MultiLineString::get_type_code(),
+            Geometry::MultiPolygon(g) => g.get_type_code(),
+            Geometry::GeometryCollection(_) => GeometryCollection::get_type_code(),
+        };
+    }
+
+    /**
+     * Returns the name of this Geometry's actual class.
+     *
+     *@return the name of this <code>Geometry</code>s actual class
+     */
+    pub fn get_geometry_type(&self) -> String {
+        return match self {
+            Geometry::Point(g) => g.get_geometry_type().to_owned(),
+            Geometry::LineString(g) => g.get_geometry_type(),
+            Geometry::LinearRing(g) => g.get_geometry_type(),
+            Geometry::Polygon(g) => g.get_geometry_type(),
+            Geometry::MultiPoint(g) => g.get_geometry_type(),
+            Geometry::MultiLineString(g) => g.get_geometry_type(),
+            Geometry::MultiPolygon(g) => g.get_geometry_type(),
+            Geometry::GeometryCollection(g) => g.get_geometry_type(),
+        };
+    }
+
+    /**
+     * Tests whether the set of points covered by this <code>Geometry</code> is
+     * empty.
+     *
+     *@return <code>true</code> if this <code>Geometry</code> does not cover any points
+     */
+    pub fn is_empty(&self) -> bool {
+        return match self {
+            Geometry::Point(g) => g.is_empty(),
+            Geometry::LineString(g) => g.is_empty(),
+            Geometry::LinearRing(g) => g.is_empty(),
+            Geometry::Polygon(g) => g.is_empty(),
+            Geometry::MultiPoint(g) => g.is_empty(),
+            Geometry::MultiLineString(g) => g.is_empty(),
+            Geometry::MultiPolygon(g) => g.is_empty(),
+            Geometry::GeometryCollection(g) => g.is_empty(),
+        };
+    }
+
+    /**
+     *  Returns the area of this <code>Geometry</code>.
+     *  Areal Geometries have a non-zero area.
+     *  Others return 0.0
+     *
+     *@return the area of the Geometry
+     */
+    pub fn get_area(&self) -> f64 {
+        return match self {
+            Geometry::Polygon(g) => g.get_area(),
+            Geometry::Point(_)
+            | Geometry::LineString(_)
+            | Geometry::LinearRing(_)
+            | Geometry::MultiPoint(_) => 0.0,
+            _ => self
+                .get_members()
+                .unwrap_or_default()
+                .iter()
+                .map(|member| member.get_area())
+                .sum(),
+        };
+    }
+
+    /**
+     *  Returns the length of this <code>Geometry</code>.
+     *  Linear geometries return their length.
+     *  Areal geometries return their perimeter.
+     *  Others return 0.0
+     *
+     *@return the length of the Geometry
+     */
+    pub fn get_length(&self) -> f64 {
+        return match self {
+            Geometry::LineString(g) => g.get_length(),
+            Geometry::LinearRing(g) => g.get_length(),
+            Geometry::Polygon(g) => g.get_length(),
+            Geometry::Point(_) | Geometry::MultiPoint(_) => 0.0,
+            _ => self
+                .get_members()
+                .unwrap_or_default()
+                .iter()
+                .map(|member| member.get_length())
+                .sum(),
+        };
+    }
+
+    /**
+     * Computes the centroid of this <code>Geometry</code>.
+     * The centroid
+     * is equal to the centroid of the set of component Geometries of highest
+     * dimension (since the lower-dimension geometries contribute zero
+     * "weight" to the centroid).
+     * <p>
+     * The centroid of an empty geometry is <code>POINT EMPTY</code>.
+     *
+     * @return a {@link Point} which is the centroid of this Geometry
+     */
+    pub fn get_centroid(&self) -> Point {
+        return match Centroid::get_centroid_from_geometry(self) {
+            Some(cent_pt) => GeometryFactory::create_point_from_coordinate(&cent_pt),
+            None => Point::default(),
+        };
+    }
+
+    /**
+     * Computes the {@link Envelope} of this geometry,
+     * without using or updating the envelopes cached by its components.
+     *
+     *@return the envelope of this <code>Geometry</code>,
+     * or a null Envelope if this Geometry is empty
+     */
+    pub fn compute_envelope_internal(&self) -> Envelope {
+        return match self {
+            Geometry::Point(g) => g.compute_envelope_internal(),
+            Geometry::LineString(g) => g.compute_envelope_internal(),
+            Geometry::LinearRing(g) => g.compute_envelope_internal(),
+            Geometry::Polygon(g) => g.get_exterior_ring().compute_envelope_internal(),
+            _ => {
+                let mut env = Envelope::default();
+                for member in self.get_members().unwrap_or_default() {
+                    env.expand_to_include_envelope(&member.compute_envelope_internal());
+                }
+                env
+            }
+        };
+    }
+
+    /**
+     * Gets the elements of this geometry if it is a collection.
+     *
+     * @return the member geometries, or None if this is not a collection
+     */
+    fn get_members(&self) -> Option<Vec<Geometry>> {
+        return match self {
+            Geometry::MultiPoint(g) => Some(
+                (0..g.get_num_points())
+                    .filter_map(|i| g.get_point_at_index(i))
+                    .map(Geometry::Point)
+                    .collect(),
+            ),
+            Geometry::MultiLineString(g) => Some(
+                (0..g.get_num_line_strings())
+                    .map(|i| Geometry::LineString(g.get_line_string_at_index(i)))
+                    .collect(),
+            ),
+            Geometry::MultiPolygon(g) => Some(
+                (0..g.get_num_polygons())
+                    .map(|i| Geometry::Polygon(g.get_polygon_at_index(i)))
+                    .collect(),
+            ),
+            Geometry::GeometryCollection(g) => Some(
+                (0..g.get_num_geometries())
+                    .map(|i| g.get_geometry_n(i))
+                    .collect(),
+            ),
+            _ => None,
+        };
+    }
+
+    /**
+     * Tests whether the envelope of this geometry intersects the envelope
+     * of another geometry.
+     * For collections the envelopes of the individual elements are tested,
+     * so this returns <code>true</code> only if the envelopes of some
+     * element of each geometry intersect.
+     * This is a cheap test which can be used to filter geometries
+     * before computing an exact spatial predicate.
+     * The element envelopes are computed once and cached in the elements,
+     * so repeated tests against the same geometries do not rescan their coordinates.
+     *
+     *@param  other  the <code>Geometry</code> with which to compare this <code>Geometry</code>
+     *@return        <code>true</code> if some element envelopes of the two geometries intersect
+     */
+    pub fn envelope_intersects(&mut self, other: &mut Geometry) -> bool {
+        let mut other_envelopes: Vec<Envelope> = vec![];
+        other.expand_element_envelopes(&mut other_envelopes);
+        let mut other_envelope = Envelope::default();
+        for env in other_envelopes.iter() {
+            other_envelope.expand_to_include_envelope(env);
+        }
+        let mut envelopes: Vec<Envelope> = vec![];
+        self.expand_element_envelopes(&mut envelopes);
+        return envelopes.iter().any(|env| {
+            env.intersects_envelope(&other_envelope)
+                && other_envelopes.iter().any(|o| env.intersects_envelope(o))
+        });
+    }
+
+    /**
+     * Adds the cached envelopes of the non-collection elements of this geometry to a list.
+     */
+    pub(crate) fn expand_element_envelopes(&mut self, envelopes: &mut Vec<Envelope>) {
+        match self {
+            Geometry::Point(g) => envelopes.push(g.get_envelope_internal()),
+            Geometry::LineString(g) => envelopes.push(g.get_envelope_internal()),
+            Geometry::LinearRing(g) => envelopes.push(g.get_envelope_internal()),
+            Geometry::Polygon(g) => envelopes.push(g.get_envelope_internal()),
+            Geometry::MultiPoint(g) => g.expand_element_envelopes(envelopes),
+            Geometry::MultiLineString(g) => g.expand_element_envelopes(envelopes),
+            Geometry::MultiPolygon(g) => g.expand_element_envelopes(envelopes),
+            Geometry::GeometryCollection(g) => g.expand_element_envelopes(envelopes),
+        }
+    }
+
+    /**
+     * Extracts the {@link Polygon} elements from this geometry,
+     * recursing through any collections.
+     *
+     * @return a list of the Polygon components of this geometry
+     */
+    pub fn extract_polygons(&self) -> Vec<Polygon> {
+        let mut polygons: Vec<Polygon> = vec![];
+        self.extract_polygons_into(&mut polygons);
+        return polygons;
+    }
+
+    fn extract_polygons_into(&self, polygons: &mut Vec<Polygon>) {
+        match self {
+            Geometry::Polygon(g) => polygons.push(g.copy()),
+            _ => {
+                for member in self.get_members().unwrap_or_default() {
+                    member.extract_polygons_into(polygons);
+                }
+            }
+        }
+    }
+
+    /**
+     * Extracts the linear elements from this geometry,
+     * recursing through any collections.
+     * {@link LinearRing}s are returned as {@link LineString}s.
+     * The rings of polygons are not extracted.
+     *
+     * @return a list of the LineString components of this geometry
+     */
+    pub fn extract_line_strings(&self) -> Vec<LineString> {
+        let mut lines: Vec<LineString> = vec![];
+        self.extract_line_strings_into(&mut lines);
+        return lines;
+    }
+
+    fn extract_line_strings_into(&self, lines: &mut Vec<LineString>) {
+        match self {
+            Geometry::LineString(g) => lines.push(g.copy()),
+            Geometry::LinearRing(g) => lines.push(GeometryFactory::create_line_string_coordinates(
+                &g.get_coordinates(),
+            )),
+            _ => {
+                for member in self.get_members().unwrap_or_default() {
+                    member.extract_line_strings_into(lines);
+                }
+            }
+        }
+    }
+
+    /**
+     * Extracts the {@link Point} elements from this geometry,
+     * recursing through any collections.
+     *
+     * @return a list of the Point components of this geometry
+     */
+    pub fn extract_points(&self) -> Vec<Point> {
+        let mut points: Vec<Point> = vec![];
+        self.extract_points_into(&mut points);
+        return points;
+    }
+
+    fn extract_points_into(&self, points: &mut Vec<Point>) {
+        match self {
+            Geometry::Point(g) => points.push(g.copy()),
+            _ => {
+                for member in self.get_members().unwrap_or_default() {
+                    member.extract_points_into(points);
+                }
+            }
+        }
+    }
+
+    /**
+     * Reduces the number of vertices in each linear component of this geometry
+     * by sampling vertices at uniform index intervals.
+     * The endpoints of lines are kept, and rings remain closed.
+     * No line or ring in the result has more than <code>maxPoints</code> vertices,
+     * except that lines always keep at least 2 vertices and rings at least 4.
+     * Components which already have few enough vertices are unchanged.
+     * <p>
+     * Unlike simplification this does not consider the shape of the geometry,
+     * so it is fast and produces a predictable number of vertices.
+     * It is intended for reducing geometries for display at small scales.
+     * The result may not be valid.
+     *
+     * @param maxPoints the maximum number of vertices in each component
+     * @return the decimated geometry
+     */
+    pub fn decimate_to(&self, max_points: usize) -> Geometry {
+        return match self {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => self.copy(),
+            Geometry::LineString(g) => {
+                Geometry::LineString(GeometryFactory::create_line_string_coordinates(
+                    &Geometry::decimate_coordinates(&g.get_coordinates(), max_points, false),
+                ))
+            }
+            Geometry::LinearRing(g) => Geometry::LinearRing(Geometry::decimate_ring(g, max_points)),
+            Geometry::Polygon(g) => Geometry::Polygon(Geometry::decimate_polygon(g, max_points)),
+            Geometry::MultiLineString(g) => {
+                let lines: Vec<LineString> = (0..g.get_num_line_strings())
+                    .map(|i| {
+                        GeometryFactory::create_line_string_coordinates(
+                            &Geometry::decimate_coordinates(
+                                &g.get_line_string_at_index(i).get_coordinates(),
+                                max_points,
+                                false,
+                            ),
+                        )
+                    })
+                    .collect();
+                Geometry::MultiLineString(MultiLineString::new_with_line_strings(&lines))
+            }
+            Geometry::MultiPolygon(g) => {
+                let polygons: Vec<Polygon> = (0..g.get_num_polygons())
+                    .map(|i| Geometry::decimate_polygon(&g.get_polygon_at_index(i), max_points))
+                    .collect();
+                Geometry::MultiPolygon(GeometryFactory::create_multi_polygon(&polygons))
+            }
+            Geometry::GeometryCollection(g) => {
+                let geometries: Vec<Geometry> = (0..g.get_num_geometries())
+                    .map(|i| g.get_geometry_n(i).decimate_to(max_points))
+                    .collect();
+                Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&geometries))
+            }
+        };
+    }
+
+    fn decimate_polygon(polygon: &Polygon, max_points: usize) -> Polygon {
+        if polygon.is_empty() {
+            return polygon.copy();
+        }
+        let shell = Geometry::decimate_ring(&polygon.get_exterior_ring(), max_points);
+        let holes: Vec<LinearRing> = (0..polygon.get_num_interior_ring())
+            .map(|i| Geometry::decimate_ring(&polygon.get_interior_ring_n(i), max_points))
+            .collect();
+        return GeometryFactory::create_polygon_with_linear_ring_vec(&shell, &holes);
+    }
+
+    fn decimate_ring(ring: &LinearRing, max_points: usize) -> LinearRing {
+        return GeometryFactory::create_linear_ring_with_coordinates(
+            &Geometry::decimate_coordinates(&ring.get_coordinates(), max_points, true),
+        );
+    }
+
+    /**
+     * Samples a list of vertices at uniform index intervals,
+     * keeping the first and last vertex.
+     * For a ring the closing vertex is not sampled,
+     * but is added to close the result.
+     */
+    fn decimate_coordinates(
+        coords: &[Coordinate],
+        max_points: usize,
+        is_ring: bool,
+    ) -> Vec<Coordinate> {
+        let min_points = if is_ring { 4 } else { 2 };
+        let max_points = usize::max(max_points, min_points);
+        if coords.len() <= max_points {
+            return coords.to_vec();
+        }
+        let mut decimated: Vec<Coordinate>;
+        if is_ring {
+            // sample the distinct vertices, then close the ring
+            let num_distinct = coords.len() - 1;
+            let num_samples = max_points - 1;
+            decimated = (0..num_samples)
+                .map(|i| coords[i * num_distinct / num_samples])
+                .collect();
+            decimated.push(coords[0]);
+        } else {
+            let last = coords.len() - 1;
+            decimated = (0..max_points)
+                .map(|i| coords[i * last / (max_points - 1)])
+                .collect();
+        }
+        return decimated;
+    }
+
+    /**
+     *  Converts this <code>Geometry</code> to <b>normal form</b> (or <b>
+     *  canonical form</b> ). Normal form is a unique representation for <code>Geometry</code>
+     *  s. It can be used to test whether two <code>Geometry</code>s are equal
+     *  in a way that is independent of the ordering of the coordinates within
+     *  them. Normal form equality is a stronger condition than topological
+     *  equality, but weaker than pointwise equality. The definitions for normal
+     *  form use the standard lexicographical ordering for coordinates. "Sorted in
+     *  order of coordinates" means the obvious extension of this ordering to
+     *  sequences of coordinates.
+     *  <p>
+     *  NOTE that this method mutates the value of this geometry in-place.
+     *  If this is not safe and/or wanted, the geometry should be
+     *  cloned prior to normalization.
+     */
+    pub fn normalize(&mut self) {
+        match self {
+            Geometry::Point(_) => {}
+            Geometry::LineString(g) => g.normalize(),
+            Geometry::LinearRing(g) => g.normalize(),
+            Geometry::Polygon(g) => g.self_normalize(),
+            Geometry::MultiPoint(g) => g.normalize(),
+            Geometry::MultiLineString(g) => g.normalize(),
+            Geometry::MultiPolygon(g) => g.normalize(),
+            Geometry::GeometryCollection(g) => g.normalize(),
+        }
+    }
+
+    /**
+     * Creates a new Geometry which is a normalized
+     * copy of this Geometry.
+     *
+     * @return a normalized copy of this geometry.
+     * @see #normalize()
+     */
+    pub fn normalized_copy(&self) -> Geometry {
+        let mut copy = self.copy();
+        copy.normalize();
+        return copy;
+    }
+
+    /**
+     * Creates a deep copy of this {@link Geometry} object.
+     *
+     * @return a deep copy of this geometry
+     */
+    pub fn copy(&self) -> Geometry {
+        return match self {
+            Geometry::Point(g) => Geometry::Point(g.copy()),
+            Geometry::LineString(g) => Geometry::LineString(g.copy()),
+            Geometry::LinearRing(g) => Geometry::LinearRing(g.copy()),
+            Geometry::Polygon(g) => Geometry::Polygon(g.copy()),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.copy()),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.copy()),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.copy()),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.copy()),
+        };
+    }
+
+    /**
+     * Returns true if the two <code>Geometry</code>s are exactly equal,
+     * up to a specified distance tolerance.
+     * Two Geometries are exactly equal within a distance tolerance
+     * if and only if:
+     * <ul>
+     * <li>they have the same structure
+     * <li>they have the same values for their vertices,
+     * within the given tolerance distance, in exactly the same order.
+     * </ul>
+     * To properly test equality between different geometries,
+     * it is usually necessary to {@link #normalize()} them first.
+     *
+     * @param other the <code>Geometry</code> with which to compare this <code>Geometry</code>
+     * @param tolerance distance at or below which two <code>Coordinate</code>s
+     *   are considered equal
+     * @return <code>true</code> if this and the other <code>Geometry</code>
+     *   have identical structure and point values, up to the distance tolerance.
+     */
+    pub fn equals_exact(&self, other: &Geometry, tolerance: f64) -> bool {
+        return match (self, other) {
+            (Geometry::Point(a), Geometry::Point(b)) => a.equals_exact(b, tolerance),
+            (Geometry::LineString(a), Geometry::LineString(b)) => a.equals_exact(b, tolerance),
+            (Geometry::LinearRing(a), Geometry::LinearRing(b)) => a.equals_exact(b, tolerance),
+            (Geometry::Polygon(a), Geometry::Polygon(b)) => a.equals_exact(b, tolerance),
+            (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => a.equals_exact(b, tolerance),
+            (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => {
+                a.equals_exact(b, tolerance)
+            }
+            (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => a.equals_exact(b, tolerance),
+            (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+                a.equals_exact(b, tolerance)
+            }
+            _ => false,
+        };
+    }
+
+    /**
+     * Tests whether two geometries are exactly equal
+     * in their normalized forms.
+     * This is a convenience method which creates normalized
+     * versions of both geometries before computing
+     * {@link #equalsExact(Geometry)}.
+     *
+     * @param g a Geometry
+     * @return true if the input geometries are exactly equal in their normalized form
+     */
+    pub fn equals_norm(&self, g: &Geometry) -> bool {
+        return self
+            .normalized_copy()
+            .equals_exact(&g.normalized_copy(), 0.);
+    }
+
+    /**
+     * Computes a hash code for this geometry over its normalized form,
+     * so that geometries which are equal under {@link #equalsNorm(Geometry)}
+     * have the same hash.
+     * The hash is deterministic within one build, which allows it to be used as
+     * an in-memory key for caching the results of expensive operations.
+     * The hash algorithm may change between Rust releases,
+     * so the hash must not be persisted or shared between builds.
+     *
+     * @return a hash of the normalized structure and vertices of this geometry
+     */
+    pub fn topology_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.normalized_copy().hash_structure(&mut hasher);
+        return hasher.finish();
+    }
+
+    fn hash_structure(&self, hasher: &mut DefaultHasher) {
+        self.get_type_code().hash(hasher);
+        match self {
+            Geometry::Point(g) => Geometry::hash_coordinates(&g.get_coordinates(), hasher),
+            Geometry::LineString(g) => Geometry::hash_coordinates(&g.get_coordinates(), hasher),
+            Geometry::LinearRing(g) => Geometry::hash_coordinates(&g.get_coordinates(), hasher),
+            Geometry::Polygon(g) => Geometry::hash_polygon(g, hasher),
+            Geometry::MultiPoint(g) => Geometry::hash_coordinates(&g.get_coordinates(), hasher),
+            Geometry::MultiLineString(g) => {
+                g.get_num_line_strings().hash(hasher);
+                for i in 0..g.get_num_line_strings() {
+                    let line = g.get_line_string_at_index(i);
+                    Geometry::hash_coordinates(&line.get_coordinates(), hasher);
+                }
+            }
+            Geometry::MultiPolygon(g) => {
+                g.get_num_polygons().hash(hasher);
+                for i in 0..g.get_num_polygons() {
+                    Geometry::hash_polygon(&g.get_polygon_at_index(i), hasher);
+                }
+            }
+            Geometry::GeometryCollection(g) => {
+                g.get_num_geometries().hash(hasher);
+                for i in 0..g.get_num_geometries() {
+                    g.get_geometry_n(i).hash_structure(hasher);
+                }
+            }
+        }
+    }
+
+    fn hash_polygon(polygon: &Polygon, hasher: &mut DefaultHasher) {
+        if polygon.is_empty() {
+            0_usize.hash(hasher);
+            return;
+        }
+        (polygon.get_num_interior_ring() + 1).hash(hasher);
+        Geometry::hash_coordinates(&polygon.get_exterior_ring().get_coordinates(), hasher);
+        for i in 0..polygon.get_num_interior_ring() {
+            let hole = polygon.get_interior_ring_n(i);
+            Geometry::hash_coordinates(&hole.get_coordinates(), hasher);
+        }
+    }
+
+    /**
+     * Hashes the X and Y ordinates of a coordinate list,
+     * consistent with the 2D comparison used by {@link #equalsExact(Geometry)}.
+     */
+    fn hash_coordinates(coords: &[Coordinate], hasher: &mut DefaultHasher) {
+        coords.len().hash(hasher);
+        for coord in coords {
+            // add 0.0 so that -0.0 and 0.0 hash the same
+            (coord.x + 0.).to_bits().hash(hasher);
+            (coord.y + 0.).to_bits().hash(hasher);
+        }
+    }
+
+    /**
+     *  Returns whether this <code>Geometry</code> is greater than, equal to,
+     *  or less than another <code>Geometry</code>. <P>
+     *
+     *  If their classes are different, they are compared using the following
+     *  ordering:
+     *  <UL>
+     *    <LI> Point (lowest)
+     *    <LI> MultiPoint
+     *    <LI> LineString
+     *    <LI> LinearRing
+     *    <LI> MultiLineString
+     *    <LI> Polygon
+     *    <LI> MultiPolygon
+     *    <LI> GeometryCollection (highest)
+     *  </UL>
+     *  If the two <code>Geometry</code>s have the same class, their first
+     *  elements are compared. If those are the same, the second elements are
+     *  compared, etc.
+     *
+     *@param  o  a <code>Geometry</code> with which to compare this <code>Geometry</code>
+     *@return    a positive number, 0, or a negative number, depending on whether
+     *      this object is greater than, equal to, or less than <code>o</code>
+     */
+    pub fn compare_to(&self, other: &Geometry) -> i32 {
+        if self.get_type_code() != other.get_type_code() {
+            return self.get_type_code() - other.get_type_code();
+        }
+        return Geometry::compare_same_class(self.is_empty(), other.is_empty(), || {
+            match (self, other) {
+                (Geometry::Point(a), Geometry::Point(b)) => a.compare_to(b),
+                (Geometry::LineString(a), Geometry::LineString(b)) => a.compare_to(b),
+                (Geometry::LinearRing(a), Geometry::LinearRing(b)) => a.compare_to_same_class(b),
+                (Geometry::Polygon(a), Geometry::Polygon(b)) => a.compare_to(b),
+                (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => a.compare_to(b),
+                (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => a.compare_to(b),
+                (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => a.compare_to(b),
+                (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+                    a.compare_to(b)
+                }
+                _ => unreachable!(),
+            }
+        });
+    }
+
+    /**
+     * Compares two geometries of the same class, ordering empty geometries
+     * before non-empty ones and otherwise deferring to the class comparison.
+     */
+    pub(crate) fn compare_same_class<F: FnOnce() -> i32>(
+        is_empty: bool,
+        other_is_empty: bool,
+        compare_to_same_class: F,
+    ) -> i32 {
+        if is_empty && other_is_empty {
+            return 0;
+        }
+        if is_empty {
+            return -1;
+        }
+        if other_is_empty {
+            return 1;
+        }
+        return compare_to_same_class();
+    }
+
     //     /**
     //    * An object reference which can be used to carry ancillary data defined
     //    * by the client.
@@ -0,0 +1,193 @@
+use crate::core::{algorithm::centroid::Centroid, index::strtree::STRtree};
+
+use super::{
+    envelope::Envelope, geometry::Geometry, geometry_factory::GeometryFactory, point::Point,
+    precision_model::PrecisionModel,
+};
+
+/**
+ * Models a collection of {@link Geometry}s of
+ * arbitrary type and dimension.
+ *
+ *
+ *@version 1.7
+ */
+
+#[derive(Clone)]
+pub struct GeometryCollection {
+    /**
+     *  Internal representation of this <code>GeometryCollection</code>.
+     */
+    geometries: Vec<Geometry>,
+    precision_model: Option<PrecisionModel>,
+    envelope: Option<Envelope>,
+    /**
+     * An optional index of the member envelopes, holding member positions.
+     */
+    index: Option<STRtree<usize>>,
+}
+
+impl GeometryCollection {
+    /**
+     * @param geometries
+     *            the <code>Geometry</code>s for this <code>GeometryCollection</code>,
+     *            or <code>null</code> or an empty array to create the empty
+     *            geometry. Elements may be empty <code>Geometry</code>s,
+     *            but not <code>null</code>s.
+     */
+    pub fn new_with_geometries(geometries: &Vec<Geometry>) -> Self {
+        Self {
+            geometries: geometries.to_vec(),
+            precision_model: None,
+            envelope: None,
+            index: None,
+        }
+    }
+
+    /**
+     * Adds the cached envelopes of the elements of this collection to a list,
+     * recursing through any nested collections.
+     */
+    pub(crate) fn expand_element_envelopes(&mut self, envelopes: &mut Vec<Envelope>) {
+        for geometry in self.geometries.iter_mut() {
+            geometry.expand_element_envelopes(envelopes);
+        }
+    }
+
+    pub fn get_num_geometries(&self) -> usize {
+        return self.geometries.len();
+    }
+
+    pub fn get_geometry_n(&self, n: usize) -> Geometry {
+        return self.geometries[n].clone();
+    }
+
+    /**
+     * Builds a spatial index of the envelopes of the members of this collection,
+     * which is used to speed up {@link #query(Envelope)}.
+     * The index is discarded if the collection is modified.
+     */
+    pub fn build_index(&mut self) {
+        let mut index = STRtree::new();
+        for (i, geom) in self.geometries.iter().enumerate() {
+            index.insert(&geom.compute_envelope_internal(), i);
+        }
+        index.build();
+        self.index = Some(index);
+    }
+
+    /**
+     * Finds the members of this collection whose envelopes intersect an envelope.
+     * If an index has been built with {@link #buildIndex()} it is used,
+     * otherwise all members are scanned.
+     *
+     * @param env the envelope to query
+     * @return the intersecting members, in collection order
+     */
+    pub fn query(&self, env: &Envelope) -> Vec<&Geometry> {
+        let mut indexes: Vec<usize> = match &self.index {
+            Some(index) => index.query(env).into_iter().copied().collect(),
+            None => (0..self.geometries.len())
+                .filter(|&i| {
+                    self.geometries[i]
+                        .compute_envelope_internal()
+                        .intersects_envelope(env)
+                })
+                .collect(),
+        };
+        indexes.sort_unstable();
+        return indexes.iter().map(|&i| &self.geometries[i]).collect();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        for i in 0..self.geometries.len() {
+            if !self.geometries[i].is_empty() {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /**
+     * Computes the centroid of this <code>Geometry</code>.
+     * The centroid
+     * is equal to the centroid of the set of component Geometries of highest
+     * dimension (since the lower-dimension geometries contribute zero
+     * "weight" to the centroid).
+     * <p>
+     * The centroid of an empty geometry is <code>POINT EMPTY</code>.
+     *
+     * @return a {@link Point} which is the centroid of this Geometry
+     */
+    pub fn get_centroid(&self) -> Point {
+        return match Centroid::get_centroid_from_geometry_collection(self) {
+            Some(cent_pt) => GeometryFactory::create_point_from_coordinate(&cent_pt),
+            None => Point::default(),
+        };
+    }
+
+    pub fn get_geometry_type(&self) -> String {
+        return Geometry::TYPENAME_GEOMETRYCOLLECTION.to_owned();
+    }
+
+    pub fn equals_exact(&self, other: &GeometryCollection, tolerance: f64) -> bool {
+        if self.geometries.len() != other.geometries.len() {
+            return false;
+        }
+        for i in 0..self.geometries.len() {
+            if !self.geometries[i].equals_exact(&other.geometries[i], tolerance) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /**
+     * Creates and returns a full copy of this {@link GeometryCollection} object.
+     * (including all coordinates contained by it).
+     *
+     * @return a clone of this instance
+     */
+    pub fn copy(&self) -> GeometryCollection {
+        let mut geometries: Vec<Geometry> = vec![];
+        for i in 0..self.geometries.len() {
+            geometries.push(self.geometries[i].copy());
+        }
+        return GeometryCollection::new_with_geometries(&geometries);
+    }
+
+    /**
+     * Normalizes this <code>GeometryCollection</code> by normalizing each
+     * member and sorting the members using {@link Geometry#compareTo}.
+     */
+    pub fn normalize(&mut self) {
+        // the members are reordered
+        self.index = None;
+        for i in 0..self.geometries.len() {
+            self.geometries[i].normalize();
+        }
+        self.geometries.sort_by(|a, b| a.compare_to(b).cmp(&0));
+    }
+
+    pub fn compare_to(&self, other: &GeometryCollection) -> i32 {
+        let mut i = 0;
+        while i < self.geometries.len() && i < other.geometries.len() {
+            let comparison = self.geometries[i].compare_to(&other.geometries[i]);
+            if comparison != 0 {
+                return comparison;
+            }
+            i += 1;
+        }
+        if i < self.geometries.len() {
+            return 1;
+        }
+        if i < other.geometries.len() {
+            return -1;
+        }
+        return 0;
+    }
+
+    pub fn get_type_code() -> i32 {
+        return Geometry::TYPECODE_GEOMETRYCOLLECTION;
+    }
+}
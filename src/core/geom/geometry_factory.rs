@@ -1,12 +1,22 @@
 // use super::{coordinate::Coordinate, envelope::Envelope, implementation::{coordinate_array_sequence::CoordinateArraySequence, coordinate_array_sequence_factory::CoordinateArraySequenceFactory}, point::Point, precision_model::PrecisionModel};
 
 use super::{
-    coordinate::Coordinate, coordinate_array_sequences::CoordinateArraySequences, implementation::{
+    coordinate::Coordinate, coordinate_array_sequences::CoordinateArraySequences, envelope::Envelope, geometry::Geometry, geometry_collection::GeometryCollection, implementation::{
         coordinate_array_sequence::CoordinateArraySequence,
         coordinate_array_sequence_factory::CoordinateArraySequenceFactory,
-    }, line_string::LineString, linear_ring::LinearRing, multi_point::MultiPoint, multi_polygon::MultiPolygon, point::Point, polygon::Polygon, precision_model::PrecisionModel
+    }, line_string::LineString, linear_ring::LinearRing, multi_line_string::MultiLineString, multi_point::MultiPoint, multi_polygon::MultiPolygon, point::Point, polygon::Polygon, precision_model::PrecisionModel
 };
 
+/**
+ * Supplies a set of utility methods for building Geometry objects.
+ * <p>
+ * The associated <code>create_*</code> functions are stateless, and create
+ * geometries with no precision model.
+ * The <code>create_precise_*</code> methods round the coordinates of
+ * {@link Point}s, {@link LineString}s and {@link Polygon}s to this
+ * factory's PrecisionModel and associate them with it.
+ * The SRID is only recorded on the factory.
+ */
 #[derive(Clone, Copy)]
 pub struct GeometryFactory {
     srid: i32,
@@ -14,109 +24,203 @@ pub struct GeometryFactory {
 }
 
 impl GeometryFactory {
-    // /**
-    //    * Constructs a GeometryFactory that generates Geometries having the given
-    //    * PrecisionModel, spatial-reference ID, and CoordinateSequence implementation.
-    //    */
-    //   pub fn new_with_precision_model_srid(precision_model: PrecisionModel, srid: i32) -> Self {
-    //         Self {
-    //             srid,
-    //             precision_model,
-    //         }
-    // }
+    /**
+     * Constructs a GeometryFactory that generates Geometries having the given
+     * PrecisionModel and spatial-reference ID.
+     */
+    pub fn new(precision_model: PrecisionModel, srid: i32) -> Self {
+        Self {
+            srid,
+            precision_model,
+        }
+    }
 
-    // /**
-    // * Constructs a GeometryFactory that generates Geometries having the given
-    // * CoordinateSequence implementation, a double-precision floating PrecisionModel and a
-    // * spatial-reference ID of 0.
-    // */
-    // pub fn default() -> Self {
-    //     Self {
-    //         srid: 0,
-    //         precision_model: PrecisionModel::default(),
-    //     }
-    // }
+    /**
+     * Constructs a GeometryFactory that generates Geometries having a
+     * double-precision floating PrecisionModel and a spatial-reference ID of 0.
+     */
+    pub fn default() -> Self {
+        Self {
+            srid: 0,
+            precision_model: PrecisionModel::default(),
+        }
+    }
 
-    // /**
-    // * Constructs a GeometryFactory that generates Geometries having the given
-    // * {@link PrecisionModel} and the default CoordinateSequence
-    // * implementation.
-    // *
-    // * @param precisionModel the PrecisionModel to use
-    // */
-    // pub fn new_with_precision_model (precision_model: PrecisionModel) -> Self {
-    //     Self {
-    //         srid: 0,
-    //         precision_model,
-    //     }
-    // }
+    /**
+     * Constructs a GeometryFactory that generates Geometries having the given
+     * {@link PrecisionModel} and a spatial-reference ID of 0.
+     *
+     * @param precisionModel the PrecisionModel to use
+     */
+    pub fn new_with_precision_model(precision_model: PrecisionModel) -> Self {
+        Self {
+            srid: 0,
+            precision_model,
+        }
+    }
 
-    // /**
-    // * Creates a {@link Geometry} with the same extent as the given envelope.
-    // * The Geometry returned is guaranteed to be valid.
-    // * To provide this behaviour, the following cases occur:
-    // * <p>
-    // * If the <code>Envelope</code> is:
-    // * <ul>
-    // * <li>null : returns an empty {@link Point}
-    // * <li>a point : returns a non-empty {@link Point}
-    // * <li>a line : returns a two-point {@link LineString}
-    // * <li>a rectangle : returns a {@link Polygon} whose points are (minx, miny),
-    // *  (minx, maxy), (maxx, maxy), (maxx, miny), (minx, miny).
-    // * </ul>
-    // *
-    // *@param  envelope the <code>Envelope</code> to convert
-    // *@return an empty <code>Point</code> (for null <code>Envelope</code>s),
-    // *	a <code>Point</code> (when min x = max x and min y = max y) or a
-    // *      <code>Polygon</code> (in all other cases)
-    // */
-    // pub fn envelope_to_point(envelope: &Envelope) -> Point {
-    //     // null envelope - return empty point geometry
-    //     if envelope.is_null() {
-    //         return GeometryFactory::createPoint();
-    //     }
-
-    //     // point?
-    //     if envelope.get_min_x() == envelope.get_max_x() && envelope.get_min_y() == envelope.get_max_y() {
-    //         return GeometryFactory::createPointFromCoordinate(&Coordinate::new_xy(envelope.get_min_x(), envelope.get_min_y()));
-    //     } else {
-    //         return GeometryFactory::createPoint();
-    //     }
-    // }
+    /**
+     * Creates a {@link Geometry} with the same extent as the given envelope.
+     * The Geometry returned is guaranteed to be valid.
+     * To provide this behaviour, the following cases occur:
+     * <p>
+     * If the <code>Envelope</code> is:
+     * <ul>
+     * <li>null : returns an empty {@link Point}
+     * <li>a point : returns a non-empty {@link Point}
+     * <li>a line : returns a two-point {@link LineString}
+     * <li>a rectangle : returns a {@link Polygon} whose points are (minx, miny),
+     *  (minx, maxy), (maxx, maxy), (maxx, miny), (minx, miny).
+     * </ul>
+     *
+     *@param  envelope the <code>Envelope</code> to convert
+     *@return an empty <code>Point</code> (for null <code>Envelope</code>s),
+     * a <code>Point</code> (when min x = max x and min y = max y),
+     * a <code>LineString</code> (when min x = max x or min y = max y) or a
+     * <code>Polygon</code> (in all other cases)
+     */
+    pub fn envelope_to_geometry(envelope: &Envelope) -> Geometry {
+        // null envelope - return empty point geometry
+        if envelope.is_null() {
+            return Geometry::Point(GeometryFactory::create_point());
+        }
 
-    // pub fn envelope_to_line_string(envelope: &Envelope) -> LineString {
-    //     // vertical or horizontal line?
-    //     if envelope.get_min_x() == envelope.get_max_x() || envelope.get_min_y() == envelope.get_max_y() {
-    //         let coords: Vec<Coordinate> = vec![
-    //             Coordinate::new_xy(envelope.get_min_x(), envelope.get_min_y()),
-    //             Coordinate::new_xy(envelope.get_max_x(), envelope.get_max_y())
-    //         ];
-    //         return createLineString(coords);
-    //     }
-    // }
+        // point?
+        if envelope.get_min_x() == envelope.get_max_x()
+            && envelope.get_min_y() == envelope.get_max_y()
+        {
+            return Geometry::Point(GeometryFactory::create_point_from_coordinate(
+                &Coordinate::new_xy(envelope.get_min_x(), envelope.get_min_y()),
+            ));
+        }
 
-    // pub fn toPolygon(envelope: &Envelope) -> Polygon {
-    //     let coords: Vec<Coordinate> = vec![
-    //         Coordinate::new_xy(envelope.get_min_x(), envelope.get_min_y()),
-    //         Coordinate::new_xy(envelope.get_min_x(), envelope.get_max_y()),
-    //         Coordinate::new_xy(envelope.get_max_x(), envelope.get_max_y()),
-    //         Coordinate::new_xy(envelope.get_max_x(), envelope.get_min_y()),
-    //         Coordinate::new_xy(envelope.get_min_x(), envelope.get_min_y())
-    //     ];
-
-    //     // create a CW ring for the polygon
-    //     return createPolygon(createLinearRing(coords), None);
-    // }
+        // vertical or horizontal line?
+        if envelope.get_min_x() == envelope.get_max_x()
+            || envelope.get_min_y() == envelope.get_max_y()
+        {
+            let coords: Vec<Coordinate> = vec![
+                Coordinate::new_xy(envelope.get_min_x(), envelope.get_min_y()),
+                Coordinate::new_xy(envelope.get_max_x(), envelope.get_max_y()),
+            ];
+            return Geometry::LineString(GeometryFactory::create_line_string_coordinates(&coords));
+        }
 
-    // /**
-    // * Returns the PrecisionModel that Geometries created by this factory
-    // * will be associated with.
-    // *
-    // * @return the PrecisionModel for this factory
-    // */
-    // pub fn getPrecisionModel(&self) -> PrecisionModel {
-    //     return self.precision_model;
-    // }
+        return Geometry::Polygon(GeometryFactory::envelope_to_polygon(envelope));
+    }
+
+    /**
+     * Creates a rectangular {@link Polygon} covering an envelope.
+     * The shell is oriented clockwise, and starts and ends at (minx, miny).
+     * The envelope should have a non-zero width and height,
+     * since the polygon is otherwise degenerate.
+     *
+     *@param  envelope the <code>Envelope</code> to convert
+     *@return a <code>Polygon</code> with the extent of the envelope
+     */
+    pub fn envelope_to_polygon(envelope: &Envelope) -> Polygon {
+        let coords: Vec<Coordinate> = vec![
+            Coordinate::new_xy(envelope.get_min_x(), envelope.get_min_y()),
+            Coordinate::new_xy(envelope.get_min_x(), envelope.get_max_y()),
+            Coordinate::new_xy(envelope.get_max_x(), envelope.get_max_y()),
+            Coordinate::new_xy(envelope.get_max_x(), envelope.get_min_y()),
+            Coordinate::new_xy(envelope.get_min_x(), envelope.get_min_y()),
+        ];
+
+        // create a CW ring for the polygon
+        return GeometryFactory::create_polygon_with_coordinates(&coords);
+    }
+
+    /**
+     * Returns the PrecisionModel that Geometries created by this factory
+     * will be associated with.
+     *
+     * @return the PrecisionModel for this factory
+     */
+    pub fn get_precision_model(&self) -> PrecisionModel {
+        return self.precision_model;
+    }
+
+    /**
+     * Gets the SRID value defined for this factory.
+     * Geometries do not carry an SRID, so it is not attached
+     * to the geometries created by this factory.
+     *
+     * @return the factory SRID value
+     */
+    pub fn get_srid(&self) -> i32 {
+        return self.srid;
+    }
+
+    /**
+     * Rounds coordinates to the grid of this factory's PrecisionModel.
+     */
+    fn make_precise(&self, coordinates: &[Coordinate]) -> Vec<Coordinate> {
+        let mut precision_model = self.precision_model;
+        let mut pts = coordinates.to_vec();
+        for pt in pts.iter_mut() {
+            precision_model.make_precise_coordinate(pt);
+        }
+        return pts;
+    }
+
+    /**
+     * Creates a {@link Point} at the given coordinate,
+     * rounded to the grid of this factory's PrecisionModel.
+     * The point is associated with the PrecisionModel.
+     *
+     * @param coordinate the location of the point
+     * @return the created Point
+     */
+    pub fn create_precise_point(&self, coordinate: &Coordinate) -> Point {
+        return Point::new_with_coordinate(
+            &self.make_precise(&[*coordinate])[0],
+            Some(self.precision_model),
+        );
+    }
+
+    /**
+     * Creates a {@link LineString} using the given Coordinates,
+     * rounded to the grid of this factory's PrecisionModel.
+     * The line string is associated with the PrecisionModel.
+     *
+     * @param coordinates an array without null elements, or an empty array
+     * @return the created LineString
+     */
+    pub fn create_precise_line_string(&self, coordinates: &[Coordinate]) -> LineString {
+        return LineString::new_from_coordinate_sequence_precision_model(
+            CoordinateArraySequenceFactory::create_from_coordinates(
+                &self.make_precise(coordinates),
+            ),
+            self.precision_model,
+        );
+    }
+
+    /**
+     * Creates a {@link Polygon} with the given shell and hole Coordinates,
+     * rounded to the grid of this factory's PrecisionModel.
+     * The polygon is associated with the PrecisionModel.
+     *
+     * @param shell the shell coordinates, forming a closed ring, or an empty array
+     * @param holes the coordinates of each hole, each forming a closed ring
+     * @return the created Polygon
+     */
+    pub fn create_precise_polygon(
+        &self,
+        shell: &[Coordinate],
+        holes: &[Vec<Coordinate>],
+    ) -> Polygon {
+        let holes: Vec<LinearRing> = holes
+            .iter()
+            .map(|hole| {
+                GeometryFactory::create_linear_ring_with_coordinates(&self.make_precise(hole))
+            })
+            .collect();
+        return Polygon::new_with_linear_ring_vec_precision_model(
+            &GeometryFactory::create_linear_ring_with_coordinates(&self.make_precise(shell)),
+            &holes,
+            self.precision_model,
+        );
+    }
 
     /**
      * Constructs an empty {@link Point} geometry.
@@ -156,45 +260,45 @@ impl GeometryFactory {
         return Point::new_with_coordinate_seq(coordinates);
     }
 
-    // /**
-    // * Constructs an empty {@link MultiLineString} geometry.
-    // *
-    // * @return an empty MultiLineString
-    // */
-    // public MultiLineString createMultiLineString() {
-    // return new MultiLineString(null, this);
-    // }
+    /**
+     * Constructs an empty {@link MultiLineString} geometry.
+     *
+     * @return an empty MultiLineString
+     */
+    pub fn create_multi_line_string_empty() -> MultiLineString {
+        return MultiLineString::new_with_line_strings(&vec![]);
+    }
 
-    // /**
-    // * Creates a MultiLineString using the given LineStrings; a null or empty
-    // * array will create an empty MultiLineString.
-    // *
-    // * @param lineStrings LineStrings, each of which may be empty but not null
-    // * @return the created MultiLineString
-    // */
-    // public MultiLineString createMultiLineString(LineString[] lineStrings) {
-    // return new MultiLineString(lineStrings, this);
-    // }
+    /**
+     * Creates a MultiLineString using the given LineStrings; a null or empty
+     * array will create an empty MultiLineString.
+     *
+     * @param lineStrings LineStrings, each of which may be empty but not null
+     * @return the created MultiLineString
+     */
+    pub fn create_multi_line_string(line_strings: &Vec<LineString>) -> MultiLineString {
+        return MultiLineString::new_with_line_strings(line_strings);
+    }
 
-    // /**
-    // * Constructs an empty {@link GeometryCollection} geometry.
-    // *
-    // * @return an empty GeometryCollection
-    // */
-    // public GeometryCollection createGeometryCollection() {
-    // return new GeometryCollection(null, this);
-    // }
+    /**
+     * Constructs an empty {@link GeometryCollection} geometry.
+     *
+     * @return an empty GeometryCollection
+     */
+    pub fn create_geometry_collection_empty() -> GeometryCollection {
+        return GeometryCollection::new_with_geometries(&vec![]);
+    }
 
-    // /**
-    // * Creates a GeometryCollection using the given Geometries; a null or empty
-    // * array will create an empty GeometryCollection.
-    // *
-    // * @param geometries an array of Geometries, each of which may be empty but not null, or null
-    // * @return the created GeometryCollection
-    // */
-    // public GeometryCollection createGeometryCollection(Geometry[] geometries) {
-    // return new GeometryCollection(geometries, this);
-    // }
+    /**
+     * Creates a GeometryCollection using the given Geometries; a null or empty
+     * array will create an empty GeometryCollection.
+     *
+     * @param geometries an array of Geometries, each of which may be empty but not null, or null
+     * @return the created GeometryCollection
+     */
+    pub fn create_geometry_collection(geometries: &Vec<Geometry>) -> GeometryCollection {
+        return GeometryCollection::new_with_geometries(geometries);
+    }
 
     // /**
     // * Constructs an empty {@link MultiPolygon} geometry.
@@ -398,83 +502,90 @@ impl GeometryFactory {
         return Polygon::new_with_linear_ring(shell);
     }
 
-    // /**
-    // *  Build an appropriate <code>Geometry</code>, <code>MultiGeometry</code>, or
-    // *  <code>GeometryCollection</code> to contain the <code>Geometry</code>s in
-    // *  it.
-    // * For example:<br>
-    // *
-    // *  <ul>
-    // *    <li> If <code>geomList</code> contains a single <code>Polygon</code>,
-    // *    the <code>Polygon</code> is returned.
-    // *    <li> If <code>geomList</code> contains several <code>Polygon</code>s, a
-    // *    <code>MultiPolygon</code> is returned.
-    // *    <li> If <code>geomList</code> contains some <code>Polygon</code>s and
-    // *    some <code>LineString</code>s, a <code>GeometryCollection</code> is
-    // *    returned.
-    // *    <li> If <code>geomList</code> is empty, an empty <code>GeometryCollection</code>
-    // *    is returned
-    // *  </ul>
-    // *
-    // * Note that this method does not "flatten" Geometries in the input, and hence if
-    // * any MultiGeometries are contained in the input a GeometryCollection containing
-    // * them will be returned.
-    // *
-    // *@param  geomList  the <code>Geometry</code>s to combine
-    // *@return           a <code>Geometry</code> of the "smallest", "most
-    // *      type-specific" class that can contain the elements of <code>geomList</code>
-    // *      .
-    // */
-    // public Geometry buildGeometry(Collection geomList) {
-
-    // /**
-    // * Determine some facts about the geometries in the list
-    // */
-    // Class geomClass = null;
-    // boolean isHeterogeneous = false;
-    // boolean hasGeometryCollection = false;
-    // for (Iterator i = geomList.iterator(); i.hasNext(); ) {
-    // Geometry geom = (Geometry) i.next();
-    // Class partClass = geom.getClass();
-    // if (geomClass == null) {
-    // geomClass = partClass;
-    // }
-    // if (partClass != geomClass) {
-    // isHeterogeneous = true;
-    // }
-    // if (geom instanceof GeometryCollection)
-    // hasGeometryCollection = true;
-    // }
+    /**
+     *  Build an appropriate <code>Geometry</code>, <code>MultiGeometry</code>, or
+     *  <code>GeometryCollection</code> to contain the <code>Geometry</code>s in
+     *  it.
+     * For example:<br>
+     *
+     *  <ul>
+     *    <li> If <code>geomList</code> contains a single <code>Polygon</code>,
+     *    the <code>Polygon</code> is returned.
+     *    <li> If <code>geomList</code> contains several <code>Polygon</code>s, a
+     *    <code>MultiPolygon</code> is returned.
+     *    <li> If <code>geomList</code> contains some <code>Polygon</code>s and
+     *    some <code>LineString</code>s, a <code>GeometryCollection</code> is
+     *    returned.
+     *    <li> If <code>geomList</code> is empty, an empty <code>GeometryCollection</code>
+     *    is returned
+     *  </ul>
+     *
+     * Note that this method does not "flatten" Geometries in the input, and hence if
+     * any MultiGeometries are contained in the input a GeometryCollection containing
+     * them will be returned.
+     *
+     *@param  geomList  the <code>Geometry</code>s to combine
+     *@return           a <code>Geometry</code> of the "smallest", "most
+     *      type-specific" class that can contain the elements of <code>geomList</code>
+     *      .
+     */
+    pub fn build_geometry(geoms: Vec<Geometry>) -> Geometry {
+        // Determine some facts about the geometries in the list
+        let mut geom_type: Option<i32> = None;
+        let mut is_heterogeneous = false;
+        let mut has_geometry_collection = false;
+        for geom in &geoms {
+            let part_type = geom.get_type_code();
+            if geom_type.is_none() {
+                geom_type = Some(part_type);
+            }
+            if geom_type != Some(part_type) {
+                is_heterogeneous = true;
+            }
+            // multi-geometries are collections too
+            match geom {
+                Geometry::MultiPoint(_)
+                | Geometry::MultiLineString(_)
+                | Geometry::MultiPolygon(_)
+                | Geometry::GeometryCollection(_) => has_geometry_collection = true,
+                _ => {}
+            }
+        }
 
-    // /**
-    // * Now construct an appropriate geometry to return
-    // */
-    // // for the empty geometry, return an empty GeometryCollection
-    // if (geomClass == null) {
-    // return createGeometryCollection();
-    // }
-    // if (isHeterogeneous || hasGeometryCollection) {
-    // return createGeometryCollection(toGeometryArray(geomList));
-    // }
-    // // at this point we know the collection is hetereogenous.
-    // // Determine the type of the result from the first Geometry in the list
-    // // this should always return a geometry, since otherwise an empty collection would have already been returned
-    // Geometry geom0 = (Geometry) geomList.iterator().next();
-    // boolean isCollection = geomList.size() > 1;
-    // if (isCollection) {
-    // if (geom0 instanceof Polygon) {
-    // return createMultiPolygon(toPolygonArray(geomList));
-    // }
-    // else if (geom0 instanceof LineString) {
-    // return createMultiLineString(toLineStringArray(geomList));
-    // }
-    // else if (geom0 instanceof Point) {
-    // return createMultiPoint(toPointArray(geomList));
-    // }
-    // Assert.shouldNeverReachHere("Unhandled class: " + geom0.getClass().getName());
-    // }
-    // return geom0;
-    // }
+        // Now construct an appropriate geometry to return.
+        // For the empty geometry, return an empty GeometryCollection
+        if geom_type.is_none() {
+            return Geometry::GeometryCollection(
+                GeometryFactory::create_geometry_collection_empty(),
+            );
+        }
+        if is_heterogeneous || has_geometry_collection {
+            return Geometry::GeometryCollection(GeometryFactory::create_geometry_collection(
+                &geoms,
+            ));
+        }
+        // at this point we know the collection is homogeneous.
+        // Determine the type of the result from the first Geometry in the list
+        let is_collection = geoms.len() > 1;
+        if is_collection {
+            let geometries = Geometry::GeometryCollection(
+                GeometryFactory::create_geometry_collection(&geoms),
+            );
+            return match &geoms[0] {
+                Geometry::Polygon(_) => Geometry::MultiPolygon(
+                    GeometryFactory::create_multi_polygon(&geometries.extract_polygons()),
+                ),
+                Geometry::LineString(_) | Geometry::LinearRing(_) => Geometry::MultiLineString(
+                    GeometryFactory::create_multi_line_string(&geometries.extract_line_strings()),
+                ),
+                Geometry::Point(_) => Geometry::MultiPoint(
+                    GeometryFactory::create_multi_point_from_points(&geometries.extract_points()),
+                ),
+                _ => unreachable!(),
+            };
+        }
+        return geoms.into_iter().next().unwrap();
+    }
 
     /**
      * Constructs an empty {@link LineString} geometry.
@@ -74,6 +74,27 @@ impl IntersectionMatrix {
         new
     }
 
+    /**
+     *  Creates an <code>IntersectionMatrix</code> with the given dimension
+     *  values.
+     *
+     *@param  values  the dimension values, indexed by row and column.
+     *      Possible values are <code>{TRUE, FALSE, DONTCARE, 0, 1, 2}</code>
+     *@throws IllegalArgumentException if a value is not a dimension value
+     */
+    pub fn new_from_values(values: [[i32; 3]; 3]) -> Self {
+        for row in &values {
+            for &value in row {
+                assert!(
+                    Dimension::to_dimension_symbol(value).is_some(),
+                    "Unknown dimension value: {}",
+                    value
+                );
+            }
+        }
+        Self { matrix: values }
+    }
+
     /**
      *  Creates an <code>IntersectionMatrix</code> with the same elements as
      *  <code>other</code>.
@@ -203,6 +224,27 @@ impl IntersectionMatrix {
         self.matrix[row][column] = dimension_value;
     }
 
+    /**
+     *  Changes the value of one of this <code>IntersectionMatrix</code>s
+     *  elements, addressed by {@link Location} values
+     *  rather than by matrix indices.
+     *
+     *@param  row             the {@link Location} of the first <code>Geometry</code>
+     *      (interior, boundary or exterior)
+     *@param  column          the {@link Location} of the second <code>Geometry</code>
+     *      (interior, boundary or exterior)
+     *@param  dimensionValue  the new value of the element
+     *
+     * @throws IllegalArgumentException if row or column is {@link Location#NONE}
+     */
+    pub fn set_location(&mut self, row: i32, column: i32, dimension_value: i32) {
+        assert!(
+            row != Location::NONE && column != Location::NONE,
+            "Location NONE does not address a matrix entry"
+        );
+        self.set_row_column_value(row as usize, column as usize, dimension_value);
+    }
+
     /**
      *  Changes the elements of this <code>IntersectionMatrix</code> to the
      *  dimension symbols in <code>dimensionSymbols</code>.
@@ -339,6 +381,36 @@ impl IntersectionMatrix {
         return self.matrix[row][column];
     }
 
+    /**
+     *  Returns the values of this matrix, indexed by row and column.
+     *
+     *@return the dimension values of this <code>IntersectionMatrix</code>
+     */
+    pub fn to_values(&self) -> [[i32; 3]; 3] {
+        return self.matrix;
+    }
+
+    /**
+     *  Returns the value of one of this matrix
+     *  entries, addressed by {@link Location} values
+     *  rather than by matrix indices.
+     *
+     *@param  row     the {@link Location} of the first <code>Geometry</code>
+     *      (interior, boundary or exterior)
+     *@param  column  the {@link Location} of the second <code>Geometry</code>
+     *      (interior, boundary or exterior)
+     *@return         the dimension value at the given matrix position.
+     *
+     * @throws IllegalArgumentException if row or column is {@link Location#NONE}
+     */
+    pub fn get_location(&self, row: i32, column: i32) -> i32 {
+        assert!(
+            row != Location::NONE && column != Location::NONE,
+            "Location NONE does not address a matrix entry"
+        );
+        return self.get(row as usize, column as usize);
+    }
+
     /**
      * Tests if this matrix matches <code>[FF*FF****]</code>.
      *
@@ -560,6 +632,33 @@ impl IntersectionMatrix {
                 == Dimension::FALSE;
     }
 
+    /**
+     *  Tests whether the argument dimensions are equal and
+     *  this matrix matches the pattern <tt>[TFFFTFFFT]</tt>
+     *  stated in <i>Simple feature access - Part 1: Common architecture</i>.
+     *  <p>
+     *  Unlike {@link #isEquals}, this pattern requires the boundaries
+     *  of the geometries to intersect, so two identical <tt>POINT</tt>s
+     *  (which have empty boundaries) are not equal.
+     *  It is provided for checking conformance with the specification.
+     *
+     *@param  dimensionOfGeometryA  the dimension of the first <code>Geometry</code>
+     *@param  dimensionOfGeometryB  the dimension of the second <code>Geometry</code>
+     *@return                       <code>true</code> if the two <code>Geometry</code>s
+     *      related by this matrix are equal according to the SFS pattern; the
+     *      <code>Geometry</code>s must have the same dimension to be equal
+     */
+    pub fn is_equals_strict(
+        &self,
+        dimension_of_geometry_a: i32,
+        dimension_of_geometry_b: i32,
+    ) -> bool {
+        if dimension_of_geometry_a != dimension_of_geometry_b {
+            return false;
+        }
+        return self.matches_string("TFFFTFFFT".to_owned());
+    }
+
     pub fn equals(&self, other: &IntersectionMatrix) -> bool {
         for i in 0..3 {
             for j in 0..3 {
@@ -640,6 +739,60 @@ impl IntersectionMatrix {
         return true;
     }
 
+    /**
+     * Tests whether a string is a valid matrix pattern,
+     * consisting of exactly nine dimension symbols.
+     * Invalid patterns never match any matrix.
+     *
+     *@param  pattern  the pattern to validate. Possible
+     *      symbol values are <code>{T, F, * , 0, 1, 2}</code>,
+     *      with <code>T</code> and <code>F</code> in either case.
+     *@return <code>true</code> if the pattern is valid
+     */
+    pub fn is_valid_pattern(pattern: &str) -> bool {
+        return pattern.chars().count() == 9
+            && pattern
+                .chars()
+                .all(|c| Dimension::to_dimension_value(c).is_some());
+    }
+
+    /**
+     * Tests whether this matrix matches a named spatial predicate.
+     * The supported names are
+     * <code>disjoint</code>, <code>intersects</code>, <code>touches</code>,
+     * <code>crosses</code>, <code>within</code>, <code>contains</code>,
+     * <code>covers</code>, <code>coveredBy</code>, <code>equals</code>
+     * and <code>overlaps</code>, compared ignoring case.
+     * This allows relate tests to be driven by predicate names,
+     * for instance from a configuration file.
+     *
+     *@param  name  the name of the spatial predicate
+     *@param  dimensionOfGeometryA  the dimension of the first <code>Geometry</code>
+     *@param  dimensionOfGeometryB  the dimension of the second <code>Geometry</code>
+     *@return <code>true</code> if this matrix satisfies the predicate,
+     *      or <code>false</code> if it does not or the name is unknown
+     */
+    pub fn matches_predicate(
+        &self,
+        name: &str,
+        dimension_of_geometry_a: i32,
+        dimension_of_geometry_b: i32,
+    ) -> bool {
+        return match name.to_ascii_lowercase().as_str() {
+            "disjoint" => self.is_disjoint(),
+            "intersects" => self.is_intersects(),
+            "touches" => self.is_touches(dimension_of_geometry_a, dimension_of_geometry_b),
+            "crosses" => self.is_crosses(dimension_of_geometry_a, dimension_of_geometry_b),
+            "within" => self.is_within(),
+            "contains" => self.is_contains(),
+            "covers" => self.is_covers(),
+            "coveredby" => self.is_covered_by(),
+            "equals" => self.is_equals(dimension_of_geometry_a, dimension_of_geometry_b),
+            "overlaps" => self.is_overlaps(dimension_of_geometry_a, dimension_of_geometry_b),
+            _ => false,
+        };
+    }
+
     /**
      *  Transposes this IntersectionMatrix.
      *
@@ -656,25 +809,21 @@ impl IntersectionMatrix {
         self.matrix[2][1] = self.matrix[1][2];
         self.matrix[1][2] = temp;
     }
+}
 
+impl std::fmt::Display for IntersectionMatrix {
     /**
-     *  Returns a nine-character <code>String</code> representation of this <code>IntersectionMatrix</code>
-     *  .
-     *
-     *@return    the nine dimension symbols of this <code>IntersectionMatrix</code>
-     *      in row-major order.
+     *  Writes the nine-character <code>String</code> representation of this
+     *  <code>IntersectionMatrix</code>: its nine dimension symbols in row-major order.
      */
-    pub fn to_string(&self) -> String {
-        let mut output = "".to_owned();
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for ai in 0..3 {
             for bi in 0..3 {
-                let character = Dimension::to_dimension_symbol(self.matrix[ai][bi]);
-                match character {
-                    Some(character) => output.push(character),
-                    None => {}
+                if let Some(character) = Dimension::to_dimension_symbol(self.matrix[ai][bi]) {
+                    write!(f, "{}", character)?;
                 }
             }
         }
-        output
+        return Ok(());
     }
 }
@@ -1,16 +1,38 @@
+use std::{
+    f64::consts::PI,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
 use crate::core::algorithm::{
-    distance::Distance, intersection::Intersection, orientation::Orientation,
+    angle::Angle, distance::Distance, intersection::Intersection, orientation::Orientation,
     robust_line_intersector::RobustLineIntersector,
 };
 
-use super::coordinate::Coordinate;
+use super::{
+    coordinate::Coordinate, geometry_factory::GeometryFactory, line_string::LineString,
+    polygon::Polygon,
+};
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineSegment {
     pub p0: Coordinate,
     pub p1: Coordinate,
 }
 
+/**
+ * The result of intersecting two line segments.
+ * Collinear segments which overlap intersect in a segment,
+ * given by its two endpoints.
+ */
+#[derive(Clone, Copy)]
+pub enum LineIntersectionResult {
+    None,
+    Point(Coordinate),
+    Collinear(Coordinate, Coordinate),
+}
+
 impl LineSegment {
     pub fn default() -> Self {
         let p0 = Coordinate::default();
@@ -93,6 +115,27 @@ impl LineSegment {
         return self.p0.distance(&self.p1);
     }
 
+    /**
+     * Tests whether the segment has zero length,
+     * in which case it has no direction.
+     * @return true if the endpoints of the segment are equal
+     */
+    pub fn is_zero_length(&self) -> bool {
+        return self.p0.equals_2d(&self.p1);
+    }
+
+    /**
+     * Computes the squared length of the line segment.
+     * This avoids a square root, so it is faster than {@link #getLength()}
+     * for comparing lengths.
+     * @return the squared length of the line segment
+     */
+    pub fn get_length_squared(&self) -> f64 {
+        let dx = self.p1.x - self.p0.x;
+        let dy = self.p1.y - self.p0.y;
+        return dx * dx + dy * dy;
+    }
+
     /**
      * Tests whether the segment is horizontal.
      *
@@ -161,6 +204,30 @@ impl LineSegment {
         return Orientation::index(&self.p0, &self.p1, p);
     }
 
+    /**
+     * Tests whether a point lies on the line determined by this segment.
+     * The test uses the robust {@link Orientation#index(Coordinate, Coordinate, Coordinate)},
+     * so it is exact for all input values.
+     * A zero-length segment is collinear with every point.
+     *
+     * @param p the point to test
+     * @return true if the point is collinear with this segment
+     */
+    pub fn is_point_collinear(&self, p: &Coordinate) -> bool {
+        return self.orientation_index_coordinate(p) == Orientation::COLLINEAR;
+    }
+
+    /**
+     * Tests whether another segment lies on the line determined by this segment.
+     * The segments do not need to overlap.
+     *
+     * @param other the segment to test
+     * @return true if both endpoints of the other segment are collinear with this segment
+     */
+    pub fn collinear_with(&self, other: &LineSegment) -> bool {
+        return self.is_point_collinear(&other.p0) && self.is_point_collinear(&other.p1);
+    }
+
     /**
      * Reverses the direction of the line segment.
      */
@@ -170,6 +237,15 @@ impl LineSegment {
         self.p1 = temp;
     }
 
+    /**
+     * Creates a copy of this segment with the opposite direction.
+     *
+     * @return a reversed copy of this segment
+     */
+    pub fn reversed(&self) -> LineSegment {
+        return LineSegment::new_from_coordinates(&self.p1, &self.p0);
+    }
+
     /**
      * Puts the line segment into a normalized form.
      * This is useful for using line segments in maps and indexes when
@@ -230,6 +306,26 @@ impl LineSegment {
         return Distance::point_to_segment(p, &self.p0, &self.p1);
     }
 
+    /**
+     * Computes the squared distance between this line segment and a given point.
+     * This avoids a square root, so it is faster than {@link #distance(Coordinate)}
+     * for comparing distances.
+     *
+     * @return the squared distance from this segment to the given point
+     */
+    pub fn distance_squared_coordinate(&self, p: &Coordinate) -> f64 {
+        let factor = self.projection_factor(p);
+        // a zero-length segment has no projection factor
+        let r = if f64::is_nan(factor) {
+            0.
+        } else {
+            factor.clamp(0., 1.)
+        };
+        let dx = self.p0.x + r * (self.p1.x - self.p0.x) - p.x;
+        let dy = self.p0.y + r * (self.p1.y - self.p0.y) - p.y;
+        return dx * dx + dy * dy;
+    }
+
     /**
      * Computes the perpendicular distance between the (infinite) line defined
      * by this line segment and a point.
@@ -298,15 +394,14 @@ impl LineSegment {
      * @param segmentLengthFraction the fraction of the segment length along the line
      * @param offsetDistance the distance the point is offset from the segment
      *    (positive is to the left, negative is to the right)
-     * @return the point at that distance and offset
-     *
-     * @throws IllegalStateException if the segment has zero length
+     * @return the point at that distance and offset,
+     *    or None if the segment has zero length and the offset distance is non-zero
      */
     pub fn point_along_offset(
         &self,
         segment_length_fraction: f64,
         offset_distance: f64,
-    ) -> Coordinate {
+    ) -> Option<Coordinate> {
         // the point on the segment line
         let segx = self.p0.x + segment_length_fraction * (self.p1.x - self.p0.x);
         let segy = self.p0.y + segment_length_fraction * (self.p1.y - self.p0.y);
@@ -317,8 +412,10 @@ impl LineSegment {
         let mut ux = 0.0;
         let mut uy = 0.0;
         if offset_distance != 0.0 {
-            // if (len <= 0.0)
-            //   throw new IllegalStateException("Cannot compute offset from zero-length line segment");
+            if len <= 0.0 {
+                // a zero-length segment has no direction to offset in
+                return None;
+            }
 
             // u is the vector that is the length of the offset, in the direction of the segment
             ux = offset_distance * dx / len;
@@ -332,7 +429,7 @@ impl LineSegment {
         let mut coord = Coordinate::default();
         coord.set_x(offsetx);
         coord.set_y(offsety);
-        return coord;
+        return Some(coord);
     }
 
     /**
@@ -480,14 +577,83 @@ impl LineSegment {
      *
      * @param offsetDistance the distance the point is offset from the segment
      *    (positive is to the left, negative is to the right)
-     * @return a line segment offset by the specified distance
+     * @return a line segment offset by the specified distance,
+     *    or None if the segment has zero length and the offset distance is non-zero
+     */
+    pub fn offset(&self, offset_distance: f64) -> Option<LineSegment> {
+        let offset0 = self.point_along_offset(0., offset_distance)?;
+        let offset1 = self.point_along_offset(1., offset_distance)?;
+        return Some(LineSegment::new_from_coordinates(&offset0, &offset1));
+    }
+
+    /**
+     * Computes the buffer of this segment, which is a "capsule" polygon
+     * formed by the segments offset to each side joined by semicircular end caps.
+     * The end caps are approximated by line segments, using the given number
+     * of segments per quarter circle.
+     * A zero-length segment produces a circle.
+     * A non-positive distance produces an empty polygon.
      *
-     * @throws IllegalStateException if the segment has zero length
+     * @param distance the buffer distance
+     * @param quadrantSegments the number of segments used to approximate a quarter circle
+     * @return the buffer polygon, with a CCW shell
      */
-    pub fn offset(&self, offset_distance: f64) -> LineSegment {
-        let offset0 = self.point_along_offset(0., offset_distance);
-        let offset1 = self.point_along_offset(1., offset_distance);
-        return LineSegment::new_from_coordinates(&offset0, &offset1);
+    pub fn buffer(&self, distance: f64, quadrant_segments: usize) -> Polygon {
+        if distance <= 0. {
+            return GeometryFactory::create_polygon_with_coordinates(&vec![]);
+        }
+        let n_seg = usize::max(quadrant_segments, 1);
+        let angle_inc = PI / 2. / n_seg as f64;
+        let mut pts: Vec<Coordinate> = vec![];
+
+        if self.p0.equals_2d(&self.p1) {
+            for i in 0..(4 * n_seg) {
+                pts.push(Angle::project(&self.p0, i as f64 * angle_inc, distance));
+            }
+        } else {
+            // right side runs forward, left side runs backward,
+            // so the caps are traversed counter-clockwise
+            let angle = self.angle();
+            LineSegment::add_cap(
+                &mut pts,
+                &self.p1,
+                angle - PI / 2.,
+                angle_inc,
+                2 * n_seg,
+                distance,
+            );
+            LineSegment::add_cap(
+                &mut pts,
+                &self.p0,
+                angle + PI / 2.,
+                angle_inc,
+                2 * n_seg,
+                distance,
+            );
+        }
+        pts.push(pts[0]);
+        return GeometryFactory::create_polygon_with_coordinates(&pts);
+    }
+
+    /**
+     * Adds the points of a semicircular arc around a centre point,
+     * including both ends.
+     */
+    fn add_cap(
+        pts: &mut Vec<Coordinate>,
+        centre: &Coordinate,
+        start_angle: f64,
+        angle_inc: f64,
+        n_seg: usize,
+        distance: f64,
+    ) {
+        for i in 0..=n_seg {
+            pts.push(Angle::project(
+                centre,
+                start_angle + i as f64 * angle_inc,
+                distance,
+            ));
+        }
     }
 
     /**
@@ -537,6 +703,31 @@ impl LineSegment {
         return self.p1;
     }
 
+    /**
+     * Computes the closest point on this line segment to another point,
+     * together with the fraction along the segment at which it occurs.
+     * The fraction is clamped to the range [0.0, 1.0],
+     * as computed by {@link #segmentFraction(Coordinate)}.
+     *
+     * @param p the point to find the closest point to
+     * @return the closest point on the line segment to the point p, and its segment fraction
+     */
+    pub fn closest_point_and_fraction(&self, p: &Coordinate) -> (Coordinate, f64) {
+        let factor = self.projection_factor(p);
+        if factor > 0. && factor < 1. {
+            return (
+                self.project_coordinate_with_projection_factor(p, factor),
+                factor,
+            );
+        }
+        let dist0 = self.p0.distance(p);
+        let dist1 = self.p1.distance(p);
+        if dist0 < dist1 {
+            return (self.p0, 0.);
+        }
+        return (self.p1, 1.);
+    }
+
     /**
      * Computes the closest points on two line segments.
      *
@@ -613,6 +804,58 @@ impl LineSegment {
         return None;
     }
 
+    /**
+     * Computes an intersection point between two line segments, if there is one,
+     * using a given intersector.
+     * This avoids creating a new intersector for each pair of segments
+     * when many intersections are computed.
+     *
+     * @param li the intersector to use
+     * @param line a line segment
+     * @return an intersection point, or <code>null</code> if there is none
+     *
+     * @see #intersection(LineSegment)
+     */
+    pub fn intersection_with(
+        &self,
+        li: &mut RobustLineIntersector,
+        line: &LineSegment,
+    ) -> Option<Coordinate> {
+        li.reset();
+        li.compute_intersection_4(&self.p0, &self.p1, &line.p0, &line.p1);
+        if li.has_intersection() {
+            return Some(li.get_intersection(0));
+        }
+        return None;
+    }
+
+    /**
+     * Computes the intersection of this segment and another,
+     * distinguishing a single intersection point from
+     * the overlap of collinear segments.
+     *
+     * @param line a line segment
+     * @return the intersection of the segments
+     *
+     * @see RobustLineIntersector
+     */
+    pub fn intersection_detail(&self, line: &LineSegment) -> LineIntersectionResult {
+        let mut li = RobustLineIntersector::default();
+        li.compute_intersection_4(&self.p0, &self.p1, &line.p0, &line.p1);
+        match li.get_intersection_num() {
+            RobustLineIntersector::POINT_INTERSECTION => {
+                return LineIntersectionResult::Point(li.get_intersection(0));
+            }
+            RobustLineIntersector::COLLINEAR_INTERSECTION => {
+                return LineIntersectionResult::Collinear(
+                    li.get_intersection(0),
+                    li.get_intersection(1),
+                );
+            }
+            _ => return LineIntersectionResult::None,
+        }
+    }
+
     /**
      * Computes the intersection point of the lines of infinite extent defined
      * by two line segments (if there is one).
@@ -637,17 +880,15 @@ impl LineSegment {
     /**
      * Creates a LineString with the same coordinates as this segment
      *
-     * @param geomFactory the geometry factory to use
      * @return a LineString with the same geometry as this segment
      */
-    // TODO: implement GeometryFactory::createLineString
-    // pub fn to_line_string(&self) -> LineString {
-    //     let coords: Vec<Coordinate> = vec![
-    //         Coordinate::from_coordinate(&self.p0),
-    //         Coordinate::from_coordinate(&self.p1),
-    //     ];
-    //     return GeometryFactory::createLineString(&coords);
-    // }
+    pub fn to_line_string(self) -> LineString {
+        let coords: Vec<Coordinate> = vec![
+            Coordinate::from_coordinate(&self.p0),
+            Coordinate::from_coordinate(&self.p1),
+        ];
+        return GeometryFactory::create_line_string_coordinates(&coords);
+    }
 
     /**
      *  Returns <code>true</code> if <code>other</code> has the same values for
@@ -691,12 +932,47 @@ impl LineSegment {
         return self.p0.equals_2d(&other.p0) && self.p1.equals_2d(&other.p1)
             || self.p0.equals_2d(&other.p1) && self.p1.equals_2d(&other.p0);
     }
+}
+
+impl fmt::Display for LineSegment {
+    /**
+     * Writes this segment in WKT form, as a <code>LINESTRING</code> of its two endpoints.
+     */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "LINESTRING ({} {}, {} {})",
+            self.p0.x, self.p0.y, self.p1.x, self.p1.y
+        )
+    }
+}
 
-    // public String toString()
-    // {
-    //   return WKTConstants.LINESTRING + " (" +
-    //       p0.x + " " + p0.y
-    //       + ", " +
-    //       p1.x + " " + p1.y + ")";
-    // }
+impl PartialEq for LineSegment {
+    /**
+     * Segments are equal if they have the same endpoints in the same order.
+     *
+     * @see #equalsTopo
+     */
+    fn eq(&self, other: &Self) -> bool {
+        return self.equals(other);
+    }
+}
+
+/**
+ * A segment with a NaN X or Y ordinate is not equal to itself,
+ * so such segments must not be used as keys of a map or set.
+ */
+impl Eq for LineSegment {}
+
+impl Hash for LineSegment {
+    /**
+     * {@link #equals} compares only the X and Y of the endpoints,
+     * so the hash covers only those four ordinates, and ignores Z and M.
+     * An endpoint at -0.0 equals one at 0.0, so it is hashed as 0.0.
+     */
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for ordinate in [self.p0.x, self.p0.y, self.p1.x, self.p1.y] {
+            (ordinate + 0.0).to_bits().hash(state);
+        }
+    }
 }
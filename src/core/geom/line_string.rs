@@ -5,6 +5,7 @@ use super::{
     coordinate_sequence_comparator::CoordinateSequenceComparator, dimension::Dimension,
     envelope::Envelope, geometry::Geometry, geometry_factory::GeometryFactory,
     implementation::coordinate_array_sequence::CoordinateArraySequence, point::Point,
+    precision_model::PrecisionModel,
 };
 
 /**
@@ -36,6 +37,11 @@ pub struct LineString {
      *  The bounding box of this <code>Geometry</code>.
      */
     envelope: Option<Envelope>,
+
+    /**
+     *  The specification of the grid of allowable points, if any.
+     */
+    precision_model: Option<PrecisionModel>,
 }
 
 impl LineString {
@@ -56,9 +62,34 @@ impl LineString {
         Self {
             points,
             envelope: None,
+            precision_model: None,
         }
     }
 
+    /**
+     * Constructs a <code>LineString</code> with the given points,
+     * associated with a {@link PrecisionModel}.
+     * The points are not rounded to the precision model.
+     *
+     *@param  points the points of the linestring
+     *@param  precisionModel  the specification of the grid of allowable points
+     *      for this <code>LineString</code>
+     */
+    pub fn new_from_coordinate_sequence_precision_model(
+        points: CoordinateArraySequence,
+        precision_model: PrecisionModel,
+    ) -> Self {
+        Self {
+            points,
+            envelope: None,
+            precision_model: Some(precision_model),
+        }
+    }
+
+    pub fn get_precision_model(&self) -> Option<PrecisionModel> {
+        return self.precision_model;
+    }
+
     pub fn init(&mut self, points: CoordinateArraySequence) {
         self.points = points;
     }
@@ -167,6 +198,7 @@ impl LineString {
      */
     pub fn reverse(&self) -> LineString {
         let mut res = self.reverse_internal();
+        res.precision_model = self.precision_model;
         if self.envelope.is_some() {
             res.envelope = Some(self.envelope.unwrap().copy());
         }
@@ -196,6 +228,21 @@ impl LineString {
         return false;
     }
 
+    /**
+     * Gets an {@link Envelope} containing
+     * the minimum and maximum x and y values in this <code>Geometry</code>.
+     * The envelope is computed on first use and cached.
+     *
+     *@return the envelope of this <code>Geometry</code>.
+     *@return an empty Envelope if this Geometry is empty
+     */
+    pub fn get_envelope_internal(&mut self) -> Envelope {
+        if self.envelope.is_none() {
+            self.envelope = Some(self.compute_envelope_internal());
+        }
+        return Envelope::new_envelope(&self.envelope.unwrap());
+    }
+
     pub fn compute_envelope_internal(&self) -> Envelope {
         if self.is_empty() {
             return Envelope::default();
@@ -252,7 +299,9 @@ impl LineString {
     //   }
 
     pub fn copy(&self) -> LineString {
-        return LineString::new_from_coordinate_sequence(self.points.copy());
+        let mut res = LineString::new_from_coordinate_sequence(self.points.copy());
+        res.precision_model = self.precision_model;
+        return res;
     }
 
     /**
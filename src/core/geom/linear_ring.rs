@@ -2,6 +2,7 @@ use crate::core::algorithm::length::Length;
 
 use super::{
     coordinate::Coordinate, coordinate_array_sequences::CoordinateArraySequences,
+    coordinate_filter::CoordinateFilter,
     coordinate_sequence_comparator::CoordinateSequenceComparator, coordinates::Coordinates,
     dimension::Dimension, envelope::Envelope, geometry::Geometry,
     geometry_factory::GeometryFactory,
@@ -205,6 +206,60 @@ impl LinearRing {
         return true;
     }
 
+    /**
+     * Applies a {@link CoordinateFilter} to each vertex of this ring.
+     * If the filter changes any vertex the cached envelope is discarded.
+     *
+     * @param filter the filter to apply
+     * @return true if any vertex was changed
+     */
+    pub fn apply_coordinate_filter(&mut self, filter: &mut dyn CoordinateFilter) -> bool {
+        let mut pts = self.points.to_coordinate_array();
+        let mut is_changed = false;
+        for pt in pts.iter_mut() {
+            let orig = *pt;
+            filter.filter(pt);
+            if !pt.equals_3d(&orig) {
+                is_changed = true;
+            }
+        }
+        if is_changed {
+            self.points = CoordinateArraySequence::new_with_coordinates(&pts);
+            self.envelope = None;
+        }
+        return is_changed;
+    }
+
+    /**
+     * Normalizes a LinearRing in the same way as a LineString:
+     * the first point which is not equal to its reflected point
+     * is made less than the reflected point.
+     * Ring orientation is normalized by the containing {@link Polygon}.
+     */
+    pub fn normalize(&mut self) {
+        for i in 0..(self.points.size() / 2) {
+            let j = self.points.size() - 1 - i;
+            // skip equal points on both ends
+            if !self
+                .points
+                .get_coordinate_index(i)
+                .equals_2d(&self.points.get_coordinate_index(j))
+            {
+                if self
+                    .points
+                    .get_coordinate_index(i)
+                    .compare_to(&self.points.get_coordinate_index(j))
+                    > 0
+                {
+                    let mut copy = self.points.copy();
+                    CoordinateArraySequences::reverse(&mut copy);
+                    self.points = copy;
+                }
+                return;
+            }
+        }
+    }
+
     pub fn compare_to_same_class(&self, other: &LinearRing) -> i32 {
         // MD - optimized implementation
         let mut i = 0;
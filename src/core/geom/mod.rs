@@ -3,8 +3,10 @@ pub(crate) mod point;
 pub(crate) mod polygon;
 pub(crate) mod position;
 pub(crate) mod precision_model;
+pub(crate) mod prepared;
 pub(crate) mod packed_coordinate_sequences;
 pub(crate) mod geometry;
+pub(crate) mod geometry_collection;
 pub(crate) mod geometry_factory;
 pub(crate) mod line_segment;
 pub(crate) mod line_string;
@@ -12,6 +14,7 @@ pub(crate) mod coordinate_sequence_comparator;
 pub(crate) mod coordinate_array_sequences;
 pub(crate) mod coordinate_list;
 pub(crate) mod coordinate_arrays;
+pub(crate) mod coordinate_filter;
 pub(crate) mod coordinate;
 pub(crate) mod coordinates;
 pub(crate) mod dimension;
@@ -24,4 +27,5 @@ pub(crate) mod linear_ring;
 pub(crate) mod multi_polygon;
 pub(crate) mod octagonal_envelope;
 pub(crate) mod quadrant;
-pub(crate) mod triangle;
\ No newline at end of file
+pub(crate) mod triangle;
+pub(crate) mod util;
\ No newline at end of file
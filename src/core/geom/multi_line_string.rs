@@ -11,6 +11,7 @@ use super::{
  *@version 1.7
  */
 
+#[derive(Clone)]
 pub struct MultiLineString {
     line_strings: Vec<LineString>,
     precision_model: Option<PrecisionModel>,
@@ -85,6 +86,27 @@ impl MultiLineString {
         return true;
     }
 
+    /**
+     * Adds the cached envelopes of the line strings of this collection to a list.
+     */
+    pub(crate) fn expand_element_envelopes(&mut self, envelopes: &mut Vec<Envelope>) {
+        for line_string in self.line_strings.iter_mut() {
+            envelopes.push(line_string.get_envelope_internal());
+        }
+    }
+
+    pub fn get_num_line_strings(&self) -> usize {
+        return self.line_strings.len();
+    }
+
+    pub fn get_num_geometries(&self) -> usize {
+        return self.line_strings.len();
+    }
+
+    pub fn get_line_string_at_index(&self, n: usize) -> LineString {
+        return self.line_strings[n].copy();
+    }
+
     pub fn is_closed(&self) -> bool {
         if self.is_empty() {
             return false;
@@ -130,8 +152,8 @@ impl MultiLineString {
 
     fn reverse_internal(&self) -> MultiLineString {
         let mut line_strings: Vec<LineString> = vec![];
-        for i in 0..line_strings.len() {
-            line_strings[i] = self.line_strings[i].reverse();
+        for i in 0..self.line_strings.len() {
+            line_strings.push(self.line_strings[i].reverse());
         }
         return MultiLineString::new_with_line_strings(&line_strings);
     }
@@ -139,7 +161,7 @@ impl MultiLineString {
     pub fn copy_internal(&self) -> MultiLineString {
         let mut line_strings: Vec<LineString> = vec![];
         for i in 0..self.line_strings.len() {
-            line_strings[i] = self.line_strings[i].copy();
+            line_strings.push(self.line_strings[i].copy());
         }
         return MultiLineString::new_with_line_strings(&line_strings);
     }
@@ -156,6 +178,44 @@ impl MultiLineString {
         return true;
     }
 
+    pub fn copy(&self) -> MultiLineString {
+        self.copy_internal()
+    }
+
+    /**
+     * Normalizes this <code>MultiLineString</code> by normalizing each
+     * component and sorting the components into order.
+     */
+    pub fn normalize(&mut self) {
+        for i in 0..self.line_strings.len() {
+            self.line_strings[i].normalize();
+        }
+        self.line_strings.sort_by(|a, b| {
+            Geometry::compare_same_class(a.is_empty(), b.is_empty(), || a.compare_to(b)).cmp(&0)
+        });
+    }
+
+    pub fn compare_to(&self, other: &MultiLineString) -> i32 {
+        let mut i = 0;
+        while i < self.line_strings.len() && i < other.line_strings.len() {
+            let a = &self.line_strings[i];
+            let b = &other.line_strings[i];
+            let comparison =
+                Geometry::compare_same_class(a.is_empty(), b.is_empty(), || a.compare_to(b));
+            if comparison != 0 {
+                return comparison;
+            }
+            i += 1;
+        }
+        if i < self.line_strings.len() {
+            return 1;
+        }
+        if i < other.line_strings.len() {
+            return -1;
+        }
+        return 0;
+    }
+
     pub fn get_type_code() -> i32 {
         return Geometry::TYPECODE_MULTILINESTRING;
     }
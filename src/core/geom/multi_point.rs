@@ -11,6 +11,7 @@ use super::{
  *@version 1.7
  */
 
+#[derive(Clone)]
 pub struct MultiPoint {
     points: Vec<Point>,
     precision_model: Option<PrecisionModel>,
@@ -124,6 +125,15 @@ impl MultiPoint {
         return envelope;
     }
 
+    /**
+     * Adds the cached envelopes of the points of this collection to a list.
+     */
+    pub(crate) fn expand_element_envelopes(&mut self, envelopes: &mut Vec<Envelope>) {
+        for point in self.points.iter_mut() {
+            envelopes.push(point.get_envelope_internal());
+        }
+    }
+
     pub fn reverse(&self) -> MultiPoint {
         let mut res = self.reverse_internal();
         if self.envelope.is_some() {
@@ -136,7 +146,7 @@ impl MultiPoint {
     fn reverse_internal(&self) -> MultiPoint {
         let mut points: Vec<Point> = vec![];
         for i in 0..self.points.len() {
-            points[i] = self.points[i].copy();
+            points.push(self.points[i].copy());
         }
         return MultiPoint::new_with_points(&points);
     }
@@ -208,7 +218,7 @@ impl MultiPoint {
     //     return RelateOpMultiPoint::relate_multipoints(self, multipoint);
     // }
 
-    pub fn equals_exact(&self, other: MultiPoint, tolerance: f64) -> bool {
+    pub fn equals_exact(&self, other: &MultiPoint, tolerance: f64) -> bool {
         if self.points.len() != other.points.len() {
             return false;
         }
@@ -221,7 +231,7 @@ impl MultiPoint {
     }
 
     pub fn get_point_at_index(&self, index: usize) -> Option<Point> {
-        if self.points.len() == 0 || self.points.len() < index - 1 {
+        if index >= self.points.len() {
             return None;
         }
         return Some(self.points[index].copy());
@@ -263,11 +273,42 @@ impl MultiPoint {
     pub fn copy(&self) -> MultiPoint {
         let mut points: Vec<Point> = vec![];
         for i in 0..self.points.len() {
-            points[i] = self.points[i].copy();
+            points.push(self.points[i].copy());
         }
         return MultiPoint::new_with_points(&points);
     }
 
+    /**
+     * Normalizes this <code>MultiPoint</code> by sorting its points
+     * in coordinate order.
+     */
+    pub fn normalize(&mut self) {
+        self.points.sort_by(|a, b| {
+            Geometry::compare_same_class(a.is_empty(), b.is_empty(), || a.compare_to(b)).cmp(&0)
+        });
+    }
+
+    pub fn compare_to(&self, other: &MultiPoint) -> i32 {
+        let mut i = 0;
+        while i < self.points.len() && i < other.points.len() {
+            let a = &self.points[i];
+            let b = &other.points[i];
+            let comparison =
+                Geometry::compare_same_class(a.is_empty(), b.is_empty(), || a.compare_to(b));
+            if comparison != 0 {
+                return comparison;
+            }
+            i += 1;
+        }
+        if i < self.points.len() {
+            return 1;
+        }
+        if i < other.points.len() {
+            return -1;
+        }
+        return 0;
+    }
+
     pub fn get_type_code() -> i32 {
         return Geometry::TYPECODE_MULTIPOINT;
     }
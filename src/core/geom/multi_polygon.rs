@@ -129,14 +129,45 @@ impl MultiPolygon {
         return area;
     }
 
+    /**
+     *  Returns the length of this <code>GeometryCollection</code>,
+     *  which is the sum of the perimeters of its polygons.
+     *
+     * @return the length of the geometry
+     */
+    pub fn get_length(&self) -> f64 {
+        let mut sum = 0.0;
+        for i in 0..self.polygons.len() {
+            sum += self.polygons[i].get_length();
+        }
+        return sum;
+    }
+
+    /**
+     * Adds the cached envelopes of the polygons of this collection to a list.
+     */
+    pub(crate) fn expand_element_envelopes(&mut self, envelopes: &mut Vec<Envelope>) {
+        for polygon in self.polygons.iter_mut() {
+            envelopes.push(polygon.get_envelope_internal());
+        }
+    }
+
     pub fn get_num_polygons(&self) -> usize {
         return self.polygons.len();
     }
 
+    pub fn get_num_geometries(&self) -> usize {
+        return self.polygons.len();
+    }
+
     pub fn get_polygon_at_index(&self, n: usize) -> Polygon {
         return self.polygons[n].copy();
     }
 
+    pub fn get_geometry_n(&self, n: usize) -> Geometry {
+        return Geometry::Polygon(self.polygons[n].copy());
+    }
+
     /**
      * Computes the centroid of this <code>Geometry</code>.
      * The centroid
@@ -174,7 +205,7 @@ impl MultiPolygon {
         return true;
     }
 
-    pub fn equals_exact(&self, other: MultiPolygon, tolerance: f64) -> bool {
+    pub fn equals_exact(&self, other: &MultiPolygon, tolerance: f64) -> bool {
         if self.polygons.len() != other.polygons.len() {
             return false;
         }
@@ -205,7 +236,7 @@ impl MultiPolygon {
     fn reverse_internal(&self) -> MultiPolygon {
         let mut polygons: Vec<Polygon> = vec![];
         for i in 0..self.polygons.len() {
-            polygons[i] = self.polygons[i].reverse();
+            polygons.push(self.polygons[i].reverse());
         }
         return MultiPolygon::new_with_polygons(&polygons);
     }
@@ -217,11 +248,45 @@ impl MultiPolygon {
     fn copy_internal(&self) -> MultiPolygon {
         let mut polygons: Vec<Polygon> = vec![];
         for i in 0..self.polygons.len() {
-            polygons[i] = self.polygons[i].copy();
+            polygons.push(self.polygons[i].copy());
         }
         return MultiPolygon::new_with_polygons(&polygons);
     }
 
+    /**
+     * Normalizes this <code>MultiPolygon</code> by normalizing each
+     * component and sorting the components into order.
+     */
+    pub fn normalize(&mut self) {
+        for i in 0..self.polygons.len() {
+            self.polygons[i].self_normalize();
+        }
+        self.polygons.sort_by(|a, b| {
+            Geometry::compare_same_class(a.is_empty(), b.is_empty(), || a.compare_to(b)).cmp(&0)
+        });
+    }
+
+    pub fn compare_to(&self, other: &MultiPolygon) -> i32 {
+        let mut i = 0;
+        while i < self.polygons.len() && i < other.polygons.len() {
+            let a = &self.polygons[i];
+            let b = &other.polygons[i];
+            let comparison =
+                Geometry::compare_same_class(a.is_empty(), b.is_empty(), || a.compare_to(b));
+            if comparison != 0 {
+                return comparison;
+            }
+            i += 1;
+        }
+        if i < self.polygons.len() {
+            return 1;
+        }
+        if i < other.polygons.len() {
+            return -1;
+        }
+        return 0;
+    }
+
     pub fn get_type_code(&self) -> i32 {
         return Geometry::TYPECODE_MULTIPOLYGON;
     }
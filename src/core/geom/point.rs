@@ -73,6 +73,10 @@ impl Point {
         }
     }
 
+    pub fn get_precision_model(&self) -> Option<PrecisionModel> {
+        return self.precision_model;
+    }
+
     pub fn get_coordinates(&self) -> Vec<Coordinate> {
         if self.is_empty() {
             return vec![];
@@ -253,6 +257,13 @@ impl Point {
         return Point::new_with_coordinate_seq(&self.coordinates.copy());
     }
 
+    pub fn compare_to(&self, other: &Point) -> i32 {
+        return self
+            .get_coordinate()
+            .unwrap()
+            .compare_to(&other.get_coordinate().unwrap());
+    }
+
     pub fn compare_to_same_class(&self, other: &Point, comp: &CoordinateSequenceComparator) -> i32 {
         return comp.compare_coordinate_array_sequence(&self.coordinates, &other.coordinates);
     }
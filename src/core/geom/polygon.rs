@@ -1,10 +1,13 @@
-use crate::core::algorithm::{area::Area, centroid::Centroid, orientation::Orientation};
+use crate::core::algorithm::{
+    area::Area, centroid::Centroid, orientation::Orientation, point_location::PointLocation,
+};
 
 use super::{
     coordinate::Coordinate, coordinate_array_sequences::CoordinateArraySequences,
+    coordinate_arrays::CoordinateArrays, coordinate_filter::CoordinateFilter,
     coordinate_sequence_comparator::CoordinateSequenceComparator, envelope::Envelope,
-    geometry::Geometry, geometry_factory::GeometryFactory, linear_ring::LinearRing, point::Point,
-    precision_model::PrecisionModel,
+    geometry::Geometry, geometry_factory::GeometryFactory, linear_ring::LinearRing,
+    location::Location, point::Point, precision_model::PrecisionModel,
 };
 
 /**
@@ -301,14 +304,26 @@ impl Polygon {
         return true;
     }
 
-    // TODO: Implement ME!
-    //   public void apply(CoordinateFilter filter) {
-    // 	    shell.apply(filter);
-    // 	    for (int i = 0; i < holes.length; i++) {
-    // 	      holes[i].apply(filter);
-    // 	    }
-    // 	  }
+    /**
+     * Applies a {@link CoordinateFilter} to the vertices of the shell
+     * and then of each hole.
+     * If the filter changes any vertex the cached envelope is discarded.
+     *
+     * @param filter the filter to apply
+     */
+    pub fn apply_coordinate_filter(&mut self, filter: &mut dyn CoordinateFilter) {
+        let mut is_changed = self.shell.apply_coordinate_filter(filter);
+        for hole in self.holes.iter_mut() {
+            if hole.apply_coordinate_filter(filter) {
+                is_changed = true;
+            }
+        }
+        if is_changed {
+            self.envelope = None;
+        }
+    }
 
+    // TODO: Implement ME!
     //   public void apply(CoordinateSequenceFilter filter)
     //   {
     // 	    shell.apply(filter);
@@ -358,7 +373,10 @@ impl Polygon {
         for i in 0..self.holes.len() {
             self.holes[i] = self.normalized(&self.holes[i], false);
         }
-        // Arrays.sort(holes);
+        self.holes.sort_by(|a, b| {
+            Geometry::compare_same_class(a.is_empty(), b.is_empty(), || a.compare_to_same_class(b))
+                .cmp(&0)
+        });
     }
 
     pub fn compare_to(&self, poly: &Polygon) -> i32 {
@@ -428,17 +446,21 @@ impl Polygon {
     }
 
     pub fn normalized(&self, ring: &LinearRing, clockwise: bool) -> LinearRing {
-        let res = ring.copy();
-        Polygon::normalize(&res, clockwise);
+        let mut res = ring.copy();
+        Polygon::normalize(&mut res, clockwise);
         return res;
     }
 
-    pub fn normalize(ring: &LinearRing, clockwise: bool) {
+    pub fn normalize(ring: &mut LinearRing, clockwise: bool) {
         if ring.is_empty() {
             return;
         }
 
         let mut seq = ring.get_coordinate_array_sequence();
+        // a malformed ring with a single point has nothing to normalize
+        if seq.size() < 2 {
+            return;
+        }
         let min_coordinate_index = CoordinateArraySequences::min_coordinate_index_sequence_from_to(
             &seq,
             0,
@@ -452,6 +474,34 @@ impl Polygon {
         if Orientation::is_ccw_coordinate_array_sequence(&seq) == clockwise {
             CoordinateArraySequences::reverse(&mut seq);
         }
+        *ring = LinearRing::new_with_coordinate_array_sequence(&seq);
+    }
+
+    /**
+     * Creates a copy of this polygon with the shell and holes oriented
+     * so that the shell has the given orientation and the holes the opposite one.
+     * The start point of each ring is preserved.
+     *
+     * @param isShellCCW true if the shell should be oriented counter-clockwise
+     * @return an oriented copy of this polygon
+     */
+    pub fn to_oriented(&self, is_shell_ccw: bool) -> Polygon {
+        let shell = Polygon::oriented(&self.shell, is_shell_ccw);
+        let mut holes: Vec<LinearRing> = vec![];
+        for i in 0..self.holes.len() {
+            holes.push(Polygon::oriented(&self.holes[i], !is_shell_ccw));
+        }
+        return Polygon::new_with_linear_ring_vec(&shell, &holes);
+    }
+
+    fn oriented(ring: &LinearRing, ccw: bool) -> LinearRing {
+        if ring.is_empty()
+            || Orientation::is_ccw_coordinate_array_sequence(&ring.get_coordinate_array_sequence())
+                == ccw
+        {
+            return ring.copy();
+        }
+        return ring.reverse();
     }
 
     /**
@@ -473,7 +523,7 @@ impl Polygon {
         let shell = self.get_exterior_ring().reverse();
         let mut holes: Vec<LinearRing> = vec![];
         for i in 0..self.get_num_interior_ring() {
-            holes[i] = self.get_interior_ring_n(i).reverse();
+            holes.push(self.get_interior_ring_n(i).reverse());
         }
 
         return GeometryFactory::create_polygon_with_linear_ring_vec(&shell, &holes);
@@ -521,6 +571,144 @@ impl Polygon {
         return Point::default();
     }
 
+    /**
+     * Computes an interior point of this <code>Geometry</code>.
+     * An interior point is guaranteed to lie in the interior of the Geometry,
+     * if it possible to calculate such a point exactly. Otherwise,
+     * the point may lie on the boundary of the geometry.
+     * <p>
+     * The point is the midpoint of the widest interior section
+     * of a horizontal scan line.
+     * The scan line is placed near the centroid, but between the Y ordinates
+     * of the vertices, so that it does not pass through any vertex.
+     * <p>
+     * The interior point of an empty geometry is <code>POINT EMPTY</code>.
+     *
+     * @return a {@link Point} which is in the interior of this Geometry
+     */
+    pub fn get_interior_point(&self) -> Point {
+        if self.is_empty() {
+            return Point::default();
+        }
+        let rings: Vec<Vec<Coordinate>> = std::iter::once(&self.shell)
+            .chain(self.holes.iter())
+            .map(|ring| ring.get_coordinates())
+            .collect();
+
+        let centre_y = match Centroid::get_centroid_from_polygon(self) {
+            Some(centroid) => centroid.y,
+            None => rings[0][0].y,
+        };
+        let scan_y = Polygon::scan_line_y(&rings, centre_y);
+
+        let mut crossings: Vec<f64> = vec![];
+        for pts in &rings {
+            for i in 1..pts.len() {
+                let (p0, p1) = (&pts[i - 1], &pts[i]);
+                // the scan line does not pass through any vertex
+                if (p0.y > scan_y) != (p1.y > scan_y) {
+                    crossings.push(p0.x + (scan_y - p0.y) * (p1.x - p0.x) / (p1.y - p0.y));
+                }
+            }
+        }
+        crossings.sort_by(|a, b| a.total_cmp(b));
+
+        let mut interior_pt = self.shell.get_coordinates()[0];
+        let mut max_width = -1.;
+        for chord in crossings.chunks_exact(2) {
+            let width = chord[1] - chord[0];
+            if width > max_width {
+                max_width = width;
+                interior_pt = Coordinate::new_xy((chord[0] + chord[1]) / 2., scan_y);
+            }
+        }
+        return self.create_point_from_internal_coord(&mut interior_pt);
+    }
+
+    /**
+     * Finds a Y ordinate for a scan line close to a given Y ordinate,
+     * which lies halfway between the Y ordinates of the nearest vertices above and below it.
+     */
+    fn scan_line_y(rings: &[Vec<Coordinate>], centre_y: f64) -> f64 {
+        let mut hi_y = f64::INFINITY;
+        let mut lo_y = f64::NEG_INFINITY;
+        for pts in rings {
+            for p in pts {
+                if p.y <= centre_y {
+                    lo_y = f64::max(lo_y, p.y);
+                } else {
+                    hi_y = f64::min(hi_y, p.y);
+                }
+            }
+        }
+        if hi_y == f64::INFINITY {
+            return lo_y;
+        }
+        return (hi_y + lo_y) / 2.;
+    }
+
+    /**
+     * Tests whether a point lies in the interior of this polygon.
+     * Points on the boundary of the shell or of a hole are not contained,
+     * and neither are points inside a hole.
+     *
+     * @param p the point to test
+     * @return true if the point lies in the interior of the polygon
+     */
+    pub fn contains_point(&self, p: &Coordinate) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        if PointLocation::locate_in_ring(p, &self.shell.get_coordinates()) != Location::INTERIOR {
+            return false;
+        }
+        for hole in &self.holes {
+            if PointLocation::locate_in_ring(p, &hole.get_coordinates()) != Location::EXTERIOR {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /**
+     * Tests whether this polygon is structurally valid.
+     * Each ring must be closed, have at least 4 points
+     * and no repeated consecutive points,
+     * and each hole must lie inside the shell.
+     * Self-intersection and the connectedness of the interior
+     * are not checked.
+     * Empty polygons are valid.
+     *
+     * @return true if the polygon is structurally valid
+     */
+    pub fn is_valid(&self) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let shell = self.shell.get_coordinates();
+        if !Polygon::is_valid_ring(&shell) {
+            return false;
+        }
+        for hole in &self.holes {
+            let pts = hole.get_coordinates();
+            if !Polygon::is_valid_ring(&pts) {
+                return false;
+            }
+            // hole vertices may touch the shell, but not lie outside it
+            if pts
+                .iter()
+                .any(|p| PointLocation::locate_in_ring(p, &shell) == Location::EXTERIOR)
+            {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    fn is_valid_ring(pts: &Vec<Coordinate>) -> bool {
+        return CoordinateArrays::is_ring(pts) && !CoordinateArrays::has_repeated_points(pts);
+    }
+
     fn create_point_from_internal_coord(&self, coord: &mut Coordinate) -> Point {
         if let Some(mut precision_model) = self.precision_model {
             precision_model.make_precise_coordinate(coord);
@@ -0,0 +1 @@
+pub(crate) mod prepared_line_string;
@@ -0,0 +1,222 @@
+use crate::core::{
+    algorithm::{point_location::PointLocation, robust_line_intersector::RobustLineIntersector},
+    geom::{
+        coordinate::Coordinate, envelope::Envelope, geometry::Geometry, line_segment::LineSegment,
+        line_string::LineString, location::Location, polygon::Polygon,
+    },
+    index::strtree::STRtree,
+};
+
+/**
+ * A prepared version for {@link LineString} geometries.
+ * The segments of the line are indexed in an {@link STRtree},
+ * which allows predicates against many other geometries to be
+ * evaluated efficiently.
+ */
+pub struct PreparedLineString {
+    line: LineString,
+    envelope: Envelope,
+    segment_index: STRtree<LineSegment>,
+}
+
+impl PreparedLineString {
+    /**
+     * Creates a new prepared line, indexing its segments.
+     *
+     * @param line the line to prepare
+     */
+    pub fn new(line: &LineString) -> Self {
+        let coords = line.get_coordinates();
+        let mut segment_index = STRtree::new();
+        for i in 1..coords.len() {
+            let seg = LineSegment::new_from_coordinates(&coords[i - 1], &coords[i]);
+            segment_index.insert(&Envelope::new_coordinates(&seg.p0, &seg.p1), seg);
+        }
+        segment_index.build();
+        Self {
+            line: line.copy(),
+            envelope: line.compute_envelope_internal(),
+            segment_index,
+        }
+    }
+
+    /**
+     * Gets the original {@link LineString} which has been prepared.
+     *
+     * @return the base geometry
+     */
+    pub fn get_geometry(&self) -> &LineString {
+        return &self.line;
+    }
+
+    /**
+     * Tests whether a point lies on the prepared line
+     * (including its endpoints).
+     *
+     * @param p the point to test
+     * @return true if the point lies on a segment of the line
+     * @see PointLocation#isOnLine(Coordinate, Coordinate[])
+     */
+    pub fn contains(&self, p: &Coordinate) -> bool {
+        let mut is_on_line = false;
+        self.segment_index
+            .query_visitor(&Envelope::new_coordinate(p), |seg| {
+                if !is_on_line && PointLocation::is_on_segment(p, &seg.p0, &seg.p1) {
+                    is_on_line = true;
+                }
+            });
+        return is_on_line;
+    }
+
+    /**
+     * Tests whether the prepared line intersects a given geometry.
+     * <p>
+     * The segments of the target geometry are tested against the indexed
+     * segments of the line. If the target contains polygons,
+     * the line intersects them if it lies in their interior
+     * without crossing their boundary.
+     *
+     * @param other the geometry to test
+     * @return true if the line intersects the geometry
+     */
+    pub fn intersects(&self, other: &Geometry) -> bool {
+        if !self
+            .envelope
+            .intersects_envelope(&other.compute_envelope_internal())
+        {
+            return false;
+        }
+
+        let mut points: Vec<Coordinate> = vec![];
+        let mut segments: Vec<LineSegment> = vec![];
+        let mut polygons: Vec<Polygon> = vec![];
+        PreparedLineString::extract_components(other, &mut points, &mut segments, &mut polygons);
+
+        if points.iter().any(|p| self.contains(p)) {
+            return true;
+        }
+        if segments.iter().any(|seg| self.intersects_segment(seg)) {
+            return true;
+        }
+        // no boundary crossing, so the line is either inside or outside each polygon
+        if let Some(pt) = self.line.get_coordinate() {
+            return polygons
+                .iter()
+                .any(|poly| PreparedLineString::is_in_polygon(&pt, poly));
+        }
+        return false;
+    }
+
+    fn intersects_segment(&self, seg: &LineSegment) -> bool {
+        let mut li = RobustLineIntersector::default();
+        let mut is_intersected = false;
+        self.segment_index.query_visitor(
+            &Envelope::new_coordinates(&seg.p0, &seg.p1),
+            |line_seg| {
+                if is_intersected {
+                    return;
+                }
+                li.compute_intersection_4(&line_seg.p0, &line_seg.p1, &seg.p0, &seg.p1);
+                is_intersected = li.has_intersection();
+            },
+        );
+        return is_intersected;
+    }
+
+    fn is_in_polygon(p: &Coordinate, polygon: &Polygon) -> bool {
+        if polygon.is_empty() {
+            return false;
+        }
+        let shell = polygon.get_exterior_ring().get_coordinates();
+        if PointLocation::locate_in_ring(p, &shell) == Location::EXTERIOR {
+            return false;
+        }
+        for i in 0..polygon.get_num_interior_ring() {
+            let hole = polygon.get_interior_ring_n(i).get_coordinates();
+            if PointLocation::locate_in_ring(p, &hole) == Location::INTERIOR {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /**
+     * Extracts the points, segments and polygons making up a geometry.
+     */
+    fn extract_components(
+        geom: &Geometry,
+        points: &mut Vec<Coordinate>,
+        segments: &mut Vec<LineSegment>,
+        polygons: &mut Vec<Polygon>,
+    ) {
+        match geom {
+            Geometry::Point(g) => points.extend(g.get_coordinates()),
+            Geometry::MultiPoint(g) => points.extend(g.get_coordinates()),
+            Geometry::LineString(g) => {
+                PreparedLineString::extract_segments(&g.get_coordinates(), points, segments)
+            }
+            Geometry::LinearRing(g) => {
+                PreparedLineString::extract_segments(&g.get_coordinates(), points, segments)
+            }
+            Geometry::MultiLineString(g) => {
+                for i in 0..g.get_num_line_strings() {
+                    let line = g.get_line_string_at_index(i);
+                    PreparedLineString::extract_segments(&line.get_coordinates(), points, segments);
+                }
+            }
+            Geometry::Polygon(g) => PreparedLineString::extract_polygon(g, segments, polygons),
+            Geometry::MultiPolygon(g) => {
+                for i in 0..g.get_num_polygons() {
+                    let polygon = g.get_polygon_at_index(i);
+                    PreparedLineString::extract_polygon(&polygon, segments, polygons);
+                }
+            }
+            Geometry::GeometryCollection(g) => {
+                for i in 0..g.get_num_geometries() {
+                    PreparedLineString::extract_components(
+                        &g.get_geometry_n(i),
+                        points,
+                        segments,
+                        polygons,
+                    );
+                }
+            }
+        }
+    }
+
+    fn extract_polygon(
+        polygon: &Polygon,
+        segments: &mut Vec<LineSegment>,
+        polygons: &mut Vec<Polygon>,
+    ) {
+        if polygon.is_empty() {
+            return;
+        }
+        // degenerate single-point rings are ignored
+        let mut points: Vec<Coordinate> = vec![];
+        let shell = polygon.get_exterior_ring().get_coordinates();
+        PreparedLineString::extract_segments(&shell, &mut points, segments);
+        for i in 0..polygon.get_num_interior_ring() {
+            let hole = polygon.get_interior_ring_n(i).get_coordinates();
+            PreparedLineString::extract_segments(&hole, &mut points, segments);
+        }
+        polygons.push(polygon.copy());
+    }
+
+    fn extract_segments(
+        coords: &Vec<Coordinate>,
+        points: &mut Vec<Coordinate>,
+        segments: &mut Vec<LineSegment>,
+    ) {
+        // a line with a single point is treated as a point
+        if coords.len() == 1 {
+            points.push(coords[0]);
+        }
+        for i in 1..coords.len() {
+            segments.push(LineSegment::new_from_coordinates(
+                &coords[i - 1],
+                &coords[i],
+            ));
+        }
+    }
+}
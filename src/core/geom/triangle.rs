@@ -16,9 +16,9 @@ pub struct Triangle {
     /**
      * The coordinates of the vertices of the triangle
      */
-    p0: Coordinate,
-    p1: Coordinate,
-    p2: Coordinate,
+    pub p0: Coordinate,
+    pub p1: Coordinate,
+    pub p2: Coordinate,
 }
 
 impl Triangle {
@@ -149,6 +149,41 @@ impl Triangle {
         return (a * b * c) / (4. * area);
     }
 
+    /**
+     * Computes the radius of the circumcircle of a triangle.
+     * <p>
+     * This method uses {@link DD} extended-precision arithmetic for the
+     * side lengths and the area, so it provides more accurate results
+     * than {@link #circumradius(Coordinate, Coordinate, Coordinate)}
+     * for very thin (sliver) triangles.
+     *
+     * @param a a vertex of the triangle
+     * @param b a vertex of the triangle
+     * @param c a vertex of the triangle
+     * @return the circumradius of the triangle
+     */
+    pub fn circumradius_dd_coordinates(a: &Coordinate, b: &Coordinate, c: &Coordinate) -> f64 {
+        let ax = DD::value_of_f64(a.x).subtract_f64(c.x);
+        let ay = DD::value_of_f64(a.y).subtract_f64(c.y);
+        let bx = DD::value_of_f64(b.x).subtract_f64(c.x);
+        let by = DD::value_of_f64(b.y).subtract_f64(c.y);
+
+        // twice the signed area of the triangle
+        let det = DD::determinant_xy_dd(&ax, &ay, &bx, &by).abs();
+        if det.is_zero() {
+            return f64::INFINITY;
+        }
+
+        let len_ab = DD::hypot(&ax.subtract_dd(&bx), &ay.subtract_dd(&by));
+        let len_bc = DD::hypot(&bx, &by);
+        let len_ca = DD::hypot(&ax, &ay);
+        return len_ab
+            .multiply_dd(&len_bc)
+            .multiply_dd(&len_ca)
+            .divide_dd(&det.multiply_f64(2.))
+            .double_value();
+    }
+
     /**
      * Computes the circumcentre of a triangle. The circumcentre is the centre of
      * the circumcircle, the smallest circle which encloses the triangle. It is
@@ -270,6 +305,61 @@ impl Triangle {
         return Coordinate::new_xy(ccx, ccy);
     }
 
+    /**
+     * Tests if a point is inside the circumcircle of a triangle.
+     * The triangle may have either orientation.
+     * Points on the circumcircle are not considered to be inside it.
+     * <p>
+     * This test is robust: it evaluates the in-circle determinant
+     * using {@link DD} extended-precision arithmetic, after translating
+     * the triangle vertices so that the test point is at the origin
+     * (See <i>Lecture Notes on Geometric Robustness</i>,
+     * Jonathan Richard Shewchuk, 1999).
+     * A degenerate (collinear) triangle has no interior, so no point is inside it.
+     *
+     * @param a
+     *          a vertex of the triangle
+     * @param b
+     *          a vertex of the triangle
+     * @param c
+     *          a vertex of the triangle
+     * @param p
+     *          the point to test
+     * @return true if the point is strictly inside the circumcircle
+     */
+    pub fn is_in_circle_coordinates(
+        a: &Coordinate,
+        b: &Coordinate,
+        c: &Coordinate,
+        p: &Coordinate,
+    ) -> bool {
+        let orientation = Orientation::index(a, b, c);
+        if orientation == Orientation::COLLINEAR {
+            return false;
+        }
+
+        let adx = DD::value_of_f64(a.x).subtract_f64(p.x);
+        let ady = DD::value_of_f64(a.y).subtract_f64(p.y);
+        let bdx = DD::value_of_f64(b.x).subtract_f64(p.x);
+        let bdy = DD::value_of_f64(b.y).subtract_f64(p.y);
+        let cdx = DD::value_of_f64(c.x).subtract_f64(p.x);
+        let cdy = DD::value_of_f64(c.y).subtract_f64(p.y);
+
+        let abdet = DD::determinant_xy_dd(&adx, &ady, &bdx, &bdy);
+        let bcdet = DD::determinant_xy_dd(&bdx, &bdy, &cdx, &cdy);
+        let cadet = DD::determinant_xy_dd(&cdx, &cdy, &adx, &ady);
+        let alift = adx.sqr().add_dd(&ady.sqr());
+        let blift = bdx.sqr().add_dd(&bdy.sqr());
+        let clift = cdx.sqr().add_dd(&cdy.sqr());
+
+        let sum = alift
+            .multiply_dd(&bcdet)
+            .add_dd(&blift.multiply_dd(&cadet))
+            .add_dd(&clift.multiply_dd(&abdet));
+        // the determinant is positive for a point inside a CCW triangle's circle
+        return sum.signum() * orientation > 0;
+    }
+
     /**
      * Computes the determinant of a 2x2 matrix. Uses standard double-precision
      * arithmetic, so is susceptible to round-off error.
@@ -493,6 +583,9 @@ impl Triangle {
      * <p>
      * This method can be used to interpolate the Z-value of a point inside a
      * triangle (for example, of a TIN facet with elevations on the vertices).
+     * <p>
+     * If the triangle is degenerate the plane is undefined,
+     * and NaN is returned.
      *
      * @param p
      *          the point to compute the Z-value of
@@ -502,7 +595,8 @@ impl Triangle {
      *          a vertex of a triangle, with a Z ordinate
      * @param v2
      *          a vertex of a triangle, with a Z ordinate
-     * @return the computed Z-value (elevation) of the point
+     * @return the computed Z-value (elevation) of the point,
+     *          or NaN if the triangle is degenerate
      */
     pub fn interpolate_z_coordinates(
         p: &Coordinate,
@@ -517,6 +611,9 @@ impl Triangle {
         let c = v1.y - y0;
         let d = v2.y - y0;
         let det = a * d - b * c;
+        if det == 0.0 {
+            return f64::NAN;
+        }
         let dx = p.x - x0;
         let dy = p.y - y0;
         let t = (d * dx - b * dy) / det;
@@ -561,6 +658,17 @@ impl Triangle {
         return Triangle::is_ccw_coordinates(&self.p0, &self.p1, &self.p2);
     }
 
+    /**
+     * Tests if a point is strictly inside the circumcircle of this triangle.
+     * The test is robust.
+     *
+     * @param p the point to test
+     * @return true if the point is inside the circumcircle
+     */
+    pub fn is_in_circle(&self, p: &Coordinate) -> bool {
+        return Triangle::is_in_circle_coordinates(&self.p0, &self.p1, &self.p2, p);
+    }
+
     /**
      * Computes the circumcentre of this triangle. The circumcentre is the centre
      * of the circumcircle, the smallest circle which passes through all the triangle vertices.
@@ -580,6 +688,41 @@ impl Triangle {
         return Triangle::circumcentre_coordinates(&self.p0, &self.p1, &self.p2);
     }
 
+    /**
+     * Computes the circumcentre of this triangle, using double-double precision arithmetic.
+     * This is more accurate than {@link #circumcentre()}
+     * for triangles which are nearly degenerate.
+     *
+     * @return the circumcentre of this triangle
+     * @see #circumcentreDD(Coordinate, Coordinate, Coordinate)
+     */
+    pub fn circumcentre_dd(&self) -> Coordinate {
+        return Triangle::circumcentre_dd_coordinates(&self.p0, &self.p1, &self.p2);
+    }
+
+    /**
+     * Computes the line which is the perpendicular bisector of a side of this triangle.
+     * The side is given by the indexes of its vertices.
+     *
+     * @param i the index of a vertex of the side (0, 1 or 2)
+     * @param j the index of the other vertex of the side (0, 1 or 2)
+     * @return the perpendicular bisector, as an HCoordinate
+     */
+    pub fn perpendicular_bisector(&self, i: usize, j: usize) -> HCoordinate {
+        let pts = [self.p0, self.p1, self.p2];
+        return Triangle::perpendicular_bisector_coordinates(&pts[i], &pts[j]);
+    }
+
+    /**
+     * Computes the point at which the bisector of the angle at vertex p1
+     * cuts the opposite side p0-p2.
+     *
+     * @return the angle bisector cut point
+     */
+    pub fn angle_bisector(&self) -> Coordinate {
+        return Triangle::angle_bisector_coordinates(&self.p0, &self.p1, &self.p2);
+    }
+
     /**
      * Computes the radius of the circumcircle of a triangle.
      *
@@ -650,6 +793,18 @@ impl Triangle {
         return Triangle::signed_area_coordinates(&self.p0, &self.p1, &self.p2);
     }
 
+    /**
+     * Tests whether this triangle is degenerate,
+     * meaning that its vertices are collinear and it encloses no area.
+     * Degenerate triangles do not define a plane,
+     * so they cannot be used for interpolation.
+     *
+     * @return true if this triangle has zero area
+     */
+    pub fn is_degenerate(&self) -> bool {
+        return self.signed_area() == 0.0;
+    }
+
     /**
      * Computes the 3D area of this triangle. The value computed is always
      * non-negative.
@@ -668,10 +823,14 @@ impl Triangle {
      * <p>
      * This method can be used to interpolate the Z-value of a point inside this
      * triangle (for example, of a TIN facet with elevations on the vertices).
+     * <p>
+     * If this triangle is degenerate NaN is returned.
      *
      * @param p
      *          the point to compute the Z-value of
-     * @return the computed Z-value (elevation) of the point
+     * @return the computed Z-value (elevation) of the point,
+     *          or NaN if this triangle is degenerate
+     * @see #isDegenerate()
      */
     pub fn interpolate_z(&self, p: &Coordinate) -> f64 {
         return Triangle::interpolate_z_coordinates(p, &self.p0, &self.p1, &self.p2);
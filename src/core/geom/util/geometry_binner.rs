@@ -0,0 +1,118 @@
+use crate::core::geom::{envelope::Envelope, geometry::Geometry};
+
+/**
+ * Assigns geometries to the cells of a regular grid covering an extent,
+ * based on the envelope of each geometry.
+ * A geometry is assigned to every cell its envelope intersects,
+ * so a geometry may be assigned to several cells.
+ * Cells are closed, so an envelope which touches a cell boundary
+ * is assigned to the cells on both sides.
+ * Parts of an envelope which lie outside the extent are ignored.
+ * <p>
+ * This can be used to partition a dataset spatially,
+ * for example to process each cell independently.
+ */
+pub struct GeometryBinner {
+    extent: Envelope,
+    cols: usize,
+    rows: usize,
+    cell_width: f64,
+    cell_height: f64,
+}
+
+impl GeometryBinner {
+    /**
+     * Creates a binner for a grid of cells covering an extent.
+     *
+     * @param extent the extent covered by the grid
+     * @param cols the number of columns in the grid
+     * @param rows the number of rows in the grid
+     */
+    pub fn new(extent: &Envelope, cols: usize, rows: usize) -> Self {
+        assert!(cols > 0 && rows > 0, "Grid must have at least one cell");
+        Self {
+            extent: *extent,
+            cols,
+            rows,
+            cell_width: extent.get_width() / cols as f64,
+            cell_height: extent.get_height() / rows as f64,
+        }
+    }
+
+    /**
+     * Gets the envelope of a grid cell.
+     *
+     * @param col the column of the cell
+     * @param row the row of the cell
+     * @return the envelope of the cell
+     */
+    pub fn get_cell_envelope(&self, col: usize, row: usize) -> Envelope {
+        let min_x = self.extent.get_min_x() + col as f64 * self.cell_width;
+        let min_y = self.extent.get_min_y() + row as f64 * self.cell_height;
+        // use the extent bounds for the last cells to avoid round-off
+        let max_x = if col + 1 == self.cols {
+            self.extent.get_max_x()
+        } else {
+            min_x + self.cell_width
+        };
+        let max_y = if row + 1 == self.rows {
+            self.extent.get_max_y()
+        } else {
+            min_y + self.cell_height
+        };
+        return Envelope::new_xy(min_x, max_x, min_y, max_y);
+    }
+
+    /**
+     * Computes the grid cells which the envelope of a geometry intersects.
+     *
+     * @param geom the geometry to bin
+     * @return the (column, row) indices of the intersected cells
+     */
+    pub fn bin(&self, geom: &Geometry) -> Vec<(usize, usize)> {
+        let env = geom
+            .compute_envelope_internal()
+            .intersection_envelope(&self.extent);
+        if env.is_null() {
+            return vec![];
+        }
+        let (col_min, col_max) = GeometryBinner::cell_range(
+            env.get_min_x() - self.extent.get_min_x(),
+            env.get_max_x() - self.extent.get_min_x(),
+            self.cell_width,
+            self.cols,
+        );
+        let (row_min, row_max) = GeometryBinner::cell_range(
+            env.get_min_y() - self.extent.get_min_y(),
+            env.get_max_y() - self.extent.get_min_y(),
+            self.cell_height,
+            self.rows,
+        );
+
+        let mut cells: Vec<(usize, usize)> = vec![];
+        for col in col_min..=col_max {
+            for row in row_min..=row_max {
+                if self.get_cell_envelope(col, row).intersects_envelope(&env) {
+                    cells.push((col, row));
+                }
+            }
+        }
+        return cells;
+    }
+
+    /**
+     * Computes a range of cell indices which covers an interval,
+     * widened by one cell on each side so that cells touching
+     * the interval are included despite round-off.
+     * Candidate cells are filtered by an exact envelope test.
+     */
+    fn cell_range(min: f64, max: f64, cell_size: f64, num_cells: usize) -> (usize, usize) {
+        if cell_size <= 0. {
+            return (0, num_cells - 1);
+        }
+        let lo = f64::floor(min / cell_size) - 1.;
+        let hi = f64::floor(max / cell_size) + 1.;
+        let last = (num_cells - 1) as f64;
+        return (lo.clamp(0., last) as usize, hi.clamp(0., last) as usize);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::core::{
+    algorithm::angle::Angle,
+    geom::{coordinate::Coordinate, quadrant::Quadrant},
+};
+
+/**
+ * Models the end of an edge incident on a node.
+ * EdgeEnds have a direction
+ * determined by the direction of the ray from the initial
+ * point to the next point.
+ * EdgeEnds are ordered around a node by
+ * the angle of their direction with the x-axis
+ * (see {@link EdgeStar}).
+ * @version 1.7
+ */
+#[derive(Clone, Copy)]
+pub struct EdgeEnd {
+    /**
+     * the node this edge end originates at
+     */
+    p0: Coordinate,
+    /**
+     * points of initial line segment
+     */
+    p1: Coordinate,
+    /**
+     * the direction vector for this edge from its starting point
+     */
+    dx: f64,
+    dy: f64,
+    quadrant: i32,
+}
+
+impl EdgeEnd {
+    pub fn new(p0: &Coordinate, p1: &Coordinate) -> Self {
+        let dx = p1.x - p0.x;
+        let dy = p1.y - p0.y;
+        Self {
+            p0: *p0,
+            p1: *p1,
+            dx,
+            dy,
+            quadrant: Quadrant::quadrant_xy(dx, dy),
+        }
+    }
+
+    pub fn get_coordinate(&self) -> Coordinate {
+        return self.p0;
+    }
+
+    pub fn get_directed_coordinate(&self) -> Coordinate {
+        return self.p1;
+    }
+
+    pub fn get_quadrant(&self) -> i32 {
+        return self.quadrant;
+    }
+
+    pub fn get_dx(&self) -> f64 {
+        return self.dx;
+    }
+
+    pub fn get_dy(&self) -> f64 {
+        return self.dy;
+    }
+
+    /**
+     * Gets the angle of this edge end with the positive x-axis,
+     * in the range (-Pi, Pi].
+     *
+     * @return the angle of the edge direction, in radians
+     */
+    pub fn get_angle(&self) -> f64 {
+        return Angle::angle_coordinates(&self.p0, &self.p1);
+    }
+
+    /**
+     * Tests whether this edge end has the same origin and direction point
+     * as another.
+     */
+    pub fn equals(&self, e: &EdgeEnd) -> bool {
+        return self.p0.equals_2d(&e.p0) && self.p1.equals_2d(&e.p1);
+    }
+}
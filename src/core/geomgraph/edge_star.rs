@@ -0,0 +1,100 @@
+use crate::core::geom::coordinate::Coordinate;
+
+use super::edge_end::EdgeEnd;
+
+/**
+ * A collection of {@link EdgeEnd}s incident on a single node,
+ * kept sorted in counter-clockwise order of their angle
+ * with the positive x-axis (as computed by {@link Angle#angle}).
+ * <p>
+ * The star supports traversing around the node
+ * to the next edge in clockwise or counter-clockwise order,
+ * which is the basic step used to extract minimal rings
+ * from a planar graph.
+ *
+ * @version 1.7
+ */
+pub struct EdgeStar {
+    /**
+     * the node the edges originate at
+     */
+    coord: Coordinate,
+    /**
+     * the edges, sorted by increasing angle
+     */
+    edge_list: Vec<EdgeEnd>,
+}
+
+impl EdgeStar {
+    pub fn new(coord: &Coordinate) -> Self {
+        Self {
+            coord: *coord,
+            edge_list: vec![],
+        }
+    }
+
+    /**
+     * @return the coordinate for the node this star is based at
+     */
+    pub fn get_coordinate(&self) -> Coordinate {
+        return self.coord;
+    }
+
+    pub fn get_degree(&self) -> usize {
+        return self.edge_list.len();
+    }
+
+    /**
+     * Returns the edges around the node, in counter-clockwise order
+     * starting from the direction of the negative x-axis.
+     */
+    pub fn get_edges(&self) -> &Vec<EdgeEnd> {
+        return &self.edge_list;
+    }
+
+    /**
+     * Inserts an EdgeEnd into this star, keeping the edges sorted by angle.
+     * Edges with equal angles are kept in insertion order.
+     */
+    pub fn insert(&mut self, e: EdgeEnd) {
+        let angle = e.get_angle();
+        let index = self
+            .edge_list
+            .partition_point(|other| other.get_angle() <= angle);
+        self.edge_list.insert(index, e);
+    }
+
+    pub fn find_index(&self, e_search: &EdgeEnd) -> Option<usize> {
+        return self.edge_list.iter().position(|e| e.equals(e_search));
+    }
+
+    /**
+     * Gets the edge which follows the given edge in clockwise order
+     * around the node.
+     *
+     * @param ee an edge in this star
+     * @return the next edge clockwise, or None if the edge is not in the star
+     */
+    pub fn next_cw(&self, ee: &EdgeEnd) -> Option<&EdgeEnd> {
+        let i = self.find_index(ee)?;
+        let i_next_cw = if i == 0 {
+            self.edge_list.len() - 1
+        } else {
+            i - 1
+        };
+        return Some(&self.edge_list[i_next_cw]);
+    }
+
+    /**
+     * Gets the edge which follows the given edge in counter-clockwise order
+     * around the node.
+     *
+     * @param ee an edge in this star
+     * @return the next edge counter-clockwise, or None if the edge is not in the star
+     */
+    pub fn next_ccw(&self, ee: &EdgeEnd) -> Option<&EdgeEnd> {
+        let i = self.find_index(ee)?;
+        let i_next_ccw = (i + 1) % self.edge_list.len();
+        return Some(&self.edge_list[i_next_ccw]);
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod edge_end;
+pub(crate) mod edge_star;
@@ -0,0 +1,2 @@
+pub(crate) mod quadtree;
+pub(crate) mod strtree;
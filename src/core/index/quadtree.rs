@@ -0,0 +1,572 @@
+use std::{cmp::Ordering, cmp::Reverse, collections::BinaryHeap};
+
+use crate::core::geom::{coordinate::Coordinate, envelope::Envelope};
+
+/**
+ * A Quadtree is a spatial index structure for efficient range querying
+ * of items bounded by 2D rectangles.
+ * {@link Geometry}s can be indexed by using their
+ * {@link Envelope}s.
+ * Any type of item can also be indexed as
+ * long as it has an extent that can be represented by an {@link Envelope}.
+ * <p>
+ * This Quadtree index provides a <b>primary filter</b>
+ * for range rectangle queries.
+ * The query method returns a list of
+ * all objects which <i>may</i> intersect the query rectangle.
+ * Note that it may thus return objects which do <b>not</b> in fact intersect the query rectangle.
+ * A secondary filter is required to test for actual intersection
+ * between the query rectangle and the envelope of each candidate item.
+ * <p>
+ * This implementation does not require specifying the extent of the inserted
+ * items beforehand.  It will automatically expand to accommodate any extent
+ * of dataset.
+ * <p>
+ * Items with a null envelope are stored in the root of the tree,
+ * so they are returned by every query.
+ *
+ * @version 1.7
+ */
+pub struct Quadtree<T> {
+    root: QuadtreeNode,
+    items: Vec<(Envelope, T)>,
+    /**
+     * minExtent is the minimum envelope extent of all items
+     * inserted into the tree so far. It is used as a heuristic value
+     * to construct non-zero envelopes for features with zero X and/or Y extent.
+     * Start with a non-zero extent, in case the first feature inserted has
+     * a zero extent in both directions.  This value may be non-optimal, but
+     * only one feature will be inserted with this value.
+     **/
+    min_extent: f64,
+}
+
+/**
+ * Represents a node of a {@link Quadtree}.  Nodes contain
+ * items which have a spatial extent corresponding to the node's position
+ * in the quadtree.
+ * The root node has no envelope, and is centred on the origin.
+ */
+struct QuadtreeNode {
+    env: Option<Envelope>,
+    centre_x: f64,
+    centre_y: f64,
+    level: i32,
+    items: Vec<usize>,
+    /**
+     * subquads are numbered as follows:
+     * <pre>
+     *  2 | 3
+     *  --+--
+     *  0 | 1
+     * </pre>
+     */
+    subnodes: [Option<Box<QuadtreeNode>>; 4],
+}
+
+/**
+ * An entry in the priority queue used for nearest neighbour search.
+ */
+struct NearestEntry<'a> {
+    distance: f64,
+    target: NearestTarget<'a>,
+}
+
+enum NearestTarget<'a> {
+    Item(usize),
+    Node(&'a QuadtreeNode),
+}
+
+impl Ord for NearestEntry<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // at equal distances items are taken before nodes
+        return self.distance.total_cmp(&other.distance).then_with(|| {
+            match (&self.target, &other.target) {
+                (NearestTarget::Item(a), NearestTarget::Item(b)) => a.cmp(b),
+                (NearestTarget::Item(_), NearestTarget::Node(_)) => Ordering::Less,
+                (NearestTarget::Node(_), NearestTarget::Item(_)) => Ordering::Greater,
+                (NearestTarget::Node(_), NearestTarget::Node(_)) => Ordering::Equal,
+            }
+        });
+    }
+}
+
+impl PartialOrd for NearestEntry<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl PartialEq for NearestEntry<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for NearestEntry<'_> {}
+
+impl<T> Quadtree<T> {
+    /**
+     * Constructs a Quadtree with zero items.
+     */
+    pub fn new() -> Self {
+        Self {
+            root: QuadtreeNode::new_root(),
+            items: vec![],
+            min_extent: 1.0,
+        }
+    }
+
+    /**
+     * Returns the number of items in the tree.
+     *
+     * @return the number of items in the tree
+     */
+    pub fn size(&self) -> usize {
+        return self.items.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.items.is_empty();
+    }
+
+    /**
+     * Returns the number of levels in the tree.
+     */
+    pub fn depth(&self) -> usize {
+        return self.root.depth();
+    }
+
+    /**
+     * Inserts an item into the tree, with an extent given by an {@link Envelope}.
+     *
+     * @param itemEnv the envelope of the item
+     * @param item the item to insert
+     */
+    pub fn insert(&mut self, item_env: &Envelope, item: T) {
+        let index = self.items.len();
+        self.items.push((*item_env, item));
+        if item_env.is_null() {
+            self.root.items.push(index);
+            return;
+        }
+        self.collect_stats(item_env);
+        let insert_env = Quadtree::<T>::ensure_extent(item_env, self.min_extent);
+        self.root.insert_root(&insert_env, index);
+    }
+
+    /**
+     * Ensure that the envelope for the inserted item has non-zero extents.
+     * Use the current minExtent to pad the envelope, if necessary
+     */
+    fn ensure_extent(item_env: &Envelope, min_extent: f64) -> Envelope {
+        let mut minx = item_env.get_min_x();
+        let mut maxx = item_env.get_max_x();
+        let mut miny = item_env.get_min_y();
+        let mut maxy = item_env.get_max_y();
+        // has a non-zero extent
+        if minx != maxx && miny != maxy {
+            return *item_env;
+        }
+
+        // pad one or both extents
+        if minx == maxx {
+            minx -= min_extent / 2.0;
+            maxx += min_extent / 2.0;
+        }
+        if miny == maxy {
+            miny -= min_extent / 2.0;
+            maxy += min_extent / 2.0;
+        }
+        return Envelope::new_xy(minx, maxx, miny, maxy);
+    }
+
+    fn collect_stats(&mut self, item_env: &Envelope) {
+        let del_x = item_env.get_width();
+        if del_x < self.min_extent && del_x > 0.0 {
+            self.min_extent = del_x;
+        }
+
+        let del_y = item_env.get_height();
+        if del_y < self.min_extent && del_y > 0.0 {
+            self.min_extent = del_y;
+        }
+    }
+
+    /**
+     * Queries the tree and returns items which may lie in the given search envelope.
+     * Precisely, the items that are returned are all items in the tree
+     * whose envelope <b>may</b> intersect the search Envelope.
+     * Note that some items with non-intersecting envelopes may be returned as well;
+     * the client is responsible for filtering these out.
+     *
+     * @param searchEnv the envelope of the desired query area.
+     * @return a List of items which may intersect the search envelope
+     */
+    pub fn query(&self, search_env: &Envelope) -> Vec<&T> {
+        let mut indexes: Vec<usize> = vec![];
+        self.root
+            .add_all_items_from_overlapping(search_env, &mut indexes);
+        return indexes.iter().map(|&i| &self.items[i].1).collect();
+    }
+
+    /**
+     * Return a list of all items in the Quadtree
+     */
+    pub fn query_all(&self) -> Vec<&T> {
+        return self.items.iter().map(|(_, item)| item).collect();
+    }
+
+    /**
+     * Finds the item whose envelope is nearest to a point.
+     * <p>
+     * The tree is searched best-first, using a priority queue of nodes and items
+     * ordered by the distance from the point to their envelopes.
+     * Since the distance to a node is a lower bound on the distance
+     * to any item it contains, the first item removed from the queue is the nearest,
+     * and subtrees which are further away than it are never expanded.
+     * Items with a null envelope are at distance 0 from every point.
+     *
+     * @param p the point to search from
+     * @return the item with the nearest envelope, or None if the tree is empty
+     */
+    pub fn nearest(&self, p: &Coordinate) -> Option<&T> {
+        let mut queue: BinaryHeap<Reverse<NearestEntry>> = BinaryHeap::new();
+        self.push_node_contents(&self.root, p, &mut queue);
+
+        while let Some(Reverse(entry)) = queue.pop() {
+            match entry.target {
+                NearestTarget::Item(i) => return Some(&self.items[i].1),
+                NearestTarget::Node(node) => self.push_node_contents(node, p, &mut queue),
+            }
+        }
+        return None;
+    }
+
+    fn push_node_contents<'a>(
+        &self,
+        node: &'a QuadtreeNode,
+        p: &Coordinate,
+        queue: &mut BinaryHeap<Reverse<NearestEntry<'a>>>,
+    ) {
+        for &i in &node.items {
+            queue.push(Reverse(NearestEntry {
+                distance: Quadtree::<T>::distance(&self.items[i].0, p),
+                target: NearestTarget::Item(i),
+            }));
+        }
+        for subnode in node.subnodes.iter().flatten() {
+            queue.push(Reverse(NearestEntry {
+                distance: Quadtree::<T>::distance(subnode.get_envelope(), p),
+                target: NearestTarget::Node(subnode),
+            }));
+        }
+    }
+
+    fn distance(env: &Envelope, p: &Coordinate) -> f64 {
+        if env.is_null() {
+            return 0.0;
+        }
+        return env.distance_to_coordinate(p);
+    }
+}
+
+impl QuadtreeNode {
+    /**
+     * The smallest exponent for which an interval is considered to have zero width
+     * relative to its magnitude.
+     */
+    const MIN_BINARY_EXPONENT: i32 = -50;
+
+    fn new_root() -> Self {
+        Self {
+            env: None,
+            centre_x: 0.0,
+            centre_y: 0.0,
+            level: 0,
+            items: vec![],
+            subnodes: [None, None, None, None],
+        }
+    }
+
+    fn new(env: Envelope, level: i32) -> Self {
+        Self {
+            env: Some(env),
+            centre_x: (env.get_min_x() + env.get_max_x()) / 2.0,
+            centre_y: (env.get_min_y() + env.get_max_y()) / 2.0,
+            level,
+            items: vec![],
+            subnodes: [None, None, None, None],
+        }
+    }
+
+    fn get_envelope(&self) -> &Envelope {
+        return self.env.as_ref().expect("Root node has no envelope");
+    }
+
+    /**
+     * Gets the index of the subquad that wholly contains the given envelope.
+     * If none does, returns -1.
+     *
+     * @return the index of the subquad that wholly contains the given envelope
+     * or -1 if no subquad wholly contains the envelope
+     */
+    fn get_subnode_index(env: &Envelope, centre_x: f64, centre_y: f64) -> i32 {
+        let mut subnode_index = -1;
+        if env.get_min_x() >= centre_x {
+            if env.get_min_y() >= centre_y {
+                subnode_index = 3;
+            }
+            if env.get_max_y() <= centre_y {
+                subnode_index = 1;
+            }
+        }
+        if env.get_max_x() <= centre_x {
+            if env.get_min_y() >= centre_y {
+                subnode_index = 2;
+            }
+            if env.get_max_y() <= centre_y {
+                subnode_index = 0;
+            }
+        }
+        return subnode_index;
+    }
+
+    /**
+     * Insert an item into the quadtree this is the root of.
+     */
+    fn insert_root(&mut self, item_env: &Envelope, item: usize) {
+        let index = QuadtreeNode::get_subnode_index(item_env, self.centre_x, self.centre_y);
+        // if index is -1, itemEnv must cross the X or Y axis.
+        if index == -1 {
+            self.items.push(item);
+            return;
+        }
+        let index = index as usize;
+        /*
+         * the item must be contained in one quadrant, so insert it into the
+         * tree for that quadrant (which may not yet exist)
+         */
+        let node = self.subnodes[index].take();
+        /*
+         *  If the subquad doesn't exist or this item is not contained in it,
+         *  have to expand the tree upward to contain the item.
+         */
+        let node = match node {
+            Some(node) if node.get_envelope().covers_envelope(item_env) => node,
+            node => Box::new(QuadtreeNode::create_expanded(node, item_env)),
+        };
+        /*
+         * At this point we have a subquad which exists and must contain
+         * contains the env for the item.  Insert the item into the tree.
+         */
+        let node = self.subnodes[index].insert(node);
+        QuadtreeNode::insert_contained(node, item_env, item);
+    }
+
+    /**
+     * insert an item which is known to be contained in the tree rooted at
+     * the given QuadNode root.  Lower levels of the tree will be created
+     * if necessary to hold the item.
+     */
+    fn insert_contained(tree: &mut QuadtreeNode, item_env: &Envelope, item: usize) {
+        /*
+         * Do NOT create a new quad for zero-area envelopes - this would lead
+         * to infinite recursion. Instead, use a heuristic of simply returning
+         * the smallest existing quad containing the query
+         */
+        let is_zero_x = QuadtreeNode::is_zero_width(item_env.get_min_x(), item_env.get_max_x());
+        let is_zero_y = QuadtreeNode::is_zero_width(item_env.get_min_y(), item_env.get_max_y());
+        let node = if is_zero_x || is_zero_y {
+            tree.find(item_env)
+        } else {
+            tree.get_node(item_env)
+        };
+        node.items.push(item);
+    }
+
+    /**
+     * Computes whether the interval [min, max] is effectively zero width.
+     * I.e. the width of the interval is so much less than the
+     * location of the interval that the midpoint of the interval cannot be
+     * represented precisely.
+     */
+    fn is_zero_width(min: f64, max: f64) -> bool {
+        let width = max - min;
+        if width == 0.0 {
+            return true;
+        }
+
+        let max_abs = f64::max(min.abs(), max.abs());
+        let scaled_interval = width / max_abs;
+        let level = QuadtreeNode::exponent(scaled_interval);
+        return level <= QuadtreeNode::MIN_BINARY_EXPONENT;
+    }
+
+    /**
+     * Determines the exponent for a number
+     *
+     * @return the exponent for the number
+     */
+    fn exponent(d: f64) -> i32 {
+        let signed_exponent = ((d.to_bits() >> 52) & 0x07ff) as i32;
+        return signed_exponent - 1023;
+    }
+
+    /**
+     * Computes the level and envelope of the smallest quad-aligned
+     * square which contains the given envelope.
+     * The level is the power of two giving the size of the square.
+     */
+    fn compute_key(item_env: &Envelope) -> (Envelope, i32) {
+        let dx = item_env.get_width();
+        let dy = item_env.get_height();
+        let d_max = f64::max(dx, dy);
+        let mut level = QuadtreeNode::exponent(d_max) + 1;
+        let mut key_env = QuadtreeNode::compute_key_envelope(level, item_env);
+        while !key_env.covers_envelope(item_env) {
+            level += 1;
+            key_env = QuadtreeNode::compute_key_envelope(level, item_env);
+        }
+        return (key_env, level);
+    }
+
+    fn compute_key_envelope(level: i32, item_env: &Envelope) -> Envelope {
+        let quad_size = f64::powi(2.0, level);
+        let x = f64::floor(item_env.get_min_x() / quad_size) * quad_size;
+        let y = f64::floor(item_env.get_min_y() / quad_size) * quad_size;
+        return Envelope::new_xy(x, x + quad_size, y, y + quad_size);
+    }
+
+    fn create_node(env: &Envelope) -> QuadtreeNode {
+        let (key_env, level) = QuadtreeNode::compute_key(env);
+        return QuadtreeNode::new(key_env, level);
+    }
+
+    fn create_expanded(node: Option<Box<QuadtreeNode>>, add_env: &Envelope) -> QuadtreeNode {
+        let mut expand_env = *add_env;
+        if let Some(node) = &node {
+            expand_env.expand_to_include_envelope(node.get_envelope());
+        }
+
+        let mut larger_node = QuadtreeNode::create_node(&expand_env);
+        if let Some(node) = node {
+            larger_node.insert_node(node);
+        }
+        return larger_node;
+    }
+
+    fn insert_node(&mut self, node: Box<QuadtreeNode>) {
+        let index =
+            QuadtreeNode::get_subnode_index(node.get_envelope(), self.centre_x, self.centre_y)
+                as usize;
+        if node.level == self.level - 1 {
+            self.subnodes[index] = Some(node);
+        } else {
+            // the quad is not a direct child, so make a new child quad to contain it
+            // and recursively insert the quad
+            let mut child_node = self.create_subnode(index);
+            child_node.insert_node(node);
+            self.subnodes[index] = Some(Box::new(child_node));
+        }
+    }
+
+    /**
+     * Returns the subquad containing the envelope <tt>searchEnv</tt>.
+     * Creates the subquad if
+     * it does not already exist.
+     *
+     * @return the subquad containing the search envelope
+     */
+    fn get_node(&mut self, search_env: &Envelope) -> &mut QuadtreeNode {
+        let index = QuadtreeNode::get_subnode_index(search_env, self.centre_x, self.centre_y);
+        // if subquadIndex is -1 searchEnv is not contained in a subquad
+        if index != -1 {
+            let index = index as usize;
+            if self.subnodes[index].is_none() {
+                self.subnodes[index] = Some(Box::new(self.create_subnode(index)));
+            }
+            // insert into the subquad
+            return self.subnodes[index].as_mut().unwrap().get_node(search_env);
+        }
+        return self;
+    }
+
+    /**
+     * Returns the smallest <i>existing</i>
+     * node containing the envelope.
+     */
+    fn find(&mut self, search_env: &Envelope) -> &mut QuadtreeNode {
+        let index = QuadtreeNode::get_subnode_index(search_env, self.centre_x, self.centre_y);
+        if index == -1 || self.subnodes[index as usize].is_none() {
+            return self;
+        }
+        // query lies in subquad, so search it
+        return self.subnodes[index as usize]
+            .as_mut()
+            .unwrap()
+            .find(search_env);
+    }
+
+    fn create_subnode(&self, index: usize) -> QuadtreeNode {
+        let env = self.get_envelope();
+        // create a new subquad in the appropriate quadrant
+        let (minx, maxx, miny, maxy) = match index {
+            0 => (
+                env.get_min_x(),
+                self.centre_x,
+                env.get_min_y(),
+                self.centre_y,
+            ),
+            1 => (
+                self.centre_x,
+                env.get_max_x(),
+                env.get_min_y(),
+                self.centre_y,
+            ),
+            2 => (
+                env.get_min_x(),
+                self.centre_x,
+                self.centre_y,
+                env.get_max_y(),
+            ),
+            _ => (
+                self.centre_x,
+                env.get_max_x(),
+                self.centre_y,
+                env.get_max_y(),
+            ),
+        };
+        return QuadtreeNode::new(Envelope::new_xy(minx, maxx, miny, maxy), self.level - 1);
+    }
+
+    fn is_search_match(&self, search_env: &Envelope) -> bool {
+        return match &self.env {
+            // the root node matches all searches
+            None => true,
+            Some(env) => env.intersects_envelope(search_env),
+        };
+    }
+
+    fn add_all_items_from_overlapping(&self, search_env: &Envelope, result_items: &mut Vec<usize>) {
+        if !self.is_search_match(search_env) {
+            return;
+        }
+
+        // this node may have items as well as subnodes (since items may not
+        // be wholly contained in any single subnode
+        result_items.extend(&self.items);
+
+        for subnode in self.subnodes.iter().flatten() {
+            subnode.add_all_items_from_overlapping(search_env, result_items);
+        }
+    }
+
+    fn depth(&self) -> usize {
+        let mut max_sub_depth = 0;
+        for subnode in self.subnodes.iter().flatten() {
+            max_sub_depth = usize::max(max_sub_depth, subnode.depth());
+        }
+        return max_sub_depth + 1;
+    }
+}
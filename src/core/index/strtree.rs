@@ -0,0 +1,360 @@
+use std::cell::OnceCell;
+
+use crate::core::geom::envelope::Envelope;
+
+/**
+ *  A query-only R-tree created using the Sort-Tile-Recursive (STR) algorithm.
+ *  For two-dimensional spatial data.
+ * <P>
+ *  The STR packed R-tree is simple to implement and maximizes space
+ *  utilization; that is, as many leaves as possible are filled to capacity.
+ *  Overlap between nodes is far less than in a basic R-tree.
+ *  However, the index is semi-static; once the tree has been built
+ *  (which happens automatically upon the first query), items may
+ *  not be added.
+ *  <P>
+ *  Described in: P. Rigaux, Michel Scholl and Agnes Voisard.
+ * <i>Spatial Databases With Application To GIS</i>.
+ * Morgan Kaufmann, San Francisco, 2002.
+ *
+ * @version 1.7
+ */
+#[derive(Clone)]
+pub struct STRtree<T> {
+    node_capacity: usize,
+    item_boundables: Vec<(Envelope, T)>,
+    /**
+     * The packed nodes of the tree, built on first query.
+     * The root is the last node.
+     */
+    nodes: OnceCell<Vec<STRtreeNode>>,
+}
+
+/**
+ * A node of an {@link STRtree}.
+ * The children of a leaf node (level 0) are indices of item boundables;
+ * the children of higher-level nodes are indices of other nodes.
+ */
+#[derive(Clone)]
+struct STRtreeNode {
+    bounds: Envelope,
+    level: usize,
+    children: Vec<usize>,
+}
+
+/**
+ * A reference to either a node or an item of a tree, used while traversing.
+ */
+#[derive(Clone, Copy)]
+enum Boundable {
+    Node(usize),
+    Item(usize),
+}
+
+impl<T> STRtree<T> {
+    pub const DEFAULT_NODE_CAPACITY: usize = 10;
+
+    /**
+     * Constructs an STRtree with the default node capacity.
+     */
+    pub fn new() -> Self {
+        return STRtree::new_with_node_capacity(STRtree::<T>::DEFAULT_NODE_CAPACITY);
+    }
+
+    /**
+     * Constructs an STRtree with the given maximum number of child nodes that
+     * a node may have.
+     * <p>
+     * The minimum recommended capacity setting is 4.
+     *
+     */
+    pub fn new_with_node_capacity(node_capacity: usize) -> Self {
+        assert!(node_capacity > 1, "Node capacity must be greater than 1");
+        Self {
+            node_capacity,
+            item_boundables: vec![],
+            nodes: OnceCell::new(),
+        }
+    }
+
+    /**
+     * Returns the maximum number of child nodes that a node may have
+     */
+    pub fn get_node_capacity(&self) -> usize {
+        return self.node_capacity;
+    }
+
+    /**
+     * Returns the number of items in the tree.
+     *
+     * @return the number of items in the tree
+     */
+    pub fn size(&self) -> usize {
+        return self.item_boundables.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.item_boundables.is_empty();
+    }
+
+    /**
+     * Adds a spatial item with an extent specified by the given {@link Envelope} to the index
+     */
+    pub fn insert(&mut self, item_env: &Envelope, item: T) {
+        assert!(
+            self.nodes.get().is_none(),
+            "Cannot insert items into an STR packed R-tree after it has been built."
+        );
+        if item_env.is_null() {
+            return;
+        }
+        self.item_boundables.push((*item_env, item));
+    }
+
+    /**
+     * Creates parent nodes, grandparent nodes, and so forth up to the root
+     * node, for the data that has been inserted into the tree. Can only be
+     * called once, and thus can be called only after all of the data has been
+     * inserted into the tree.
+     */
+    pub fn build(&self) {
+        self.get_nodes();
+    }
+
+    fn get_nodes(&self) -> &Vec<STRtreeNode> {
+        return self.nodes.get_or_init(|| self.create_nodes());
+    }
+
+    fn create_nodes(&self) -> Vec<STRtreeNode> {
+        let mut nodes: Vec<STRtreeNode> = vec![];
+        if self.item_boundables.is_empty() {
+            return nodes;
+        }
+        let mut child_boundables: Vec<(Envelope, usize)> = self
+            .item_boundables
+            .iter()
+            .enumerate()
+            .map(|(i, (env, _))| (*env, i))
+            .collect();
+        let mut level = 0;
+        loop {
+            child_boundables = self.create_parent_boundables(child_boundables, level, &mut nodes);
+            if child_boundables.len() == 1 {
+                return nodes;
+            }
+            level += 1;
+        }
+    }
+
+    /**
+     * Creates the parent level for the given child level. First, orders the items
+     * by the x-values of the midpoints, and groups them into vertical slices.
+     * For each slice, orders the items by the y-values of the midpoints, and
+     * group them into runs of size M (the node capacity). For each run, creates
+     * a new (parent) node.
+     */
+    fn create_parent_boundables(
+        &self,
+        mut child_boundables: Vec<(Envelope, usize)>,
+        new_level: usize,
+        nodes: &mut Vec<STRtreeNode>,
+    ) -> Vec<(Envelope, usize)> {
+        let min_leaf_count =
+            (child_boundables.len() as f64 / self.node_capacity as f64).ceil() as usize;
+        let slice_count = (min_leaf_count as f64).sqrt().ceil() as usize;
+        let slice_capacity = (child_boundables.len() as f64 / slice_count as f64).ceil() as usize;
+
+        child_boundables
+            .sort_by(|a, b| STRtree::<T>::centre_x(&a.0).total_cmp(&STRtree::<T>::centre_x(&b.0)));
+
+        let mut parent_boundables: Vec<(Envelope, usize)> = vec![];
+        for slice in child_boundables.chunks_mut(slice_capacity) {
+            slice.sort_by(|a, b| {
+                STRtree::<T>::centre_y(&a.0).total_cmp(&STRtree::<T>::centre_y(&b.0))
+            });
+            for run in slice.chunks(self.node_capacity) {
+                let mut bounds = Envelope::default();
+                for (env, _) in run {
+                    bounds.expand_to_include_envelope(env);
+                }
+                nodes.push(STRtreeNode {
+                    bounds,
+                    level: new_level,
+                    children: run.iter().map(|(_, i)| *i).collect(),
+                });
+                parent_boundables.push((bounds, nodes.len() - 1));
+            }
+        }
+        return parent_boundables;
+    }
+
+    fn centre_x(env: &Envelope) -> f64 {
+        return (env.get_min_x() + env.get_max_x()) / 2.;
+    }
+
+    fn centre_y(env: &Envelope) -> f64 {
+        return (env.get_min_y() + env.get_max_y()) / 2.;
+    }
+
+    fn get_root(&self) -> Option<Boundable> {
+        let nodes = self.get_nodes();
+        if nodes.is_empty() {
+            return None;
+        }
+        return Some(Boundable::Node(nodes.len() - 1));
+    }
+
+    fn get_bounds(&self, boundable: Boundable) -> &Envelope {
+        return match boundable {
+            Boundable::Node(i) => &self.get_nodes()[i].bounds,
+            Boundable::Item(i) => &self.item_boundables[i].0,
+        };
+    }
+
+    fn get_child_boundables(&self, node: usize) -> Vec<Boundable> {
+        let node = &self.get_nodes()[node];
+        return node
+            .children
+            .iter()
+            .map(|&i| {
+                if node.level == 0 {
+                    Boundable::Item(i)
+                } else {
+                    Boundable::Node(i)
+                }
+            })
+            .collect();
+    }
+
+    /**
+     * Queries the index for all items whose extents intersect the given search {@link Envelope}
+     * Note that some kinds of indexes may also return objects which do not in fact
+     * intersect the query envelope.
+     *
+     * @param searchEnv the envelope to query for
+     * @return a list of the items found by the query
+     */
+    pub fn query(&self, search_env: &Envelope) -> Vec<&T> {
+        let mut matches: Vec<&T> = vec![];
+        self.query_visitor(search_env, |item| matches.push(item));
+        return matches;
+    }
+
+    /**
+     * Queries the index for all items whose extents intersect the given search {@link Envelope},
+     * and applies a visitor to them.
+     * Note that some kinds of indexes may also return objects which do not in fact
+     * intersect the query envelope.
+     *
+     * @param searchEnv the envelope to query for
+     * @param visitor a visitor object to apply to the items found
+     */
+    pub fn query_visitor<'a, F: FnMut(&'a T)>(&'a self, search_env: &Envelope, mut visitor: F) {
+        if let Some(root) = self.get_root() {
+            if self.get_bounds(root).intersects_envelope(search_env) {
+                self.query_internal(root, search_env, &mut visitor);
+            }
+        }
+    }
+
+    fn query_internal<'a, F: FnMut(&'a T)>(
+        &'a self,
+        boundable: Boundable,
+        search_env: &Envelope,
+        visitor: &mut F,
+    ) {
+        match boundable {
+            Boundable::Item(i) => visitor(&self.item_boundables[i].1),
+            Boundable::Node(i) => {
+                for child in self.get_child_boundables(i) {
+                    if self.get_bounds(child).intersects_envelope(search_env) {
+                        self.query_internal(child, search_env, visitor);
+                    }
+                }
+            }
+        }
+    }
+
+    /**
+     * Finds all pairs of items in this tree and another tree whose extents
+     * intersect, and applies a visitor to each pair.
+     * <p>
+     * The trees are traversed synchronously, descending only into
+     * pairs of nodes whose bounds intersect, so that the full product
+     * of the two item sets is never enumerated.
+     *
+     * @param other the tree to join with
+     * @param visitor the visitor to apply to each pair of intersecting items
+     */
+    pub fn spatial_join<U, F: FnMut(&T, &U)>(&self, other: &STRtree<U>, mut visitor: F) {
+        let (root1, root2) = match (self.get_root(), other.get_root()) {
+            (Some(root1), Some(root2)) => (root1, root2),
+            _ => return,
+        };
+        if self
+            .get_bounds(root1)
+            .intersects_envelope(other.get_bounds(root2))
+        {
+            self.spatial_join_internal(root1, other, root2, &mut visitor);
+        }
+    }
+
+    fn spatial_join_internal<U, F: FnMut(&T, &U)>(
+        &self,
+        boundable1: Boundable,
+        other: &STRtree<U>,
+        boundable2: Boundable,
+        visitor: &mut F,
+    ) {
+        match (boundable1, boundable2) {
+            (Boundable::Item(i), Boundable::Item(j)) => {
+                visitor(&self.item_boundables[i].1, &other.item_boundables[j].1);
+            }
+            (Boundable::Node(i), Boundable::Item(_)) => {
+                self.spatial_join_expand_first(i, other, boundable2, visitor);
+            }
+            (Boundable::Item(_), Boundable::Node(j)) => {
+                self.spatial_join_expand_second(boundable1, other, j, visitor);
+            }
+            (Boundable::Node(i), Boundable::Node(j)) => {
+                // expand the larger node, to keep the bounds being compared similar in size
+                if self.get_bounds(boundable1).get_area() >= other.get_bounds(boundable2).get_area()
+                {
+                    self.spatial_join_expand_first(i, other, boundable2, visitor);
+                } else {
+                    self.spatial_join_expand_second(boundable1, other, j, visitor);
+                }
+            }
+        }
+    }
+
+    fn spatial_join_expand_first<U, F: FnMut(&T, &U)>(
+        &self,
+        node1: usize,
+        other: &STRtree<U>,
+        boundable2: Boundable,
+        visitor: &mut F,
+    ) {
+        let bounds2 = other.get_bounds(boundable2);
+        for child in self.get_child_boundables(node1) {
+            if self.get_bounds(child).intersects_envelope(bounds2) {
+                self.spatial_join_internal(child, other, boundable2, visitor);
+            }
+        }
+    }
+
+    fn spatial_join_expand_second<U, F: FnMut(&T, &U)>(
+        &self,
+        boundable1: Boundable,
+        other: &STRtree<U>,
+        node2: usize,
+        visitor: &mut F,
+    ) {
+        let bounds1 = self.get_bounds(boundable1);
+        for child in other.get_child_boundables(node2) {
+            if other.get_bounds(child).intersects_envelope(bounds1) {
+                self.spatial_join_internal(boundable1, other, child, visitor);
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+/**
+ * Strings used in GeoJSON serialization.
+ */
+pub struct GeoJsonConstants {}
+
+impl GeoJsonConstants {
+    pub const NAME_GEOMETRIES: &'static str = "geometries";
+    pub const NAME_COORDINATES: &'static str = "coordinates";
+    pub const NAME_TYPE: &'static str = "type";
+    pub const NAME_POINT: &'static str = "Point";
+    pub const NAME_LINESTRING: &'static str = "LineString";
+    pub const NAME_POLYGON: &'static str = "Polygon";
+    pub const NAME_MULTIPOINT: &'static str = "MultiPoint";
+    pub const NAME_MULTILINESTRING: &'static str = "MultiLineString";
+    pub const NAME_MULTIPOLYGON: &'static str = "MultiPolygon";
+    pub const NAME_GEOMETRYCOLLECTION: &'static str = "GeometryCollection";
+}
@@ -0,0 +1,379 @@
+use crate::core::{
+    geom::{
+        coordinate::Coordinate, geometry::Geometry, geometry_collection::GeometryCollection,
+        geometry_factory::GeometryFactory, line_string::LineString, linear_ring::LinearRing,
+        multi_line_string::MultiLineString, point::Point, polygon::Polygon,
+    },
+    io::parse_exception::ParseException,
+};
+
+use super::geo_json_constants::GeoJsonConstants;
+
+/**
+ * Reads a GeoJson Geometry from a JSON fragment into a {@link Geometry}.
+ * <p>
+ * Only the geometry objects defined by RFC 7946 are supported;
+ * Features and FeatureCollections are not.
+ * Ring orientation is preserved as read.
+ */
+pub struct GeoJsonReader {}
+
+/**
+ * A parsed JSON value.
+ */
+enum JsonValue {
+    Null,
+    Bool,
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    fn get(&self, name: &str) -> Option<&JsonValue> {
+        if let JsonValue::Object(members) = self {
+            return members.iter().find(|(k, _)| k == name).map(|(_, v)| v);
+        }
+        return None;
+    }
+}
+
+/**
+ * A minimal recursive-descent JSON parser.
+ */
+struct JsonParser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    json: &'a str,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(json: &'a str) -> Self {
+        Self {
+            chars: json.char_indices().peekable(),
+            json,
+        }
+    }
+
+    fn parse(mut self) -> Result<JsonValue, ParseException> {
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+        if let Some((i, _)) = self.chars.peek() {
+            return Err(ParseException::new(&format!(
+                "Unexpected trailing content at position {}",
+                i
+            )));
+        }
+        return Ok(value);
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some((_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseException> {
+        self.skip_whitespace();
+        return self.expect_next(expected);
+    }
+
+    fn expect_next(&mut self, expected: char) -> Result<(), ParseException> {
+        return match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((i, c)) => Err(ParseException::new(&format!(
+                "Expected '{}' but found '{}' at position {}",
+                expected, c, i
+            ))),
+            None => Err(ParseException::new(&format!(
+                "Expected '{}' but found end of input",
+                expected
+            ))),
+        };
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, ParseException> {
+        self.skip_whitespace();
+        let (i, c) = match self.chars.peek() {
+            Some(&(i, c)) => (i, c),
+            None => return Err(ParseException::new("Unexpected end of input")),
+        };
+        return match c {
+            '{' => self.parse_object(),
+            '[' => self.parse_array(),
+            '"' => Ok(JsonValue::String(self.parse_string()?)),
+            't' => self.parse_literal("true", JsonValue::Bool),
+            'f' => self.parse_literal("false", JsonValue::Bool),
+            'n' => self.parse_literal("null", JsonValue::Null),
+            '-' | '0'..='9' => self.parse_number(),
+            _ => Err(ParseException::new(&format!(
+                "Unexpected character '{}' at position {}",
+                c, i
+            ))),
+        };
+    }
+
+    fn parse_literal(
+        &mut self,
+        literal: &str,
+        value: JsonValue,
+    ) -> Result<JsonValue, ParseException> {
+        self.skip_whitespace();
+        for expected in literal.chars() {
+            self.expect_next(expected)?;
+        }
+        return Ok(value);
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, ParseException> {
+        let start = self
+            .chars
+            .peek()
+            .map(|&(i, _)| i)
+            .unwrap_or(self.json.len());
+        let mut end = start;
+        while let Some(&(i, c)) = self.chars.peek() {
+            if !(c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E') {
+                break;
+            }
+            end = i + c.len_utf8();
+            self.chars.next();
+        }
+        let text = &self.json[start..end];
+        return text
+            .parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|_| ParseException::new(&format!("Invalid number: {}", text)));
+    }
+
+    fn parse_string(&mut self) -> Result<String, ParseException> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(s),
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, 'n')) => s.push('\n'),
+                    Some((_, 't')) => s.push('\t'),
+                    Some((_, 'r')) => s.push('\r'),
+                    Some((_, c)) => s.push(c),
+                    None => break,
+                },
+                Some((_, c)) => s.push(c),
+                None => break,
+            }
+        }
+        return Err(ParseException::new("Unterminated string"));
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, ParseException> {
+        self.expect('[')?;
+        let mut values = vec![];
+        self.skip_whitespace();
+        if let Some((_, ']')) = self.chars.peek() {
+            self.chars.next();
+            return Ok(JsonValue::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, ']')) => return Ok(JsonValue::Array(values)),
+                _ => return Err(ParseException::new("Expected ',' or ']' in array")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, ParseException> {
+        self.expect('{')?;
+        let mut members = vec![];
+        self.skip_whitespace();
+        if let Some((_, '}')) = self.chars.peek() {
+            self.chars.next();
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let name = self.parse_string()?;
+            self.expect(':')?;
+            members.push((name, self.parse_value()?));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, '}')) => return Ok(JsonValue::Object(members)),
+                _ => return Err(ParseException::new("Expected ',' or '}' in object")),
+            }
+        }
+    }
+}
+
+impl GeoJsonReader {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /**
+     * Reads a GeoJson Geometry from a <tt>String</tt> into a single
+     * {@link Geometry}.
+     *
+     * @param json The GeoJson String to parse
+     * @return the resulting JTS Geometry
+     *
+     * @throws ParseException
+     *           throws a ParseException if the JSON string cannot be parsed
+     */
+    pub fn read(&self, json: &str) -> Result<Geometry, ParseException> {
+        let value = JsonParser::new(json).parse()?;
+        return GeoJsonReader::create_geometry(&value);
+    }
+
+    fn create_geometry(value: &JsonValue) -> Result<Geometry, ParseException> {
+        let type_name = match value.get(GeoJsonConstants::NAME_TYPE) {
+            Some(JsonValue::String(type_name)) => type_name.as_str(),
+            _ => {
+                return Err(ParseException::new(
+                    "Could not parse Geometry from Json string.",
+                ))
+            }
+        };
+
+        if type_name == GeoJsonConstants::NAME_GEOMETRYCOLLECTION {
+            let members = match value.get(GeoJsonConstants::NAME_GEOMETRIES) {
+                Some(JsonValue::Array(members)) => members,
+                _ => return Err(ParseException::new("Expected a geometries array")),
+            };
+            let mut geometries: Vec<Geometry> = vec![];
+            for member in members {
+                geometries.push(GeoJsonReader::create_geometry(member)?);
+            }
+            return Ok(Geometry::GeometryCollection(
+                GeometryCollection::new_with_geometries(&geometries),
+            ));
+        }
+
+        let coordinates = match value.get(GeoJsonConstants::NAME_COORDINATES) {
+            Some(coordinates @ JsonValue::Array(_)) => coordinates,
+            _ => return Err(ParseException::new("Expected a coordinates array")),
+        };
+        return match type_name {
+            GeoJsonConstants::NAME_POINT => {
+                Ok(Geometry::Point(GeoJsonReader::create_point(coordinates)?))
+            }
+            GeoJsonConstants::NAME_LINESTRING => Ok(Geometry::LineString(
+                GeoJsonReader::create_line_string(coordinates)?,
+            )),
+            GeoJsonConstants::NAME_POLYGON => Ok(Geometry::Polygon(GeoJsonReader::create_polygon(
+                coordinates,
+            )?)),
+            GeoJsonConstants::NAME_MULTIPOINT => {
+                let mut points: Vec<Point> = vec![];
+                for part in GeoJsonReader::as_array(coordinates)? {
+                    points.push(GeoJsonReader::create_point(part)?);
+                }
+                Ok(Geometry::MultiPoint(
+                    GeometryFactory::create_multi_point_from_points(&points),
+                ))
+            }
+            GeoJsonConstants::NAME_MULTILINESTRING => {
+                let mut line_strings: Vec<LineString> = vec![];
+                for part in GeoJsonReader::as_array(coordinates)? {
+                    line_strings.push(GeoJsonReader::create_line_string(part)?);
+                }
+                Ok(Geometry::MultiLineString(
+                    MultiLineString::new_with_line_strings(&line_strings),
+                ))
+            }
+            GeoJsonConstants::NAME_MULTIPOLYGON => {
+                let mut polygons: Vec<Polygon> = vec![];
+                for part in GeoJsonReader::as_array(coordinates)? {
+                    polygons.push(GeoJsonReader::create_polygon(part)?);
+                }
+                Ok(Geometry::MultiPolygon(
+                    GeometryFactory::create_multi_polygon(&polygons),
+                ))
+            }
+            _ => Err(ParseException::new(&format!(
+                "Unknown geometry type: {}",
+                type_name
+            ))),
+        };
+    }
+
+    fn as_array(value: &JsonValue) -> Result<&Vec<JsonValue>, ParseException> {
+        if let JsonValue::Array(values) = value {
+            return Ok(values);
+        }
+        return Err(ParseException::new("Expected an array"));
+    }
+
+    fn create_point(value: &JsonValue) -> Result<Point, ParseException> {
+        if GeoJsonReader::as_array(value)?.is_empty() {
+            return Ok(GeometryFactory::create_point());
+        }
+        return Ok(GeometryFactory::create_point_from_coordinate(
+            &GeoJsonReader::create_coordinate(value)?,
+        ));
+    }
+
+    fn create_line_string(value: &JsonValue) -> Result<LineString, ParseException> {
+        return Ok(GeometryFactory::create_line_string_coordinates(
+            &GeoJsonReader::create_coordinate_list(value)?,
+        ));
+    }
+
+    fn create_polygon(value: &JsonValue) -> Result<Polygon, ParseException> {
+        let rings = GeoJsonReader::as_array(value)?;
+        if rings.is_empty() {
+            return Ok(GeometryFactory::create_polygon_with_linear_ring(
+                &GeometryFactory::create_linear_ring(),
+            ));
+        }
+        let shell = GeoJsonReader::create_linear_ring(&rings[0])?;
+        let mut holes: Vec<LinearRing> = vec![];
+        for ring in &rings[1..] {
+            holes.push(GeoJsonReader::create_linear_ring(ring)?);
+        }
+        return Ok(GeometryFactory::create_polygon_with_linear_ring_vec(
+            &shell, &holes,
+        ));
+    }
+
+    fn create_linear_ring(value: &JsonValue) -> Result<LinearRing, ParseException> {
+        return Ok(GeometryFactory::create_linear_ring_with_coordinates(
+            &GeoJsonReader::create_coordinate_list(value)?,
+        ));
+    }
+
+    fn create_coordinate_list(value: &JsonValue) -> Result<Vec<Coordinate>, ParseException> {
+        let mut coordinates: Vec<Coordinate> = vec![];
+        for ordinates in GeoJsonReader::as_array(value)? {
+            coordinates.push(GeoJsonReader::create_coordinate(ordinates)?);
+        }
+        return Ok(coordinates);
+    }
+
+    fn create_coordinate(value: &JsonValue) -> Result<Coordinate, ParseException> {
+        let mut ordinates: Vec<f64> = vec![];
+        for ordinate in GeoJsonReader::as_array(value)? {
+            match ordinate {
+                JsonValue::Number(n) => ordinates.push(*n),
+                _ => return Err(ParseException::new("Expected a numeric ordinate")),
+            }
+        }
+        return match ordinates.len() {
+            2 => Ok(Coordinate::new_xy(ordinates[0], ordinates[1])),
+            3 => Ok(Coordinate::new_xyz(
+                ordinates[0],
+                ordinates[1],
+                ordinates[2],
+            )),
+            _ => Err(ParseException::new(
+                "A coordinate must have two or three ordinates",
+            )),
+        };
+    }
+}
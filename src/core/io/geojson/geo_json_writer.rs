@@ -0,0 +1,213 @@
+use crate::core::geom::{
+    coordinate::Coordinate, geometry::Geometry, line_string::LineString, linear_ring::LinearRing,
+    polygon::Polygon,
+};
+
+use super::geo_json_constants::GeoJsonConstants;
+
+/**
+ * Writes {@link Geometry}s as JSON fragments in GeoJSON format.
+ * <p>
+ * By default polygons are written following the counter-clockwise
+ * orientation (aka Right Hand Rule) required by RFC 7946:
+ * shells are counter-clockwise and holes clockwise.
+ * The input geometry is never modified.
+ */
+pub struct GeoJsonWriter {
+    is_force_ccw: bool,
+}
+
+impl GeoJsonWriter {
+    /**
+     * Constructs a GeoJsonWriter instance which forces
+     * RFC 7946 polygon ring orientation.
+     */
+    pub fn new() -> Self {
+        Self { is_force_ccw: true }
+    }
+
+    /**
+     * Sets whether the GeoJSON should be output following counter-clockwise orientation aka Right Hand Rule defined in RFC7946
+     * See <a href="https://tools.ietf.org/html/rfc7946#section-3.1.6">RFC 7946 Specification</a> for more context.
+     *
+     * @param isForceCCW true if the GeoJSON should be output following the RFC7946 counter-clockwise orientation aka Right Hand Rule
+     */
+    pub fn set_force_ccw(&mut self, is_force_ccw: bool) {
+        self.is_force_ccw = is_force_ccw;
+    }
+
+    pub fn is_force_ccw(&self) -> bool {
+        return self.is_force_ccw;
+    }
+
+    /**
+     * Writes a {@link Geometry} in GeoJson format to a String.
+     *
+     * <p>
+     * JSON has no representation for NaN or infinite numbers, so a geometry
+     * with a non-finite X or Y ordinate cannot be written.
+     * A non-finite Z ordinate is omitted, and the coordinate written as 2D.
+     *
+     * @param geometry the geometry to write
+     * @return String GeoJson Encoded Geometry,
+     * or None if the geometry has a non-finite X or Y ordinate
+     */
+    pub fn write(&self, geometry: &Geometry) -> Option<String> {
+        let mut buf = String::new();
+        self.write_geometry(geometry, &mut buf)?;
+        return Some(buf);
+    }
+
+    fn write_geometry(&self, geometry: &Geometry, buf: &mut String) -> Option<()> {
+        match geometry {
+            Geometry::Point(point) => {
+                let mut coordinates = String::new();
+                if let Some(coord) = point.get_coordinate() {
+                    GeoJsonWriter::write_coordinate(&coord, &mut coordinates)?;
+                } else {
+                    coordinates.push_str("[]");
+                }
+                GeoJsonWriter::write_typed(GeoJsonConstants::NAME_POINT, &coordinates, buf);
+            }
+            Geometry::LineString(line) => {
+                let mut coordinates = String::new();
+                GeoJsonWriter::write_line_string(line, &mut coordinates)?;
+                GeoJsonWriter::write_typed(GeoJsonConstants::NAME_LINESTRING, &coordinates, buf);
+            }
+            Geometry::LinearRing(ring) => {
+                let mut coordinates = String::new();
+                GeoJsonWriter::write_coordinates(&ring.get_coordinates(), &mut coordinates)?;
+                GeoJsonWriter::write_typed(GeoJsonConstants::NAME_LINESTRING, &coordinates, buf);
+            }
+            Geometry::Polygon(polygon) => {
+                let mut coordinates = String::new();
+                self.write_polygon(polygon, &mut coordinates)?;
+                GeoJsonWriter::write_typed(GeoJsonConstants::NAME_POLYGON, &coordinates, buf);
+            }
+            Geometry::MultiPoint(multi_point) => {
+                let mut coordinates = String::from("[");
+                for i in 0..multi_point.get_num_points() {
+                    if i > 0 {
+                        coordinates.push(',');
+                    }
+                    if let Some(coord) = multi_point.get_coordinate(i) {
+                        GeoJsonWriter::write_coordinate(&coord, &mut coordinates)?;
+                    } else {
+                        coordinates.push_str("[]");
+                    }
+                }
+                coordinates.push(']');
+                GeoJsonWriter::write_typed(GeoJsonConstants::NAME_MULTIPOINT, &coordinates, buf);
+            }
+            Geometry::MultiLineString(multi_line) => {
+                let mut coordinates = String::from("[");
+                for i in 0..multi_line.get_num_line_strings() {
+                    if i > 0 {
+                        coordinates.push(',');
+                    }
+                    GeoJsonWriter::write_line_string(
+                        &multi_line.get_line_string_at_index(i),
+                        &mut coordinates,
+                    )?;
+                }
+                coordinates.push(']');
+                GeoJsonWriter::write_typed(
+                    GeoJsonConstants::NAME_MULTILINESTRING,
+                    &coordinates,
+                    buf,
+                );
+            }
+            Geometry::MultiPolygon(multi_polygon) => {
+                let mut coordinates = String::from("[");
+                for i in 0..multi_polygon.get_num_polygons() {
+                    if i > 0 {
+                        coordinates.push(',');
+                    }
+                    self.write_polygon(&multi_polygon.get_polygon_at_index(i), &mut coordinates)?;
+                }
+                coordinates.push(']');
+                GeoJsonWriter::write_typed(GeoJsonConstants::NAME_MULTIPOLYGON, &coordinates, buf);
+            }
+            Geometry::GeometryCollection(collection) => {
+                buf.push_str(&format!(
+                    "{{\"{}\":\"{}\",\"{}\":[",
+                    GeoJsonConstants::NAME_TYPE,
+                    GeoJsonConstants::NAME_GEOMETRYCOLLECTION,
+                    GeoJsonConstants::NAME_GEOMETRIES
+                ));
+                for i in 0..collection.get_num_geometries() {
+                    if i > 0 {
+                        buf.push(',');
+                    }
+                    self.write_geometry(&collection.get_geometry_n(i), buf)?;
+                }
+                buf.push_str("]}");
+            }
+        }
+        return Some(());
+    }
+
+    fn write_typed(type_name: &str, coordinates: &str, buf: &mut String) {
+        buf.push_str(&format!(
+            "{{\"{}\":\"{}\",\"{}\":{}}}",
+            GeoJsonConstants::NAME_TYPE,
+            type_name,
+            GeoJsonConstants::NAME_COORDINATES,
+            coordinates
+        ));
+    }
+
+    fn write_polygon(&self, polygon: &Polygon, buf: &mut String) -> Option<()> {
+        if polygon.is_empty() {
+            buf.push_str("[]");
+            return Some(());
+        }
+        let oriented;
+        let polygon = if self.is_force_ccw {
+            oriented = polygon.to_oriented(true);
+            &oriented
+        } else {
+            polygon
+        };
+        buf.push('[');
+        GeoJsonWriter::write_ring(&polygon.get_exterior_ring(), buf)?;
+        for i in 0..polygon.get_num_interior_ring() {
+            buf.push(',');
+            GeoJsonWriter::write_ring(&polygon.get_interior_ring_n(i), buf)?;
+        }
+        buf.push(']');
+        return Some(());
+    }
+
+    fn write_ring(ring: &LinearRing, buf: &mut String) -> Option<()> {
+        return GeoJsonWriter::write_coordinates(&ring.get_coordinates(), buf);
+    }
+
+    fn write_line_string(line: &LineString, buf: &mut String) -> Option<()> {
+        return GeoJsonWriter::write_coordinates(&line.get_coordinates(), buf);
+    }
+
+    fn write_coordinates(coords: &[Coordinate], buf: &mut String) -> Option<()> {
+        buf.push('[');
+        for (i, coord) in coords.iter().enumerate() {
+            if i > 0 {
+                buf.push(',');
+            }
+            GeoJsonWriter::write_coordinate(coord, buf)?;
+        }
+        buf.push(']');
+        return Some(());
+    }
+
+    fn write_coordinate(coord: &Coordinate, buf: &mut String) -> Option<()> {
+        if !coord.x.is_finite() || !coord.y.is_finite() {
+            return None;
+        }
+        if coord.z.is_finite() {
+            buf.push_str(&format!("[{},{},{}]", coord.x, coord.y, coord.z));
+        } else {
+            buf.push_str(&format!("[{},{}]", coord.x, coord.y));
+        }
+        return Some(());
+    }
+}
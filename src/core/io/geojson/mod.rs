@@ -0,0 +1,3 @@
+pub(crate) mod geo_json_constants;
+pub(crate) mod geo_json_reader;
+pub(crate) mod geo_json_writer;
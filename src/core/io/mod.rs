@@ -0,0 +1,3 @@
+pub(crate) mod geojson;
+pub(crate) mod parse_exception;
+pub(crate) mod svg_writer;
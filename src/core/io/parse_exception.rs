@@ -0,0 +1,34 @@
+use std::fmt;
+
+/**
+ * Thrown by a <code>WKTReader</code> when a parsing problem occurs.
+ *
+ *@version 1.7
+ */
+#[derive(Clone, Debug)]
+pub struct ParseException {
+    message: String,
+}
+
+impl ParseException {
+    /**
+     * Creates a <code>ParseException</code> with the given detail message.
+     *
+     *@param  message  a description of this <code>ParseException</code>
+     */
+    pub fn new(message: &str) -> Self {
+        Self {
+            message: message.to_owned(),
+        }
+    }
+
+    pub fn get_message(&self) -> &str {
+        return &self.message;
+    }
+}
+
+impl fmt::Display for ParseException {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
@@ -0,0 +1,171 @@
+use crate::core::geom::{
+    coordinate::Coordinate, envelope::Envelope, geometry::Geometry, polygon::Polygon,
+};
+
+/**
+ * Writes a {@link Geometry} as an SVG document, for visual debugging.
+ * <p>
+ * Geometries are written as follows:
+ * <ul>
+ * <li>Points are written as small <code>circle</code> elements
+ * <li>Lines are written as unfilled <code>path</code> elements
+ * <li>Polygons are written as filled <code>path</code> elements,
+ * with one subpath per ring.
+ * The even-odd fill rule is used so that holes are not filled.
+ * </ul>
+ * The <code>viewBox</code> of the document is the envelope of the geometry
+ * expanded by a margin.
+ * The Y axis is flipped, so that the geometry is displayed with Y increasing upwards.
+ */
+pub struct SvgWriter {}
+
+impl SvgWriter {
+    /**
+     * The size of the margin around the geometry, as a fraction of its extent.
+     */
+    const MARGIN_FACTOR: f64 = 0.05;
+
+    /**
+     * Writes a {@link Geometry} as an SVG document.
+     *
+     * @param geom the geometry to write
+     * @return the SVG document
+     */
+    pub fn write(geom: &Geometry) -> String {
+        let env = geom.compute_envelope_internal();
+        let margin = SvgWriter::margin(&env);
+
+        let mut buf = String::new();
+        if env.is_null() {
+            buf.push_str("<svg xmlns=\"http://www.w3.org/2000/svg\">\n");
+        } else {
+            buf.push_str(&format!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n",
+                env.get_min_x() - margin,
+                SvgWriter::svg_y(env.get_max_y()) - margin,
+                env.get_width() + 2. * margin,
+                env.get_height() + 2. * margin
+            ));
+        }
+        SvgWriter::write_geometry(geom, margin / 2., &mut buf);
+        buf.push_str("</svg>\n");
+        return buf;
+    }
+
+    fn margin(env: &Envelope) -> f64 {
+        let extent = f64::max(env.get_width(), env.get_height());
+        if env.is_null() || extent <= 0. {
+            return 1.;
+        }
+        return extent * SvgWriter::MARGIN_FACTOR;
+    }
+
+    /**
+     * Converts a Y ordinate to the SVG coordinate system,
+     * in which Y increases downwards.
+     */
+    fn svg_y(y: f64) -> f64 {
+        // avoid writing "-0"
+        if y == 0. {
+            return 0.;
+        }
+        return -y;
+    }
+
+    fn write_geometry(geom: &Geometry, point_radius: f64, buf: &mut String) {
+        match geom {
+            Geometry::Point(point) => {
+                if let Some(pt) = point.get_coordinate() {
+                    SvgWriter::write_point(&pt, point_radius, buf);
+                }
+            }
+            Geometry::MultiPoint(multi_point) => {
+                for pt in multi_point.get_coordinates() {
+                    SvgWriter::write_point(&pt, point_radius, buf);
+                }
+            }
+            Geometry::LineString(line) => SvgWriter::write_line(&line.get_coordinates(), buf),
+            Geometry::LinearRing(ring) => SvgWriter::write_line(&ring.get_coordinates(), buf),
+            Geometry::MultiLineString(multi_line) => {
+                for i in 0..multi_line.get_num_line_strings() {
+                    let line = multi_line.get_line_string_at_index(i);
+                    SvgWriter::write_line(&line.get_coordinates(), buf);
+                }
+            }
+            Geometry::Polygon(polygon) => SvgWriter::write_polygon(polygon, buf),
+            Geometry::MultiPolygon(multi_polygon) => {
+                for i in 0..multi_polygon.get_num_polygons() {
+                    SvgWriter::write_polygon(&multi_polygon.get_polygon_at_index(i), buf);
+                }
+            }
+            Geometry::GeometryCollection(collection) => {
+                for i in 0..collection.get_num_geometries() {
+                    SvgWriter::write_geometry(&collection.get_geometry_n(i), point_radius, buf);
+                }
+            }
+        }
+    }
+
+    fn write_point(pt: &Coordinate, radius: f64, buf: &mut String) {
+        buf.push_str(&format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"black\" />\n",
+            pt.x,
+            SvgWriter::svg_y(pt.y),
+            radius
+        ));
+    }
+
+    fn write_line(coords: &[Coordinate], buf: &mut String) {
+        if coords.is_empty() {
+            return;
+        }
+        let mut d = String::new();
+        SvgWriter::write_path_data(coords, false, &mut d);
+        buf.push_str(&format!(
+            "<path d=\"{}\" fill=\"none\" stroke=\"black\" vector-effect=\"non-scaling-stroke\" />\n",
+            d
+        ));
+    }
+
+    fn write_polygon(polygon: &Polygon, buf: &mut String) {
+        if polygon.is_empty() {
+            return;
+        }
+        let mut d = String::new();
+        SvgWriter::write_path_data(&polygon.get_exterior_ring().get_coordinates(), true, &mut d);
+        for i in 0..polygon.get_num_interior_ring() {
+            d.push(' ');
+            SvgWriter::write_path_data(
+                &polygon.get_interior_ring_n(i).get_coordinates(),
+                true,
+                &mut d,
+            );
+        }
+        buf.push_str(&format!(
+            "<path d=\"{}\" fill=\"lightblue\" fill-rule=\"evenodd\" stroke=\"black\" vector-effect=\"non-scaling-stroke\" />\n",
+            d
+        ));
+    }
+
+    /**
+     * Writes the path commands for a sequence of coordinates.
+     * The closing point of a ring is replaced by a close-path command.
+     */
+    fn write_path_data(coords: &[Coordinate], is_ring: bool, buf: &mut String) {
+        let n = if is_ring && coords.len() > 1 {
+            coords.len() - 1
+        } else {
+            coords.len()
+        };
+        for (i, pt) in coords[..n].iter().enumerate() {
+            if i > 0 {
+                buf.push(' ');
+            }
+            let command = if i == 0 { 'M' } else { 'L' };
+            buf.push_str(&format!("{}{} {}", command, pt.x, SvgWriter::svg_y(pt.y)));
+        }
+        if is_ring {
+            buf.push_str(" Z");
+        }
+    }
+}
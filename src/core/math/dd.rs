@@ -1,3 +1,5 @@
+use crate::core::io::parse_exception::ParseException;
+
 /**
  * Implements extended-precision floating-point numbers
  * which maintain 106 bits (approximately 30 decimal digits) of precision.
@@ -213,16 +215,16 @@ impl DD {
         DD::new_hi_lo(f64::NAN, f64::NAN)
     }
 
-    // /**
-    //  * Converts the string argument to a DoubleDouble number.
-    //  *
-    //  * @param str a string containing a representation of a numeric value
-    //  * @return the extended precision version of the value
-    //  * @throws NumberFormatException if <tt>s</tt> is not a valid representation of a number
-    //  */
-    // pub fn valueOfString(String str) -> DD {
-    //   return parse(str);
-    //   }
+    /**
+     * Converts the string argument to a DoubleDouble number.
+     *
+     * @param str a string containing a representation of a numeric value
+     * @return the extended precision version of the value
+     * @throws ParseException if <tt>s</tt> is not a valid representation of a number
+     */
+    pub fn value_of_string(str: &str) -> Result<DD, ParseException> {
+        return DD::parse(str);
+    }
 
     /**
      * Converts the <tt>double</tt> argument to a DoubleDouble number.
@@ -234,6 +236,35 @@ impl DD {
         return DD::new_x(x);
     }
 
+    /**
+     * Converts the <tt>long</tt> argument to a DoubleDouble number.
+     * The conversion is exact, since the value is split into
+     * two parts each of which is exactly representable as a double.
+     *
+     * @param x a numeric value
+     * @return the extended precision version of the value
+     */
+    pub fn value_of_i64(x: i64) -> DD {
+        let hi = (x >> 32) as f64 * 4294967296.0;
+        let lo = (x & 0xFFFF_FFFF) as f64;
+        return DD::value_of_f64(hi).add_f64(lo);
+    }
+
+    /**
+     * Creates a DoubleDouble with the value of the ratio of two integers,
+     * computed in extended precision.
+     *
+     * @param numerator the numerator of the ratio
+     * @param denominator the denominator of the ratio
+     * @return the value of the ratio, or NaN if the denominator is zero
+     */
+    pub fn from_ratio(numerator: i64, denominator: i64) -> DD {
+        if denominator == 0 {
+            return DD::create_nan();
+        }
+        return DD::value_of_i64(numerator).divide_dd(&DD::value_of_i64(denominator));
+    }
+
     /**
      * Creates a new DoubleDouble with the value of the argument.
      *
@@ -249,22 +280,40 @@ impl DD {
         return copy;
     }
 
-    /*
-    double getHighComponent() { return hi; }
+    /**
+     * Gets the high-order component of this value.
+     *
+     * @return the high-order component
+     */
+    pub fn hi(&self) -> f64 {
+        return self.hi;
+    }
 
-    double getLowComponent() { return lo; }
-    */
+    /**
+     * Gets the low-order component of this value.
+     *
+     * @return the low-order component
+     */
+    pub fn lo(&self) -> f64 {
+        return self.lo;
+    }
 
-    // Testing only - should not be public
-    /*
-    public void RENORM()
-    {
-      double s = hi + lo;
-      double err = lo - (s - hi);
-      hi = s;
-      lo = err;
+    /**
+     * Renormalizes the components of this value, so that the high-order component
+     * is the double nearest to the value and
+     * <tt>|lo| &lt;= 0.5*ulp(hi)</tt> holds again.
+     * This is needed after constructing a value from arbitrary components
+     * with {@link #new_hi_lo}.
+     * The value represented is unchanged.
+     */
+    pub fn renorm(&mut self) {
+        let s = self.hi + self.lo;
+        // the exact rounding error of the sum, which is valid for any magnitudes of hi and lo
+        let bb = s - self.hi;
+        let err = (self.hi - (s - bb)) + (self.lo - bb);
+        self.hi = s;
+        self.lo = err;
     }
-    */
 
     /**
      * Set the value for the DD object. This method supports the mutating
@@ -875,6 +924,50 @@ impl DD {
         return DD::value_of_f64(x).sqrt_dd();
     }
 
+    /**
+     * Computes the reciprocal of the positive square root of this value.
+     *
+     * @return the reciprocal of the positive square root of this number.
+     * If the number is zero the result is positive infinity.
+     * If the argument is NaN or less than zero, the result is NaN.
+     */
+    pub fn rsqrt(&self) -> DD {
+        if self.is_zero() {
+            return DD::value_of_f64(f64::INFINITY);
+        }
+        if self.is_nan() || self.is_negative() {
+            return DD::create_nan();
+        }
+        return self.sqrt_dd().reciprocal();
+    }
+
+    /**
+     * Computes <tt>sqrt(x<sup>2</sup> + y<sup>2</sup>)</tt>
+     * without intermediate overflow or underflow.
+     * The smaller value is scaled by the larger before squaring,
+     * so the result is finite whenever it is representable.
+     *
+     * @param x a DD value
+     * @param y a DD value
+     * @return the length of the hypotenuse of a right triangle with sides x and y
+     */
+    pub fn hypot(x: &DD, y: &DD) -> DD {
+        if x.hi.is_infinite() || y.hi.is_infinite() {
+            return DD::value_of_f64(f64::INFINITY);
+        }
+        if x.is_nan() || y.is_nan() {
+            return DD::create_nan();
+        }
+        let ax = x.abs();
+        let ay = y.abs();
+        let (big, small) = if ax.ge(&ay) { (ax, ay) } else { (ay, ax) };
+        if big.is_zero() {
+            return DD::value_of_f64(0.0);
+        }
+        let ratio = small.divide_dd(&big);
+        return big.multiply_dd(&ratio.sqr().add_f64(1.0).sqrt_dd());
+    }
+
     /**
      * Computes the value of this number raised to an integral power.
      * Follows semantics of Java Math.pow as closely as possible.
@@ -1053,6 +1146,33 @@ impl DD {
         return self.hi == y.hi && self.lo == y.lo;
     }
 
+    /**
+     * Tests whether this value is equal to another <tt>DoubleDouble</tt> value
+     * to within an absolute tolerance.
+     * NaN values are never equal.
+     *
+     * @param y a DoubleDouble value
+     * @param tolerance the maximum allowed difference
+     * @return true if |this - y| &lt;= tolerance
+     */
+    pub fn equals_approx(&self, y: &DD, tolerance: &DD) -> bool {
+        return self.subtract_dd(y).abs().le(tolerance);
+    }
+
+    /**
+     * Tests whether this value is equal to another <tt>DoubleDouble</tt> value
+     * to within a tolerance relative to the larger magnitude of the two values.
+     * NaN values are never equal.
+     *
+     * @param y a DoubleDouble value
+     * @param relTolerance the maximum allowed difference, as a fraction of the larger magnitude
+     * @return true if |this - y| &lt;= relTolerance * max(|this|, |y|)
+     */
+    pub fn equals_relative(&self, y: &DD, rel_tolerance: f64) -> bool {
+        let scale = self.abs().max(&y.abs());
+        return self.equals_approx(y, &scale.multiply_f64(rel_tolerance));
+    }
+
     /**
      * Tests whether this value is greater than another <tt>DoubleDouble</tt> value.
      * @param y a DoubleDouble value
@@ -1117,220 +1237,192 @@ impl DD {
         return format!("DD<{}, {}>", self.hi, self.lo);
     }
 
-    // /**
-    //  * Returns a string representation of this number, in either standard or scientific notation.
-    //  * If the magnitude of the number is in the range [ 10<sup>-3</sup>, 10<sup>8</sup> ]
-    //  * standard notation will be used.  Otherwise, scientific notation will be used.
-    //  *
-    //  * @return a string representation of this number
-    //  */
-    // pub fn toString(&self) -> String
-    // {
-    //   let mag = self.magnitude(self.hi);
-    //   if mag >= -3 && mag <= 20 {
-    //     return self.toStandardNotation();
-    //   }
-    //   return self.toSciNotation();
-    // }
+    /**
+     * Returns the string representation of this value in standard notation,
+     * including all significant digits of the value.
+     * This is useful for inspecting intermediate results,
+     * since a double-precision representation discards the low-order component.
+     *
+     * @return the string representation in standard notation
+     */
+    pub fn to_string_full(&self) -> String {
+        return self.to_standard_notation();
+    }
 
-    // /**
-    //  * Returns the string representation of this value in standard notation.
-    //  *
-    //  * @return the string representation in standard notation
-    //  */
-    // pub fn toStandardNotation(&self) -> String {
-    //   let specialStr = self.getSpecialNumberString();
-    //   if specialStr.is_some() {
-    //     return specialStr;
-    //   }
-
-    //   let magnitude: [i32;1] = [1];
-    //   let sigDigits = self.extractSignificantDigits(true, magnitude);
-    //   let decimalPointPos = magnitude[0] + 1;
-
-    //   let num = sigDigits;
-    //   // add a leading 0 if the decimal point is the first char
-    //   if sigDigits.charAt(0) == '.' {
-    //     num = "0" + sigDigits;
-    //   }
-    //   else if decimalPointPos < 0 {
-    //     num = "0." + self.stringOfChar('0', -decimalPointPos) + sigDigits;
-    //   }
-    //   else if sigDigits.indexOf('.') == -1 {
-    //     // no point inserted - sig digits must be smaller than magnitude of number
-    //     // add zeroes to end to make number the correct size
-    //     let numZeroes = decimalPointPos - sigDigits.length();
-    //     let zeroes = self.stringOfChar('0', numZeroes);
-    //     num = sigDigits + zeroes + ".0";
-    //   }
-
-    //   if self.isNegative() {
-    //     return "-" + num;
-    //   }
-    //   return num;
-    // }
+    /**
+     * Returns the string representation of this value in standard notation.
+     *
+     * @return the string representation in standard notation
+     */
+    pub fn to_standard_notation(&self) -> String {
+        if let Some(special_str) = self.get_special_number_string() {
+            return special_str;
+        }
 
-    // /**
-    //  * Returns the string representation of this value in scientific notation.
-    //  *
-    //  * @return the string representation in scientific notation
-    //  */
-    // pub fn toSciNotation(&self) -> String {
-    //   // special case zero, to allow as
-    //   if self.isZero() {
-    //     return DD::SCI_NOT_ZERO.to_string();
-    //   }
-
-    //   let specialStr = self.getSpecialNumberString();
-    //   if specialStr.is_some() {
-    //     return specialStr;
-    //   }
-
-    //   let magnitude: [i32;1] = [1];
-    //   let digits = self.extractSignificantDigits(false, magnitude);
-    //   let expStr = format!("{}{}", DD::SCI_NOT_EXPONENT_CHAR.to_string(), magnitude[0]);
-
-    // //   // should never have leading zeroes
-    // //   // MD - is this correct?  Or should we simply strip them if they are present?
-    // //   if (digits.charAt(0) == '0') {
-    // //     throw new IllegalStateException("Found leading zero: " + digits);
-    // //   }
-
-    //   // add decimal point
-    //   let mut trailingDigits = "";
-    //   if digits.length() > 1 {
-    //     trailingDigits = digits.substring(1);
-    //   }
-    //   let digitsWithDecimal = digits.charAt(0) + "." + trailingDigits;
-
-    //   if self.isNegative() {
-    //     return format!("-{}{}", digitsWithDecimal, expStr);
-    //   }
-    //   return digitsWithDecimal + expStr;
-    // }
+        let (sig_digits, magnitude) = self.extract_significant_digits(true);
+        let decimal_point_pos = magnitude + 1;
+
+        let num;
+        // add a leading 0 if the decimal point is the first char
+        if sig_digits.starts_with('.') {
+            num = format!("0{}", sig_digits);
+        } else if decimal_point_pos < 0 {
+            num = format!(
+                "0.{}{}",
+                DD::string_of_char('0', -decimal_point_pos),
+                sig_digits
+            );
+        } else if !sig_digits.contains('.') {
+            // no point inserted - sig digits must be smaller than magnitude of number
+            // add zeroes to end to make number the correct size
+            let num_zeroes = decimal_point_pos - sig_digits.len() as i32;
+            let zeroes = DD::string_of_char('0', num_zeroes);
+            num = format!("{}{}.0", sig_digits, zeroes);
+        } else {
+            num = sig_digits;
+        }
 
-    // /**
-    //  * Extracts the significant digits in the decimal representation of the argument.
-    //  * A decimal point may be optionally inserted in the string of digits
-    //  * (as long as its position lies within the extracted digits
-    //  * - if not, the caller must prepend or append the appropriate zeroes and decimal point).
-    //  *
-    //  * @param y the number to extract ( >= 0)
-    //  * @param decimalPointPos the position in which to insert a decimal point
-    //  * @return the string containing the significant digits and possibly a decimal point
-    //  */
-    // pub fn extractSignificantDigits(&self, insertDecimalPoint: bool, magnitude: [i32;1]) -> String {
-    //   let y = self.abs();
-    //   // compute *correct* magnitude of y
-    //   let mag = magnitude(y.hi);
-    //   DD scale = TEN.pow(mag);
-    //   y = y.divide(scale);
-
-    //   // fix magnitude if off by one
-    //   if (y.gt(TEN)) {
-    //     y = y.divide(TEN);
-    //     mag += 1;
-    //   }
-    //   else if (y.lt(ONE)) {
-    //     y = y.multiply(TEN);
-    //     mag -= 1;
-    //   }
-
-    //   int decimalPointPos = mag + 1;
-    //   StringBuffer buf = new StringBuffer();
-    //   int numDigits = MAX_PRINT_DIGITS - 1;
-    //   for (int i = 0; i <= numDigits; i++) {
-    //     if (insertDecimalPoint && i == decimalPointPos) {
-    //       buf.append('.');
-    //     }
-    //     int digit = (int) y.hi;
-    // //      System.out.println("printDump: [" + i + "] digit: " + digit + "  y: " + y.dump() + "  buf: " + buf);
-
-    //     /**
-    //      * This should never happen, due to heuristic checks on remainder below
-    //      */
-    //     if (digit < 0 || digit > 9) {
-    // //        System.out.println("digit > 10 : " + digit);
-    // //        throw new IllegalStateException("Internal errror: found digit = " + digit);
-    //     }
-    //     /**
-    //      * If a negative remainder is encountered, simply terminate the extraction.
-    //      * This is robust, but maybe slightly inaccurate.
-    //      * My current hypothesis is that negative remainders only occur for very small lo components,
-    //      * so the inaccuracy is tolerable
-    //      */
-    //     if (digit < 0) {
-    //       break;
-    //       // throw new IllegalStateException("Internal errror: found digit = " + digit);
-    //     }
-    //     boolean rebiasBy10 = false;
-    //     char digitChar = 0;
-    //     if (digit > 9) {
-    //       // set flag to re-bias after next 10-shift
-    //       rebiasBy10 = true;
-    //       // output digit will end up being '9'
-    //       digitChar = '9';
-    //     }
-    //     else {
-    //      digitChar = (char) ('0' + digit);
-    //     }
-    //     buf.append(digitChar);
-    //     y = (y.subtract(DD.valueOf(digit))
-    //         .multiply(TEN));
-    //     if (rebiasBy10)
-    //       y.selfAdd(TEN);
-
-    //     boolean continueExtractingDigits = true;
-    //     /**
-    //      * Heuristic check: if the remaining portion of
-    //      * y is non-positive, assume that output is complete
-    //      */
-    // //      if (y.hi <= 0.0)
-    // //        if (y.hi < 0.0)
-    // //        continueExtractingDigits = false;
-    //     /**
-    //      * Check if remaining digits will be 0, and if so don't output them.
-    //      * Do this by comparing the magnitude of the remainder with the expected precision.
-    //      */
-    //     int remMag = magnitude(y.hi);
-    //     if (remMag < 0 && Math.abs(remMag) >= (numDigits - i))
-    //       continueExtractingDigits = false;
-    //     if (! continueExtractingDigits)
-    //       break;
-    //   }
-    //   magnitude[0] = mag;
-    //   return buf.toString();
-    // }
+        if self.is_negative() {
+            return format!("-{}", num);
+        }
+        return num;
+    }
 
-    // /**
-    //  * Creates a string of a given length containing the given character
-    //  *
-    //  * @param ch the character to be repeated
-    //  * @param len the len of the desired string
-    //  * @return the string
-    //  */
-    // private static String stringOfChar(char ch, int len)
-    // {
-    //   StringBuffer buf = new StringBuffer();
-    //   for (int i = 0; i < len; i++) {
-    //     buf.append(ch);
-    //   }
-    //   return buf.toString();
-    // }
+    /**
+     * Returns the string representation of this value in scientific notation.
+     *
+     * @return the string representation in scientific notation
+     */
+    pub fn to_sci_notation(&self) -> String {
+        // special case zero, to allow as
+        if self.is_zero() {
+            return DD::SCI_NOT_ZERO.to_string();
+        }
 
-    // /**
-    //  * Returns the string for this value if it has a known representation.
-    //  * (E.g. NaN or 0.0)
-    //  *
-    //  * @return the string for this special number
-    //  * or null if the number is not a special number
-    //  */
-    // private String getSpecialNumberString()
-    // {
-    //   if (isZero()) return "0.0";
-    //   if (isNaN())  return "NaN ";
-    //   return null;
-    // }
+        if let Some(special_str) = self.get_special_number_string() {
+            return special_str;
+        }
+
+        let (digits, magnitude) = self.extract_significant_digits(false);
+        let exp_str = format!("{}{}", DD::SCI_NOT_EXPONENT_CHAR, magnitude);
+
+        // add decimal point
+        let digits_with_decimal = format!("{}.{}", &digits[..1], &digits[1..]);
+
+        if self.is_negative() {
+            return format!("-{}{}", digits_with_decimal, exp_str);
+        }
+        return format!("{}{}", digits_with_decimal, exp_str);
+    }
+
+    /**
+     * Extracts the significant digits in the decimal representation of the argument.
+     * A decimal point may be optionally inserted in the string of digits
+     * (as long as its position lies within the extracted digits
+     * - if not, the caller must prepend or append the appropriate zeroes and decimal point).
+     *
+     * @param insertDecimalPoint whether to insert a decimal point
+     * @return the string containing the significant digits and possibly a decimal point,
+     * and the decimal magnitude of the value
+     */
+    fn extract_significant_digits(&self, insert_decimal_point: bool) -> (String, i32) {
+        let ten = DD::value_of_f64(10.0);
+        let one = DD::value_of_f64(1.0);
+
+        let mut y = self.abs();
+        // compute *correct* magnitude of y
+        let mut mag = self.magnitude(y.hi);
+        let scale = ten.pow(mag);
+        y = y.divide_dd(&scale);
+
+        // fix magnitude if off by one
+        if y.gt(&ten) {
+            y = y.divide_dd(&ten);
+            mag += 1;
+        } else if y.lt(&one) {
+            y = y.multiply_dd(&ten);
+            mag -= 1;
+        }
+
+        let decimal_point_pos = mag + 1;
+        let mut buf = String::new();
+        let num_digits = DD::MAX_PRINT_DIGITS - 1;
+        for i in 0..=num_digits {
+            if insert_decimal_point && i == decimal_point_pos {
+                buf.push('.');
+            }
+            let digit = y.hi as i32;
+
+            /*
+             * If a negative remainder is encountered, simply terminate the extraction.
+             * This is robust, but maybe slightly inaccurate.
+             * My current hypothesis is that negative remainders only occur for very small lo components,
+             * so the inaccuracy is tolerable
+             */
+            if digit < 0 {
+                break;
+            }
+            let mut rebias_by_10 = false;
+            let digit_char;
+            if digit > 9 {
+                // set flag to re-bias after next 10-shift
+                rebias_by_10 = true;
+                // output digit will end up being '9'
+                digit_char = '9';
+            } else {
+                digit_char = char::from(b'0' + digit as u8);
+            }
+            buf.push(digit_char);
+            y = y
+                .subtract_dd(&DD::value_of_f64(digit as f64))
+                .multiply_dd(&ten);
+            if rebias_by_10 {
+                y.self_add_dd(&ten);
+            }
+
+            /*
+             * Check if remaining digits will be 0, and if so don't output them.
+             * Do this by comparing the magnitude of the remainder with the expected precision.
+             */
+            let rem_mag = self.magnitude(y.hi);
+            if rem_mag < 0 && rem_mag <= -(num_digits - i) {
+                break;
+            }
+        }
+        return (buf, mag);
+    }
+
+    /**
+     * Creates a string of a given length containing the given character
+     *
+     * @param ch the character to be repeated
+     * @param len the len of the desired string
+     * @return the string
+     */
+    fn string_of_char(ch: char, len: i32) -> String {
+        let mut buf = String::new();
+        for _ in 0..len {
+            buf.push(ch);
+        }
+        return buf;
+    }
+
+    /**
+     * Returns the string for this value if it has a known representation.
+     * (E.g. NaN or 0.0)
+     *
+     * @return the string for this special number
+     * or None if the number is not a special number
+     */
+    fn get_special_number_string(&self) -> Option<String> {
+        if self.is_zero() {
+            return Some(String::from("0.0"));
+        }
+        if self.is_nan() {
+            return Some(String::from("NaN"));
+        }
+        return None;
+    }
 
     /**
      * Determines the decimal magnitude of a number.
@@ -1349,114 +1441,166 @@ impl DD {
         // in the computed magnitude.
         // Following tests that magnitude is correct, and adjusts it if not
 
-        let x_approx = i32::pow(10, x_mag as u32);
-        if x_approx * 10 <= x_abs as i32 {
+        let x_approx = f64::powi(10., x_mag);
+        if x_approx * 10. <= x_abs {
             x_mag += 1;
         }
 
         return x_mag;
     }
 
-    // /*------------------------------------------------------------
-    //  *   Input
-    //  *------------------------------------------------------------
-    //  */
-    // /**
-    //  * Converts a string representation of a real number into a DoubleDouble value.
-    //  * The format accepted is similar to the standard Java real number syntax.
-    //  * It is defined by the following regular expression:
-    //  * <pre>
-    //  * [<tt>+</tt>|<tt>-</tt>] {<i>digit</i>} [ <tt>.</tt> {<i>digit</i>} ] [ ( <tt>e</tt> | <tt>E</tt> ) [<tt>+</tt>|<tt>-</tt>] {<i>digit</i>}+
-    //  * </pre>
-    //  *
-    //  * @param str the string to parse
-    //  * @return the value of the parsed number
-    //  * @throws NumberFormatException if <tt>str</tt> is not a valid representation of a number
-    //  */
-    // public static DD parse(String str)
-    //   throws NumberFormatException
-    // {
-    //   int i = 0;
-    //   int strlen = str.length();
-
-    //   // skip leading whitespace
-    //   while (Character.isWhitespace(str.charAt(i)))
-    //     i++;
-
-    //   // check for sign
-    //   boolean isNegative = false;
-    //   if (i < strlen) {
-    //     char signCh = str.charAt(i);
-    //     if (signCh == '-' || signCh == '+') {
-    //       i++;
-    //       if (signCh == '-') isNegative = true;
-    //     }
-    //   }
-
-    //   // scan all digits and accumulate into an integral value
-    //   // Keep track of the location of the decimal point (if any) to allow scaling later
-    //   DD val = new DD();
-
-    //   int numDigits = 0;
-    //   int numBeforeDec = 0;
-    //   int exp = 0;
-    //   boolean hasDecimalChar = false;
-    //   while (true) {
-    //     if (i >= strlen)
-    //       break;
-    //     char ch = str.charAt(i);
-    //     i++;
-    //     if (Character.isDigit(ch)) {
-    //       double d = ch - '0';
-    //       val.selfMultiply(TEN);
-    //       // MD: need to optimize this
-    //       val.selfAdd(d);
-    //       numDigits++;
-    //       continue;
-    //     }
-    //     if (ch == '.') {
-    //       numBeforeDec = numDigits;
-    //       hasDecimalChar = true;
-    //       continue;
-    //     }
-    //     if (ch == 'e' || ch == 'E') {
-    //       String expStr = str.substring(i);
-    //       // this should catch any format problems with the exponent
-    //       try {
-    //         exp = Integer.parseInt(expStr);
-    //       }
-    //       catch (NumberFormatException ex) {
-    //         throw new NumberFormatException("Invalid exponent " + expStr + " in string " + str);
-    //       }
-    //       break;
-    //     }
-    //     throw new NumberFormatException("Unexpected character '" + ch
-    //         + "' at position " + i
-    //         + " in string " + str);
-    //   }
-    //   DD val2 = val;
-
-    //   // correct number of digits before decimal sign if we don't have a decimal sign in the string
-    //   if (!hasDecimalChar) numBeforeDec = numDigits;
-
-    //   // scale the number correctly
-    //   int numDecPlaces = numDigits - numBeforeDec - exp;
-    //   if (numDecPlaces == 0) {
-    //     val2 = val;
-    //   }
-    //   else if (numDecPlaces > 0) {
-    //     DD scale = TEN.pow(numDecPlaces);
-    //     val2 = val.divide(scale);
-    //   }
-    //   else if (numDecPlaces < 0) {
-    //     DD scale = TEN.pow(-numDecPlaces);
-    //     val2 = val.multiply(scale);
-    //   }
-    //   // apply leading sign, if any
-    //   if (isNegative) {
-    //     return val2.negate();
-    //   }
-    //   return val2;
+    /*------------------------------------------------------------
+     *   Input
+     *------------------------------------------------------------
+     */
 
-    // }
+    /**
+     * Converts a string representation of a real number into a DoubleDouble value.
+     * The format accepted is similar to the standard Java real number syntax.
+     * It is defined by the following regular expression:
+     * <pre>
+     * [<tt>+</tt>|<tt>-</tt>] {<i>digit</i>} [ <tt>.</tt> {<i>digit</i>} ] [ ( <tt>e</tt> | <tt>E</tt> ) [<tt>+</tt>|<tt>-</tt>] {<i>digit</i>}+
+     * </pre>
+     *
+     * @param str the string to parse
+     * @return the value of the parsed number
+     * @throws ParseException if <tt>str</tt> is not a valid representation of a number
+     */
+    pub fn parse(str: &str) -> Result<DD, ParseException> {
+        let chars: Vec<char> = str.chars().collect();
+        let mut i = 0;
+        let strlen = chars.len();
+
+        // skip leading whitespace
+        while i < strlen && chars[i].is_whitespace() {
+            i += 1;
+        }
+
+        // check for sign
+        let mut is_negative = false;
+        if i < strlen {
+            let sign_ch = chars[i];
+            if sign_ch == '-' || sign_ch == '+' {
+                i += 1;
+                if sign_ch == '-' {
+                    is_negative = true;
+                }
+            }
+        }
+
+        // scan all digits and accumulate into an integral value
+        // Keep track of the location of the decimal point (if any) to allow scaling later
+        let ten = DD::value_of_f64(10.0);
+        let mut val = DD::default();
+
+        let mut num_digits: i32 = 0;
+        let mut num_before_dec = 0;
+        let mut exp = 0;
+        let mut has_decimal_char = false;
+        while i < strlen {
+            let ch = chars[i];
+            i += 1;
+            if let Some(d) = ch.to_digit(10) {
+                val.self_multiply_dd(&ten);
+                // MD: need to optimize this
+                val.self_add_f64(d as f64);
+                num_digits += 1;
+                continue;
+            }
+            if ch == '.' {
+                num_before_dec = num_digits;
+                has_decimal_char = true;
+                continue;
+            }
+            if ch == 'e' || ch == 'E' {
+                let exp_str: String = chars[i..].iter().collect();
+                // this should catch any format problems with the exponent
+                exp = exp_str.parse::<i32>().map_err(|_| {
+                    ParseException::new(&format!("Invalid exponent {} in string {}", exp_str, str))
+                })?;
+                break;
+            }
+            return Err(ParseException::new(&format!(
+                "Unexpected character '{}' at position {} in string {}",
+                ch, i, str
+            )));
+        }
+        if num_digits == 0 {
+            return Err(ParseException::new(&format!(
+                "No digits found in string {}",
+                str
+            )));
+        }
+
+        // correct number of digits before decimal sign if we don't have a decimal sign in the string
+        if !has_decimal_char {
+            num_before_dec = num_digits;
+        }
+
+        // scale the number correctly
+        let num_dec_places = (num_digits - num_before_dec)
+            .checked_sub(exp)
+            .ok_or_else(|| {
+                ParseException::new(&format!("Exponent out of range in string {}", str))
+            })?;
+        let val2 = match num_dec_places.cmp(&0) {
+            std::cmp::Ordering::Equal => val,
+            std::cmp::Ordering::Greater => val.divide_dd(&ten.pow(num_dec_places)),
+            std::cmp::Ordering::Less => val.multiply_dd(&ten.pow(-num_dec_places)),
+        };
+        // apply leading sign, if any
+        if is_negative {
+            return Ok(val2.negate());
+        }
+        return Ok(val2);
+    }
+}
+
+impl std::fmt::Display for DD {
+    /**
+     * Writes a string representation of this number, in either standard or scientific notation.
+     * If the magnitude of the number is in the range [ 10<sup>-3</sup>, 10<sup>8</sup> ]
+     * standard notation will be used.  Otherwise, scientific notation will be used.
+     */
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(special_str) = self.get_special_number_string() {
+            return write!(f, "{}", special_str);
+        }
+        let mag = self.magnitude(self.hi);
+        if (-3..=8).contains(&mag) {
+            return write!(f, "{}", self.to_standard_notation());
+        }
+        return write!(f, "{}", self.to_sci_notation());
+    }
+}
+
+impl PartialEq for DD {
+    /**
+     * Values are equal if they have the same components.
+     * A NaN value is not equal to any value.
+     */
+    fn eq(&self, other: &Self) -> bool {
+        return self.equals(other);
+    }
+}
+
+impl PartialOrd for DD {
+    /**
+     * Values are ordered numerically.
+     * A NaN value has no ordering with any value.
+     */
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        return Some(self.compare_to(other).cmp(&0));
+    }
+}
+
+impl std::str::FromStr for DD {
+    type Err = ParseException;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return DD::parse(s);
+    }
 }
@@ -1,4 +1,13 @@
 pub(crate) mod algorithm;
 pub(crate) mod geom;
+pub(crate) mod geomgraph;
+pub(crate) mod index;
+pub(crate) mod io;
 pub(crate) mod math;
+pub(crate) mod noding;
+pub(crate) mod operation;
+pub(crate) mod simplify;
+#[cfg(test)]
+pub(crate) mod test_util;
+pub(crate) mod triangulate;
 pub(crate) mod util;
\ No newline at end of file
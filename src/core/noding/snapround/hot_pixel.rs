@@ -0,0 +1,270 @@
+use crate::core::{algorithm::cg_algorithms_dd::CGAlgorithmsDD, geom::coordinate::Coordinate};
+
+/**
+ * Implements a "hot pixel" as used in the Snap Rounding algorithm.
+ * A hot pixel is a square region centred
+ * on the rounded value of the coordinate given,
+ * and of width equal to the size of the scale factor.
+ * It is a partially open region, which contains
+ * the interior of the tolerance square and
+ * the boundary
+ * <b>minus</b> the top and right segments.
+ * This ensures that every point of the space lies in a unique hot pixel.
+ * It also means that some degenerate cases involving points lying exactly on the boundary
+ * are handled correctly.
+ * <p>
+ * The hot pixel operations are all computed in the integer domain
+ * to avoid rounding problems.
+ *
+ * @version 1.7
+ */
+#[derive(Clone, Copy)]
+pub struct HotPixel {
+    original_pt: Coordinate,
+    scale_factor: f64,
+    /**
+     * The scaled ordinates of the hot pixel point
+     */
+    hpx: f64,
+    hpy: f64,
+}
+
+impl HotPixel {
+    /**
+     * The tolerance around a hot pixel centre, in scaled units.
+     */
+    const TOLERANCE: f64 = 0.5;
+
+    /**
+     * Creates a new hot pixel centered on a rounded point, using a given scale factor.
+     * The scale factor must be strictly positive (non-zero).
+     *
+     * @param pt the coordinate at the centre of the pixel (already rounded)
+     * @param scaleFactor the scaleFactor determining the pixel size.  Must be &gt; 0
+     */
+    pub fn new(pt: &Coordinate, scale_factor: f64) -> Self {
+        assert!(scale_factor > 0., "Scale factor must be non-zero");
+        let mut hpx = pt.x;
+        let mut hpy = pt.y;
+        if scale_factor != 1.0 {
+            hpx = HotPixel::scale_round(pt.x, scale_factor);
+            hpy = HotPixel::scale_round(pt.y, scale_factor);
+        }
+        Self {
+            original_pt: *pt,
+            scale_factor,
+            hpx,
+            hpy,
+        }
+    }
+
+    /**
+     * Gets the coordinate this hot pixel is based at.
+     *
+     * @return the coordinate of the pixel
+     */
+    pub fn get_coordinate(&self) -> Coordinate {
+        return self.original_pt;
+    }
+
+    /**
+     * Gets the scale factor for the precision grid for this pixel.
+     *
+     * @return the pixel scale factor
+     */
+    pub fn get_scale_factor(&self) -> f64 {
+        return self.scale_factor;
+    }
+
+    /**
+     * Gets the width of the hot pixel in the original coordinate system.
+     *
+     * @return the width of the hot pixel tolerance square
+     */
+    pub fn get_width(&self) -> f64 {
+        return 1.0 / self.scale_factor;
+    }
+
+    fn scale_round(val: f64, scale_factor: f64) -> f64 {
+        return f64::round(val * scale_factor);
+    }
+
+    /**
+     * Scale without rounding.
+     * This ensures intersections are checked against original
+     * linework.
+     * This is required to ensure that intersections are not missed
+     * because the segment is moved by snapping.
+     */
+    fn scale(&self, val: f64) -> f64 {
+        return val * self.scale_factor;
+    }
+
+    /**
+     * Tests whether a coordinate lies in (intersects) this hot pixel.
+     *
+     * @param p the coordinate to test
+     * @return true if the coordinate intersects this hot pixel
+     */
+    pub fn intersects_coordinate(&self, p: &Coordinate) -> bool {
+        let x = self.scale(p.x);
+        let y = self.scale(p.y);
+        if x >= self.hpx + HotPixel::TOLERANCE {
+            return false;
+        }
+        // check Left side
+        if x < self.hpx - HotPixel::TOLERANCE {
+            return false;
+        }
+        // check Top side
+        if y >= self.hpy + HotPixel::TOLERANCE {
+            return false;
+        }
+        // check Bottom side
+        if y < self.hpy - HotPixel::TOLERANCE {
+            return false;
+        }
+        return true;
+    }
+
+    /**
+     * Tests whether the line segment (p0-p1)
+     * intersects this hot pixel.
+     *
+     * @param p0 the first coordinate of the line segment to test
+     * @param p1 the second coordinate of the line segment to test
+     * @return true if the line segment intersects this hot pixel
+     */
+    pub fn intersects_segment(&self, p0: &Coordinate, p1: &Coordinate) -> bool {
+        if self.scale_factor == 1.0 {
+            return self.intersects_scaled(p0.x, p0.y, p1.x, p1.y);
+        }
+
+        let sp0x = self.scale(p0.x);
+        let sp0y = self.scale(p0.y);
+        let sp1x = self.scale(p1.x);
+        let sp1y = self.scale(p1.y);
+        return self.intersects_scaled(sp0x, sp0y, sp1x, sp1y);
+    }
+
+    fn intersects_scaled(&self, p0x: f64, p0y: f64, p1x: f64, p1y: f64) -> bool {
+        // determine oriented segment pointing in positive X direction
+        let mut px = p0x;
+        let mut py = p0y;
+        let mut qx = p1x;
+        let mut qy = p1y;
+        if px > qx {
+            px = p1x;
+            py = p1y;
+            qx = p0x;
+            qy = p0y;
+        }
+
+        /*
+         * Report false if segment env does not intersect pixel env.
+         * This check reflects the fact that the pixel Top and Right sides
+         * are open (not part of the pixel).
+         */
+        // check Right side
+        let maxx = self.hpx + HotPixel::TOLERANCE;
+        let seg_minx = f64::min(px, qx);
+        if seg_minx >= maxx {
+            return false;
+        }
+        // check Left side
+        let minx = self.hpx - HotPixel::TOLERANCE;
+        let seg_maxx = f64::max(px, qx);
+        if seg_maxx < minx {
+            return false;
+        }
+        // check Top side
+        let maxy = self.hpy + HotPixel::TOLERANCE;
+        let seg_miny = f64::min(py, qy);
+        if seg_miny >= maxy {
+            return false;
+        }
+        // check Bottom side
+        let miny = self.hpy - HotPixel::TOLERANCE;
+        let seg_maxy = f64::max(py, qy);
+        if seg_maxy < miny {
+            return false;
+        }
+
+        /*
+         * Vertical or horizontal segments must now intersect
+         * the segment interior or Left or Bottom sides.
+         */
+        if px == qx || py == qy {
+            return true;
+        }
+
+        /*
+         * Now know segment is not horizontal or vertical.
+         *
+         * Compute orientation WRT each pixel corner.
+         * If corner orientation == 0,
+         * segment intersects the corner.
+         * From the corner and whether segment is heading up or down,
+         * can determine intersection or not.
+         *
+         * Otherwise, check whether segment crosses interior of pixel side
+         * This is the case if the orientations for each corner of the side are different.
+         */
+
+        let orient_ul = CGAlgorithmsDD::orientation_index_xy(px, py, qx, qy, minx, maxy);
+        if orient_ul == 0 {
+            // upward segment does not intersect pixel interior
+            if py < qy {
+                return false;
+            }
+            // downward segment must intersect pixel interior
+            return true;
+        }
+
+        let orient_ur = CGAlgorithmsDD::orientation_index_xy(px, py, qx, qy, maxx, maxy);
+        if orient_ur == 0 {
+            // downward segment does not intersect pixel interior
+            if py > qy {
+                return false;
+            }
+            // upward segment must intersect pixel interior
+            return true;
+        }
+        //--- check crossing Top side
+        if orient_ul != orient_ur {
+            return true;
+        }
+
+        let orient_ll = CGAlgorithmsDD::orientation_index_xy(px, py, qx, qy, minx, miny);
+        if orient_ll == 0 {
+            // segment crossed LL corner, which is the only one in pixel interior
+            return true;
+        }
+        //--- check crossing Left side
+        if orient_ll != orient_ul {
+            return true;
+        }
+
+        let orient_lr = CGAlgorithmsDD::orientation_index_xy(px, py, qx, qy, maxx, miny);
+        if orient_lr == 0 {
+            // upward segment does not intersect pixel interior
+            if py < qy {
+                return false;
+            }
+            // downward segment must intersect pixel interior
+            return true;
+        }
+
+        //--- check crossing Bottom side
+        if orient_ll != orient_lr {
+            return true;
+        }
+        //--- check crossing Right side
+        if orient_lr != orient_ur {
+            return true;
+        }
+
+        // segment does not intersect pixel
+        return false;
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod hot_pixel;
+pub(crate) mod snap_rounding_noder;
@@ -0,0 +1,139 @@
+use crate::core::{
+    algorithm::robust_line_intersector::RobustLineIntersector,
+    geom::{coordinate::Coordinate, line_segment::LineSegment, precision_model::PrecisionModel},
+};
+
+use super::hot_pixel::HotPixel;
+
+/**
+ * Uses Snap Rounding to compute a rounded,
+ * fully noded arrangement from a set of line segments.
+ * Multiple segments may be equal in the output,
+ * and segments may collapse to be zero-length,
+ * in which case they are removed from the output.
+ * <p>
+ * The algorithm proceeds as follows:
+ * <ol>
+ * <li>All input vertices and all intersection points between input segments
+ * are rounded to the precision grid. Each rounded point defines a {@link HotPixel}.
+ * <li>Each input segment is snapped to every hot pixel it passes through
+ * (tested against the original, unrounded linework),
+ * which adds the pixel centre as a node of the segment.
+ * <li>The snapped segments are split at their nodes.
+ * </ol>
+ * This guarantees that the output segments have all vertices on the
+ * precision grid, and that any two output segments intersect only at
+ * common endpoints (or are collinear and overlapping).
+ * <p>
+ * This implementation compares all pairs of segments and all segment/pixel pairs,
+ * so it is intended for moderate input sizes.
+ *
+ * @version 1.17
+ */
+pub struct SnapRoundingNoder {
+    pm: PrecisionModel,
+}
+
+impl SnapRoundingNoder {
+    /**
+     * Creates a noder which rounds to the grid of a fixed precision model.
+     *
+     * @param pm the precision model to round to
+     */
+    pub fn new(pm: PrecisionModel) -> Self {
+        assert!(
+            !pm.is_floating(),
+            "Snap rounding requires a fixed precision model"
+        );
+        Self { pm }
+    }
+
+    /**
+     * Computes the nodes in the snap-rounding line arrangement
+     * of the given segments.
+     *
+     * @param segments the input segments
+     * @return the noded, rounded segments
+     */
+    pub fn compute_nodes(&self, segments: &Vec<LineSegment>) -> Vec<LineSegment> {
+        let pixels = self.compute_hot_pixels(segments);
+        let mut noded: Vec<LineSegment> = vec![];
+        for seg in segments {
+            self.snap_segment(seg, &pixels, &mut noded);
+        }
+        return noded;
+    }
+
+    fn round(&self, p: &Coordinate) -> Coordinate {
+        let mut pm = self.pm;
+        let mut pt = *p;
+        pm.make_precise_coordinate(&mut pt);
+        return pt;
+    }
+
+    /**
+     * Creates hot pixels for all segment vertices and
+     * all intersection points between segments.
+     */
+    fn compute_hot_pixels(&self, segments: &Vec<LineSegment>) -> Vec<HotPixel> {
+        let mut pts: Vec<Coordinate> = vec![];
+        for seg in segments {
+            pts.push(self.round(&seg.p0));
+            pts.push(self.round(&seg.p1));
+        }
+
+        let mut li = RobustLineIntersector::default();
+        for i in 0..segments.len() {
+            for j in (i + 1)..segments.len() {
+                let s0 = &segments[i];
+                let s1 = &segments[j];
+                li.compute_intersection_4(&s0.p0, &s0.p1, &s1.p0, &s1.p1);
+                // collinear intersections lie on segment endpoints, which are already present
+                if li.has_intersection() {
+                    pts.push(self.round(&li.get_intersection(0)));
+                }
+            }
+        }
+
+        pts.sort_by(|a, b| a.compare_to(b).cmp(&0));
+        pts.dedup_by(|a, b| a.equals_2d(b));
+
+        let scale = self.pm.get_scale();
+        return pts.iter().map(|pt| HotPixel::new(pt, scale)).collect();
+    }
+
+    /**
+     * Snaps a segment to all the hot pixels it intersects,
+     * and adds the resulting split segments to the output.
+     */
+    fn snap_segment(
+        &self,
+        seg: &LineSegment,
+        pixels: &Vec<HotPixel>,
+        noded: &mut Vec<LineSegment>,
+    ) {
+        let mut nodes: Vec<(f64, Coordinate)> = vec![];
+        nodes.push((0., self.round(&seg.p0)));
+        if !seg.p0.equals_2d(&seg.p1) {
+            for pixel in pixels {
+                if pixel.intersects_segment(&seg.p0, &seg.p1) {
+                    let pt = pixel.get_coordinate();
+                    nodes.push((seg.projection_factor(&pt).clamp(0., 1.), pt));
+                }
+            }
+        }
+        nodes.push((1., self.round(&seg.p1)));
+        // stable sort keeps the rounded endpoints at the ends
+        nodes.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut prev = nodes[0].1;
+        for (_, pt) in nodes.iter().skip(1) {
+            // remove collapsed segments
+            if pt.equals_2d(&prev) {
+                continue;
+            }
+            noded.push(LineSegment::new_from_coordinates(&prev, pt));
+            prev = *pt;
+        }
+    }
+}
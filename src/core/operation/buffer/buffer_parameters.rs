@@ -0,0 +1,188 @@
+/**
+ * A value class containing the parameters which
+ * specify how a buffer should be constructed.
+ * <p>
+ * The parameters allow control over:
+ * <ul>
+ * <li>Quadrant segments (accuracy of approximation for circular arcs)
+ * <li>End Cap style
+ * <li>Join style
+ * <li>Mitre limit
+ * </ul>
+ *
+ * @author Martin Davis
+ *
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BufferParameters {
+    quadrant_segments: i32,
+    end_cap_style: i32,
+    join_style: i32,
+    mitre_limit: f64,
+}
+
+impl BufferParameters {
+    /**
+     * Specifies a round line buffer end cap style.
+     */
+    pub const CAP_ROUND: i32 = 1;
+    /**
+     * Specifies a flat line buffer end cap style.
+     */
+    pub const CAP_FLAT: i32 = 2;
+    /**
+     * Specifies a square line buffer end cap style.
+     */
+    pub const CAP_SQUARE: i32 = 3;
+
+    /**
+     * Specifies a round join style.
+     */
+    pub const JOIN_ROUND: i32 = 1;
+    /**
+     * Specifies a mitre join style.
+     */
+    pub const JOIN_MITRE: i32 = 2;
+    /**
+     * Specifies a bevel join style.
+     */
+    pub const JOIN_BEVEL: i32 = 3;
+
+    /**
+     * The default number of facets into which to divide a fillet of 90 degrees.
+     * A value of 8 gives less than 2% max error in the buffer distance.
+     * For a max error of &lt; 1%, use QS = 12.
+     * For a max error of &lt; 0.1%, use QS = 18.
+     */
+    pub const DEFAULT_QUADRANT_SEGMENTS: i32 = 8;
+
+    /**
+     * The default mitre limit
+     * Allows fairly pointy mitres.
+     */
+    pub const DEFAULT_MITRE_LIMIT: f64 = 5.0;
+
+    /**
+     * Creates a default set of parameters
+     *
+     */
+    pub fn default() -> Self {
+        Self {
+            quadrant_segments: BufferParameters::DEFAULT_QUADRANT_SEGMENTS,
+            end_cap_style: BufferParameters::CAP_ROUND,
+            join_style: BufferParameters::JOIN_ROUND,
+            mitre_limit: BufferParameters::DEFAULT_MITRE_LIMIT,
+        }
+    }
+
+    /**
+     * Creates a set of parameters with the
+     * given parameter values.
+     *
+     * @param quadrantSegments the number of quadrant segments to use
+     * @param endCapStyle the end cap style to use
+     * @param joinStyle the join style to use
+     * @param mitreLimit the mitre limit to use
+     */
+    pub fn new(
+        quadrant_segments: i32,
+        end_cap_style: i32,
+        join_style: i32,
+        mitre_limit: f64,
+    ) -> Self {
+        let mut params = BufferParameters::default();
+        params.set_quadrant_segments(quadrant_segments);
+        params.set_end_cap_style(end_cap_style);
+        params.set_join_style(join_style);
+        params.set_mitre_limit(mitre_limit);
+        return params;
+    }
+
+    /**
+     * Gets the number of quadrant segments which will be used
+     * to approximate angle fillets in round endcaps and joins.
+     *
+     * @return the number of quadrant segments
+     */
+    pub fn get_quadrant_segments(&self) -> i32 {
+        return self.quadrant_segments;
+    }
+
+    /**
+     * Sets the number of line segments in a quarter-circle
+     * used to approximate angle fillets in round endcaps and joins.
+     * Values less than 1 are treated as 1.
+     *
+     * @param quadSegs the number of segments in a fillet for a circle quadrant
+     */
+    pub fn set_quadrant_segments(&mut self, quad_segs: i32) {
+        self.quadrant_segments = i32::max(quad_segs, 1);
+    }
+
+    /**
+     * Gets the end cap style.
+     *
+     * @return the end cap style code
+     */
+    pub fn get_end_cap_style(&self) -> i32 {
+        return self.end_cap_style;
+    }
+
+    /**
+     * Specifies the end cap style of the generated buffer.
+     * The styles supported are {@link #CAP_ROUND}, {@link #CAP_FLAT}, and {@link #CAP_SQUARE}.
+     * The default is {@link #CAP_ROUND}.
+     *
+     * @param endCapStyle the code for the end cap style
+     */
+    pub fn set_end_cap_style(&mut self, end_cap_style: i32) {
+        self.end_cap_style = end_cap_style;
+    }
+
+    /**
+     * Gets the join style.
+     *
+     * @return the join style code
+     */
+    pub fn get_join_style(&self) -> i32 {
+        return self.join_style;
+    }
+
+    /**
+     * Sets the join style for outside (reflex) corners between line segments.
+     * The styles supported are {@link #JOIN_ROUND},
+     * {@link #JOIN_MITRE} and {@link #JOIN_BEVEL}.
+     * The default is {@link #JOIN_ROUND}.
+     *
+     * @param joinStyle the code for the join style
+     */
+    pub fn set_join_style(&mut self, join_style: i32) {
+        self.join_style = join_style;
+    }
+
+    /**
+     * Gets the mitre ratio limit.
+     *
+     * @return the limit value
+     */
+    pub fn get_mitre_limit(&self) -> f64 {
+        return self.mitre_limit;
+    }
+
+    /**
+     * Sets the limit on the mitre ratio used for very sharp corners.
+     * The mitre ratio is the ratio of the distance from the corner
+     * to the end of the mitred offset corner.
+     * When two line segments meet at a sharp angle,
+     * a miter join will extend far beyond the original geometry.
+     * (and in the extreme case will be infinitely far.)
+     * To prevent unreasonable geometry, the mitre limit
+     * allows controlling the maximum length of the join corner.
+     * Corners with a ratio which exceed the limit will be beveled.
+     *
+     * @param mitreLimit the mitre ratio limit
+     */
+    pub fn set_mitre_limit(&mut self, mitre_limit: f64) {
+        self.mitre_limit = mitre_limit;
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod buffer_parameters;
+pub(crate) mod offset_curve;
@@ -0,0 +1,187 @@
+use crate::core::{
+    algorithm::{angle::Angle, orientation::Orientation},
+    geom::{
+        coordinate::Coordinate, coordinate_arrays::CoordinateArrays,
+        geometry_factory::GeometryFactory, line_segment::LineSegment, line_string::LineString,
+    },
+};
+
+use super::buffer_parameters::BufferParameters;
+
+/**
+ * Computes the single-sided offset curve of a linear geometry.
+ * The offset curve is the line lying at a constant distance from the input line,
+ * on the left side for a positive distance and on the right side for a negative distance.
+ * It runs in the same direction as the input line.
+ * <p>
+ * Outside corners are joined using the join style of the {@link BufferParameters}.
+ * At inside corners the offset segments are trimmed back to their intersection point.
+ * No end caps are added, and self-intersections of the curve
+ * (which can occur for distances which are large relative to the line segments)
+ * are not removed.
+ * This is suitable for rendering parallel lines.
+ */
+pub struct OffsetCurveBuilder {}
+
+impl OffsetCurveBuilder {
+    /**
+     * Computes the offset curve of a line.
+     * A zero offset distance returns a copy of the line.
+     *
+     * @param line the line to offset
+     * @param distance the offset distance (positive is to the left, negative is to the right)
+     * @param params the buffer parameters providing the join style
+     * @return the offset curve
+     */
+    pub fn offset_curve(line: &LineString, distance: f64, params: &BufferParameters) -> LineString {
+        let pts = CoordinateArrays::remove_repeated_points(&line.get_coordinates());
+        if distance == 0. || pts.len() < 2 {
+            return line.copy();
+        }
+
+        // repeated points have been removed, so no segment has zero length
+        let offset_segment = |i: usize| {
+            LineSegment::new_from_coordinates(&pts[i], &pts[i + 1])
+                .offset(distance)
+                .expect("segment has non-zero length")
+        };
+
+        let mut curve: Vec<Coordinate> = vec![];
+        let mut prev_offset = offset_segment(0);
+        curve.push(prev_offset.p0);
+        for i in 1..(pts.len() - 1) {
+            let offset = offset_segment(i);
+            OffsetCurveBuilder::add_join(
+                &mut curve,
+                &pts[(i - 1)..=(i + 1)],
+                &prev_offset,
+                &offset,
+                distance,
+                params,
+            );
+            prev_offset = offset;
+        }
+        curve.push(prev_offset.p1);
+
+        return GeometryFactory::create_line_string_coordinates(
+            &CoordinateArrays::remove_repeated_points(&curve),
+        );
+    }
+
+    /**
+     * Adds the points joining the offset segments at the middle vertex
+     * of a section of three line vertices.
+     */
+    fn add_join(
+        curve: &mut Vec<Coordinate>,
+        pts: &[Coordinate],
+        offset0: &LineSegment,
+        offset1: &LineSegment,
+        distance: f64,
+        params: &BufferParameters,
+    ) {
+        let (p0, p1, p2) = (&pts[0], &pts[1], &pts[2]);
+        let orientation = Orientation::index(p0, p1, p2);
+        let is_outside_turn = (orientation == Orientation::CLOCKWISE && distance > 0.)
+            || (orientation == Orientation::COUNTERCLOCKWISE && distance < 0.);
+        if orientation == Orientation::COLLINEAR {
+            // a straight continuation needs no join
+            let is_reversal = (p1.x - p0.x) * (p2.x - p1.x) + (p1.y - p0.y) * (p2.y - p1.y) < 0.;
+            if !is_reversal {
+                curve.push(offset0.p1);
+                return;
+            }
+            // a reversal is always an outside turn, joined with a round cap
+            OffsetCurveBuilder::add_fillet(curve, p1, &offset0.p1, &offset1.p0, distance, params);
+            return;
+        }
+
+        if !is_outside_turn {
+            // trim the offset segments back to where they cross
+            match offset0.intersection(offset1) {
+                Some(int_pt) => curve.push(int_pt),
+                None => {
+                    curve.push(offset0.p1);
+                    curve.push(offset1.p0);
+                }
+            }
+            return;
+        }
+
+        match params.get_join_style() {
+            BufferParameters::JOIN_MITRE => {
+                OffsetCurveBuilder::add_mitre_join(curve, p1, offset0, offset1, distance, params)
+            }
+            BufferParameters::JOIN_BEVEL => {
+                curve.push(offset0.p1);
+                curve.push(offset1.p0);
+            }
+            _ => OffsetCurveBuilder::add_fillet(
+                curve,
+                p1,
+                &offset0.p1,
+                &offset1.p0,
+                distance,
+                params,
+            ),
+        }
+    }
+
+    /**
+     * Adds a mitred join at an outside corner,
+     * or a bevel if the mitre would exceed the mitre limit.
+     */
+    fn add_mitre_join(
+        curve: &mut Vec<Coordinate>,
+        p: &Coordinate,
+        offset0: &LineSegment,
+        offset1: &LineSegment,
+        distance: f64,
+        params: &BufferParameters,
+    ) {
+        if let Some(int_pt) = offset0.line_intersection(*offset1) {
+            let mitre_ratio = int_pt.distance(p) / distance.abs();
+            if mitre_ratio <= params.get_mitre_limit() {
+                curve.push(int_pt);
+                return;
+            }
+        }
+        curve.push(offset0.p1);
+        curve.push(offset1.p0);
+    }
+
+    /**
+     * Adds the points of a circular arc around a corner point,
+     * from the end of one offset segment to the start of the next.
+     * The arc turns clockwise for a positive (left) offset
+     * and counter-clockwise for a negative (right) offset,
+     * since those are the directions of outside turns.
+     */
+    fn add_fillet(
+        curve: &mut Vec<Coordinate>,
+        p: &Coordinate,
+        p0: &Coordinate,
+        p1: &Coordinate,
+        distance: f64,
+        params: &BufferParameters,
+    ) {
+        let radius = distance.abs();
+        let start_angle = Angle::angle_coordinates(p, p0);
+        let end_angle = Angle::angle_coordinates(p, p1);
+        let mut total_angle = end_angle - start_angle;
+        if distance > 0. && total_angle >= 0. {
+            total_angle -= Angle::PI_TIMES_2;
+        } else if distance < 0. && total_angle <= 0. {
+            total_angle += Angle::PI_TIMES_2;
+        }
+
+        let angle_inc = Angle::PI_OVER_2 / params.get_quadrant_segments() as f64;
+        let n_segs = f64::ceil(total_angle.abs() / angle_inc - 1e-9).max(1.) as usize;
+        curve.push(*p0);
+        for i in 1..n_segs {
+            let angle = start_angle + total_angle * i as f64 / n_segs as f64;
+            curve.push(Angle::project(p, angle, radius));
+        }
+        curve.push(*p1);
+    }
+}
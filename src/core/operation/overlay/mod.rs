@@ -0,0 +1,2 @@
+pub(crate) mod overlay_op;
+pub(crate) mod snap;
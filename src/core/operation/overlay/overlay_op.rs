@@ -0,0 +1,116 @@
+use crate::core::geom::geometry::Geometry;
+
+/**
+ * Computes the geometric overlay of two {@link Geometry}s.  The overlay
+ * can be used to determine any boolean combination of the geometries.
+ * <p>
+ * The overlay operations themselves are not yet ported.
+ * This provides the operation codes, and a validity check on the areas
+ * of overlay results which can be used to detect gross errors
+ * in results computed by other means.
+ */
+pub struct OverlayOp {}
+
+impl OverlayOp {
+    /**
+     * The code for the Intersection overlay operation.
+     */
+    pub const INTERSECTION: i32 = 1;
+    /**
+     * The code for the Union overlay operation.
+     */
+    pub const UNION: i32 = 2;
+    /**
+     *  The code for the Difference overlay operation.
+     */
+    pub const DIFFERENCE: i32 = 3;
+    /**
+     *  The code for the Symmetric Difference overlay operation.
+     */
+    pub const SYMDIFFERENCE: i32 = 4;
+
+    /**
+     * The tolerance used for area comparisons,
+     * as a fraction of the total area of the inputs.
+     */
+    const AREA_TOLERANCE_FRACTION: f64 = 1e-9;
+
+    /**
+     * Tests whether the area of the result of an overlay operation
+     * is consistent with the areas of the inputs.
+     * Since the area of the intersection of A and B is between 0 and
+     * the smaller of their areas, and
+     * <ul>
+     * <li>area(A &#8746; B) = area(A) + area(B) - area(A &#8745; B)
+     * <li>area(A - B) = area(A) - area(A &#8745; B)
+     * <li>area(A &#916; B) = area(A) + area(B) - 2 area(A &#8745; B)
+     * </ul>
+     * each result area must lie within a range determined by the input areas.
+     * Only polygonal components contribute to the areas.
+     * <p>
+     * This is a necessary but not sufficient condition for a correct result.
+     *
+     * @param a the first input geometry
+     * @param b the second input geometry
+     * @param result the result of the overlay operation
+     * @param op the code for the overlay operation
+     * @return true if the result area is consistent with the input areas
+     */
+    pub fn check_area_invariant(a: &Geometry, b: &Geometry, result: &Geometry, op: i32) -> bool {
+        let area_a = OverlayOp::area(a);
+        let area_b = OverlayOp::area(b);
+        let area_result = OverlayOp::area(result);
+        let tolerance = OverlayOp::AREA_TOLERANCE_FRACTION * (area_a + area_b);
+
+        let (min_area, max_area) = match op {
+            OverlayOp::INTERSECTION => (0., f64::min(area_a, area_b)),
+            OverlayOp::UNION => (f64::max(area_a, area_b), area_a + area_b),
+            OverlayOp::DIFFERENCE => (f64::max(area_a - area_b, 0.), area_a),
+            OverlayOp::SYMDIFFERENCE => ((area_a - area_b).abs(), area_a + area_b),
+            _ => panic!("Unknown overlay operation: {}", op),
+        };
+        return area_result >= min_area - tolerance && area_result <= max_area + tolerance;
+    }
+
+    /**
+     * Tests whether the areas of the results of all the overlay operations
+     * on two geometries satisfy the identities
+     * <ul>
+     * <li>area(A &#8745; B) + area(A - B) = area(A)
+     * <li>area(A &#8746; B) + area(A &#8745; B) = area(A) + area(B)
+     * <li>area(A &#916; B) + area(A &#8745; B) = area(A &#8746; B)
+     * </ul>
+     * to within a small tolerance.
+     *
+     * @param a the first input geometry
+     * @param b the second input geometry
+     * @param intersection the intersection of the inputs
+     * @param union the union of the inputs
+     * @param difference the difference of the first input and the second input
+     * @param sym_difference the symmetric difference of the inputs
+     * @return true if the result areas satisfy the identities
+     */
+    pub fn check_area_identities(
+        a: &Geometry,
+        b: &Geometry,
+        intersection: &Geometry,
+        union: &Geometry,
+        difference: &Geometry,
+        sym_difference: &Geometry,
+    ) -> bool {
+        let area_a = OverlayOp::area(a);
+        let area_b = OverlayOp::area(b);
+        let area_int = OverlayOp::area(intersection);
+        let area_union = OverlayOp::area(union);
+        let tolerance = OverlayOp::AREA_TOLERANCE_FRACTION * (area_a + area_b);
+
+        let is_equal = |x: f64, y: f64| (x - y).abs() <= tolerance;
+        return is_equal(area_int + OverlayOp::area(difference), area_a)
+            && is_equal(area_union + area_int, area_a + area_b)
+            && is_equal(OverlayOp::area(sym_difference) + area_int, area_union);
+    }
+
+    fn area(geom: &Geometry) -> f64 {
+        return geom.extract_polygons().iter().map(|p| p.get_area()).sum();
+    }
+}
@@ -0,0 +1,186 @@
+use crate::core::{
+    geom::{
+        coordinate::Coordinate, coordinate_arrays::CoordinateArrays, envelope::Envelope,
+        geometry::Geometry, geometry_collection::GeometryCollection,
+        geometry_factory::GeometryFactory, line_string::LineString, linear_ring::LinearRing,
+        multi_line_string::MultiLineString, multi_polygon::MultiPolygon, polygon::Polygon,
+    },
+    index::quadtree::Quadtree,
+};
+
+/**
+ * Snaps the vertices of a geometry
+ * to vertices of the same geometry which lie within a given tolerance.
+ * This removes near-coincident vertices, which can cause robustness
+ * problems in noding and overlay.
+ * <p>
+ * The vertices are clustered greedily, in the order they occur in the geometry:
+ * each vertex either joins the nearest existing cluster whose
+ * representative vertex lies within the tolerance,
+ * or becomes the representative of a new cluster.
+ * Every vertex is then replaced by the representative of its cluster,
+ * and consecutive repeated vertices are removed.
+ * <p>
+ * Rings remain closed. Lines or rings which would collapse
+ * (to fewer than 2 or 4 vertices respectively) are left unchanged,
+ * so that the structure of the geometry is preserved.
+ * Puntal components are not changed.
+ */
+pub struct GeometrySnapper {
+    /**
+     * The representative vertices of the clusters.
+     */
+    snap_pts: Quadtree<Coordinate>,
+    snap_tolerance: f64,
+}
+
+impl GeometrySnapper {
+    /**
+     * Snaps a geometry to itself.
+     * Vertices lying within the snap tolerance of each other
+     * are merged into a single vertex.
+     *
+     * @param geom the geometry to snap
+     * @param snapTolerance the snapping tolerance
+     * @return a new snapped Geometry
+     */
+    pub fn snap_to_self(geom: &Geometry, snap_tolerance: f64) -> Geometry {
+        assert!(snap_tolerance >= 0.0, "Snap tolerance must be non-negative");
+        let mut snapper = GeometrySnapper {
+            snap_pts: Quadtree::new(),
+            snap_tolerance,
+        };
+        for pt in GeometrySnapper::extract_vertices(geom) {
+            if snapper.find_snap_point(&pt).is_none() {
+                snapper.snap_pts.insert(&Envelope::new_coordinate(&pt), pt);
+            }
+        }
+        return snapper.snap_geometry(geom);
+    }
+
+    fn extract_vertices(geom: &Geometry) -> Vec<Coordinate> {
+        return match geom {
+            Geometry::Point(g) => g.get_coordinates(),
+            Geometry::MultiPoint(g) => g.get_coordinates(),
+            Geometry::LineString(g) => g.get_coordinates(),
+            Geometry::LinearRing(g) => g.get_coordinates(),
+            Geometry::Polygon(g) => GeometrySnapper::polygon_rings(g)
+                .iter()
+                .flat_map(|ring| ring.get_coordinates())
+                .collect(),
+            Geometry::MultiLineString(g) => (0..g.get_num_line_strings())
+                .flat_map(|i| g.get_line_string_at_index(i).get_coordinates())
+                .collect(),
+            Geometry::MultiPolygon(g) => (0..g.get_num_polygons())
+                .flat_map(|i| {
+                    GeometrySnapper::extract_vertices(&Geometry::Polygon(g.get_polygon_at_index(i)))
+                })
+                .collect(),
+            Geometry::GeometryCollection(g) => (0..g.get_num_geometries())
+                .flat_map(|i| GeometrySnapper::extract_vertices(&g.get_geometry_n(i)))
+                .collect(),
+        };
+    }
+
+    fn polygon_rings(polygon: &Polygon) -> Vec<LinearRing> {
+        if polygon.is_empty() {
+            return vec![];
+        }
+        let mut rings = vec![polygon.get_exterior_ring()];
+        for i in 0..polygon.get_num_interior_ring() {
+            rings.push(polygon.get_interior_ring_n(i));
+        }
+        return rings;
+    }
+
+    /**
+     * Finds the nearest cluster representative within the snap tolerance of a point.
+     */
+    fn find_snap_point(&self, pt: &Coordinate) -> Option<Coordinate> {
+        let mut search_env = Envelope::new_coordinate(pt);
+        search_env.expand_by(self.snap_tolerance);
+        let mut snap_pt: Option<Coordinate> = None;
+        let mut min_dist = f64::INFINITY;
+        for candidate in self.snap_pts.query(&search_env) {
+            let dist = candidate.distance(pt);
+            if dist <= self.snap_tolerance && dist < min_dist {
+                min_dist = dist;
+                snap_pt = Some(*candidate);
+            }
+        }
+        return snap_pt;
+    }
+
+    fn snap_geometry(&self, geom: &Geometry) -> Geometry {
+        return match geom {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => geom.copy(),
+            Geometry::LineString(g) => Geometry::LineString(self.snap_line_string(g)),
+            Geometry::LinearRing(g) => Geometry::LinearRing(self.snap_ring(g)),
+            Geometry::Polygon(g) => Geometry::Polygon(self.snap_polygon(g)),
+            Geometry::MultiLineString(g) => {
+                let line_strings: Vec<LineString> = (0..g.get_num_line_strings())
+                    .map(|i| self.snap_line_string(&g.get_line_string_at_index(i)))
+                    .collect();
+                Geometry::MultiLineString(MultiLineString::new_with_line_strings(&line_strings))
+            }
+            Geometry::MultiPolygon(g) => {
+                let polygons: Vec<Polygon> = (0..g.get_num_polygons())
+                    .map(|i| self.snap_polygon(&g.get_polygon_at_index(i)))
+                    .collect();
+                Geometry::MultiPolygon(MultiPolygon::new_with_polygons(&polygons))
+            }
+            Geometry::GeometryCollection(g) => {
+                let geometries: Vec<Geometry> = (0..g.get_num_geometries())
+                    .map(|i| self.snap_geometry(&g.get_geometry_n(i)))
+                    .collect();
+                Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&geometries))
+            }
+        };
+    }
+
+    /**
+     * Snaps each vertex to its cluster representative,
+     * and removes the resulting repeated points.
+     * Returns None if the result has fewer than the minimum number of points.
+     */
+    fn snap_coordinates(
+        &self,
+        coords: &[Coordinate],
+        minimum_points: usize,
+    ) -> Option<Vec<Coordinate>> {
+        let snapped: Vec<Coordinate> = coords
+            .iter()
+            .map(|pt| self.find_snap_point(pt).unwrap_or(*pt))
+            .collect();
+        let snapped = CoordinateArrays::remove_repeated_points(&snapped);
+        if snapped.len() < minimum_points {
+            return None;
+        }
+        return Some(snapped);
+    }
+
+    fn snap_line_string(&self, line: &LineString) -> LineString {
+        return match self.snap_coordinates(&line.get_coordinates(), 2) {
+            Some(coords) => GeometryFactory::create_line_string_coordinates(&coords),
+            None => line.copy(),
+        };
+    }
+
+    fn snap_ring(&self, ring: &LinearRing) -> LinearRing {
+        return match self.snap_coordinates(&ring.get_coordinates(), 4) {
+            Some(coords) => GeometryFactory::create_linear_ring_with_coordinates(&coords),
+            None => ring.copy(),
+        };
+    }
+
+    fn snap_polygon(&self, polygon: &Polygon) -> Polygon {
+        if polygon.is_empty() {
+            return polygon.copy();
+        }
+        let shell = self.snap_ring(&polygon.get_exterior_ring());
+        let holes: Vec<LinearRing> = (0..polygon.get_num_interior_ring())
+            .map(|i| self.snap_ring(&polygon.get_interior_ring_n(i)))
+            .collect();
+        return GeometryFactory::create_polygon_with_linear_ring_vec(&shell, &holes);
+    }
+}
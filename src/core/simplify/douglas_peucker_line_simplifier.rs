@@ -0,0 +1,134 @@
+use crate::core::geom::{coordinate::Coordinate, line_segment::LineSegment};
+
+/**
+ * Simplifies a linestring (sequence of points) using
+ * the standard Douglas-Peucker algorithm.
+ * <p>
+ * The simplified line always has at least a given minimum number of points
+ * (if the input has that many).
+ * If simplifying with the distance tolerance removes too many points,
+ * the tolerance is successively halved until enough points are retained.
+ *
+ * @version 1.7
+ */
+pub struct DouglasPeuckerLineSimplifier {
+    pts: Vec<Coordinate>,
+    use_pt: Vec<bool>,
+    distance_tolerance: f64,
+    minimum_points: usize,
+}
+
+impl DouglasPeuckerLineSimplifier {
+    /**
+     * The maximum number of times the distance tolerance is halved
+     * when trying to retain the minimum number of points.
+     */
+    const MAX_TOLERANCE_REDUCTIONS: usize = 64;
+
+    pub fn simplify(pts: &Vec<Coordinate>, distance_tolerance: f64) -> Vec<Coordinate> {
+        return DouglasPeuckerLineSimplifier::simplify_with_minimum_points(
+            pts,
+            distance_tolerance,
+            2,
+        );
+    }
+
+    pub fn simplify_with_minimum_points(
+        pts: &Vec<Coordinate>,
+        distance_tolerance: f64,
+        minimum_points: usize,
+    ) -> Vec<Coordinate> {
+        let mut simp = DouglasPeuckerLineSimplifier::new(pts);
+        simp.set_distance_tolerance(distance_tolerance);
+        simp.set_minimum_points(minimum_points);
+        return simp.simplify_internal();
+    }
+
+    pub fn new(pts: &Vec<Coordinate>) -> Self {
+        Self {
+            pts: pts.to_vec(),
+            use_pt: vec![],
+            distance_tolerance: 0.0,
+            minimum_points: 2,
+        }
+    }
+
+    /**
+     * Sets the distance tolerance for the simplification.
+     * All vertices in the simplified linestring will be within this
+     * distance of the original linestring.
+     *
+     * @param distanceTolerance the approximation tolerance to use
+     */
+    pub fn set_distance_tolerance(&mut self, distance_tolerance: f64) {
+        self.distance_tolerance = distance_tolerance;
+    }
+
+    /**
+     * Sets the minimum number of points the simplified line must have.
+     *
+     * @param minimumPoints the minimum number of points to retain
+     */
+    pub fn set_minimum_points(&mut self, minimum_points: usize) {
+        self.minimum_points = minimum_points;
+    }
+
+    fn simplify_internal(&mut self) -> Vec<Coordinate> {
+        if self.pts.len() <= self.minimum_points {
+            return self.pts.to_vec();
+        }
+        let mut tolerance = self.distance_tolerance;
+        for _ in 0..DouglasPeuckerLineSimplifier::MAX_TOLERANCE_REDUCTIONS {
+            let simplified = self.simplify_with_tolerance(tolerance);
+            if simplified.len() >= self.minimum_points {
+                return simplified;
+            }
+            tolerance /= 2.0;
+        }
+        return self.pts.to_vec();
+    }
+
+    fn simplify_with_tolerance(&mut self, tolerance: f64) -> Vec<Coordinate> {
+        self.use_pt = vec![true; self.pts.len()];
+        self.simplify_section(0, self.pts.len() - 1, tolerance);
+
+        let mut simplified: Vec<Coordinate> = vec![];
+        for i in 0..self.pts.len() {
+            if !self.use_pt[i] {
+                continue;
+            }
+            // remove repeated points
+            if let Some(last) = simplified.last() {
+                if last.equals_2d(&self.pts[i]) {
+                    continue;
+                }
+            }
+            simplified.push(self.pts[i]);
+        }
+        return simplified;
+    }
+
+    fn simplify_section(&mut self, i: usize, j: usize, tolerance: f64) {
+        if i + 1 == j {
+            return;
+        }
+        let seg = LineSegment::new_from_coordinates(&self.pts[i], &self.pts[j]);
+        let mut max_distance = -1.0;
+        let mut max_index = i;
+        for k in i + 1..j {
+            let distance = seg.distance_coordinate(&self.pts[k]);
+            if distance > max_distance {
+                max_distance = distance;
+                max_index = k;
+            }
+        }
+        if max_distance <= tolerance {
+            for k in i + 1..j {
+                self.use_pt[k] = false;
+            }
+        } else {
+            self.simplify_section(i, max_index, tolerance);
+            self.simplify_section(max_index, j, tolerance);
+        }
+    }
+}
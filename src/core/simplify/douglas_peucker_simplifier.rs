@@ -0,0 +1,178 @@
+use crate::core::geom::{
+    coordinate::Coordinate, geometry::Geometry, geometry_collection::GeometryCollection,
+    geometry_factory::GeometryFactory, line_string::LineString, linear_ring::LinearRing,
+    multi_line_string::MultiLineString, multi_polygon::MultiPolygon, polygon::Polygon,
+};
+
+use super::douglas_peucker_line_simplifier::DouglasPeuckerLineSimplifier;
+
+/**
+ * Simplifies a {@link Geometry} using the Douglas-Peucker algorithm.
+ * Simple lines are not guaranteed to remain simple after simplification.
+ * All geometry types are handled.
+ * Empty and point geometries are returned unchanged.
+ * <p>
+ * Note that in general D-P does not preserve topology -
+ * e.g. polygons can be split, collapse to lines or disappear
+ * holes can be created or disappear,
+ * and lines can cross.
+ * To avoid collapse, simplified lines always retain at least 2 points,
+ * and simplified rings at least 4 points
+ * (or a larger number given by {@link #setMinimumPoints(int)}).
+ * If the distance tolerance would remove too many vertices
+ * it is reduced for that component until enough vertices are retained.
+ *
+ * @version 1.7
+ */
+pub struct DouglasPeuckerSimplifier {
+    input_geom: Geometry,
+    distance_tolerance: f64,
+    minimum_points: usize,
+}
+
+impl DouglasPeuckerSimplifier {
+    /**
+     * The minimum number of points in a simplified line.
+     */
+    const MIN_LINE_POINTS: usize = 2;
+    /**
+     * The minimum number of points in a simplified ring.
+     */
+    const MIN_RING_POINTS: usize = 4;
+
+    /**
+     * Simplifies a geometry using a given tolerance.
+     *
+     * @param geom geometry to simplify
+     * @param distanceTolerance the tolerance to use
+     * @return a simplified version of the geometry
+     */
+    pub fn simplify(geom: &Geometry, distance_tolerance: f64) -> Geometry {
+        let mut tss = DouglasPeuckerSimplifier::new(geom);
+        tss.set_distance_tolerance(distance_tolerance);
+        return tss.get_result_geometry();
+    }
+
+    /**
+     * Creates a simplifier for a given geometry.
+     *
+     * @param inputGeom the geometry to simplify
+     */
+    pub fn new(input_geom: &Geometry) -> Self {
+        Self {
+            input_geom: input_geom.clone(),
+            distance_tolerance: 0.0,
+            minimum_points: 0,
+        }
+    }
+
+    /**
+     * Sets the distance tolerance for the simplification.
+     * All vertices in the simplified geometry will be within this
+     * distance of the original geometry.
+     * The tolerance value must be non-negative.
+     *
+     * @param distanceTolerance the approximation tolerance to use
+     */
+    pub fn set_distance_tolerance(&mut self, distance_tolerance: f64) {
+        assert!(distance_tolerance >= 0.0, "Tolerance must be non-negative");
+        self.distance_tolerance = distance_tolerance;
+    }
+
+    /**
+     * Sets the minimum number of points retained by each simplified
+     * line and ring.
+     * Lines always retain at least 2 points and rings at least 4 points,
+     * so smaller values have no effect.
+     *
+     * @param minimumPoints the minimum number of points to retain
+     */
+    pub fn set_minimum_points(&mut self, minimum_points: usize) {
+        self.minimum_points = minimum_points;
+    }
+
+    /**
+     * Gets the simplified geometry.
+     *
+     * @return the simplified geometry
+     */
+    pub fn get_result_geometry(&self) -> Geometry {
+        // empty input produces an empty result
+        if self.input_geom.is_empty() {
+            return self.input_geom.copy();
+        }
+        return self.simplify_geometry(&self.input_geom);
+    }
+
+    fn simplify_geometry(&self, geom: &Geometry) -> Geometry {
+        return match geom {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => geom.copy(),
+            Geometry::LineString(g) => Geometry::LineString(self.simplify_line_string(g)),
+            Geometry::LinearRing(g) => Geometry::LinearRing(self.simplify_ring(g)),
+            Geometry::Polygon(g) => Geometry::Polygon(self.simplify_polygon(g)),
+            Geometry::MultiLineString(g) => {
+                let line_strings: Vec<LineString> = (0..g.get_num_line_strings())
+                    .map(|i| self.simplify_line_string(&g.get_line_string_at_index(i)))
+                    .collect();
+                Geometry::MultiLineString(MultiLineString::new_with_line_strings(&line_strings))
+            }
+            Geometry::MultiPolygon(g) => {
+                let polygons: Vec<Polygon> = (0..g.get_num_polygons())
+                    .map(|i| self.simplify_polygon(&g.get_polygon_at_index(i)))
+                    .collect();
+                Geometry::MultiPolygon(MultiPolygon::new_with_polygons(&polygons))
+            }
+            Geometry::GeometryCollection(g) => {
+                let geometries: Vec<Geometry> = (0..g.get_num_geometries())
+                    .map(|i| self.simplify_geometry(&g.get_geometry_n(i)))
+                    .collect();
+                Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&geometries))
+            }
+        };
+    }
+
+    fn simplify_coordinates(
+        &self,
+        coords: &Vec<Coordinate>,
+        minimum_points: usize,
+    ) -> Vec<Coordinate> {
+        return DouglasPeuckerLineSimplifier::simplify_with_minimum_points(
+            coords,
+            self.distance_tolerance,
+            usize::max(self.minimum_points, minimum_points),
+        );
+    }
+
+    fn simplify_line_string(&self, line: &LineString) -> LineString {
+        if line.is_empty() {
+            return line.copy();
+        }
+        let coords = self.simplify_coordinates(
+            &line.get_coordinates(),
+            DouglasPeuckerSimplifier::MIN_LINE_POINTS,
+        );
+        return GeometryFactory::create_line_string_coordinates(&coords);
+    }
+
+    fn simplify_ring(&self, ring: &LinearRing) -> LinearRing {
+        if ring.is_empty() {
+            return ring.copy();
+        }
+        let coords = self.simplify_coordinates(
+            &ring.get_coordinates(),
+            DouglasPeuckerSimplifier::MIN_RING_POINTS,
+        );
+        return GeometryFactory::create_linear_ring_with_coordinates(&coords);
+    }
+
+    fn simplify_polygon(&self, polygon: &Polygon) -> Polygon {
+        if polygon.is_empty() {
+            return polygon.copy();
+        }
+        let shell = self.simplify_ring(&polygon.get_exterior_ring());
+        let holes: Vec<LinearRing> = (0..polygon.get_num_interior_ring())
+            .map(|i| self.simplify_ring(&polygon.get_interior_ring_n(i)))
+            .collect();
+        return GeometryFactory::create_polygon_with_linear_ring_vec(&shell, &holes);
+    }
+}
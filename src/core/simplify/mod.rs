@@ -0,0 +1,2 @@
+pub(crate) mod douglas_peucker_line_simplifier;
+pub(crate) mod douglas_peucker_simplifier;
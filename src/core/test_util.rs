@@ -0,0 +1,94 @@
+use crate::core::geom::{coordinate::Coordinate, envelope::Envelope, geometry::Geometry};
+
+/**
+ * Tests whether two ordinate values are equal within a tolerance.
+ * NaN is only equal to NaN.
+ */
+fn ordinate_equals(a: f64, b: f64, tolerance: f64) -> bool {
+    if f64::is_nan(a) || f64::is_nan(b) {
+        return f64::is_nan(a) && f64::is_nan(b);
+    }
+    return (a - b).abs() <= tolerance;
+}
+
+/**
+ * Asserts that two numbers are equal within a tolerance.
+ *
+ * @param expected the expected value
+ * @param actual the actual value
+ * @param tolerance the maximum difference allowed
+ */
+pub(crate) fn assert_eq_with_tolerance(expected: f64, actual: f64, tolerance: f64) {
+    assert!(
+        ordinate_equals(expected, actual, tolerance),
+        "Expected {} but found {} (tolerance {})",
+        expected,
+        actual,
+        tolerance
+    );
+}
+
+/**
+ * Asserts that two coordinates are equal within a tolerance.
+ * The X, Y and Z ordinates are compared independently;
+ * a NaN Z ordinate only matches another NaN Z ordinate.
+ *
+ * @param expected the expected coordinate
+ * @param actual the actual coordinate
+ * @param tolerance the maximum difference allowed in each ordinate
+ */
+pub(crate) fn assert_coordinate_eq(expected: &Coordinate, actual: &Coordinate, tolerance: f64) {
+    assert!(
+        ordinate_equals(expected.x, actual.x, tolerance)
+            && ordinate_equals(expected.y, actual.y, tolerance)
+            && ordinate_equals(expected.z, actual.z, tolerance),
+        "Expected coordinate {} but found {} (tolerance {})",
+        expected,
+        actual,
+        tolerance
+    );
+}
+
+/**
+ * Asserts that two geometries are structurally equal,
+ * with vertices matching within a tolerance.
+ *
+ * @param expected the expected geometry
+ * @param actual the actual geometry
+ * @param tolerance the distance tolerance for vertex equality
+ *
+ * @see Geometry#equalsExact(Geometry, double)
+ */
+pub(crate) fn assert_geometry_eq_exact(expected: &Geometry, actual: &Geometry, tolerance: f64) {
+    assert!(
+        expected.equals_exact(actual, tolerance),
+        "Expected {} but found {} which is not equal within tolerance {}",
+        expected.get_geometry_type(),
+        actual.get_geometry_type(),
+        tolerance
+    );
+}
+
+/**
+ * Asserts that two envelopes are equal within a tolerance.
+ * Two null envelopes are equal.
+ *
+ * @param expected the expected envelope
+ * @param actual the actual envelope
+ * @param tolerance the maximum difference allowed in each bound
+ */
+pub(crate) fn assert_envelope_eq(expected: &Envelope, actual: &Envelope, tolerance: f64) {
+    let is_equal = if expected.is_null() || actual.is_null() {
+        expected.is_null() && actual.is_null()
+    } else {
+        ordinate_equals(expected.get_min_x(), actual.get_min_x(), tolerance)
+            && ordinate_equals(expected.get_max_x(), actual.get_max_x(), tolerance)
+            && ordinate_equals(expected.get_min_y(), actual.get_min_y(), tolerance)
+            && ordinate_equals(expected.get_max_y(), actual.get_max_y(), tolerance)
+    };
+    assert!(
+        is_equal,
+        "Expected envelope {} but found {} (tolerance {})",
+        expected, actual, tolerance
+    );
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use crate::core::{
+    algorithm::orientation::Orientation,
+    geom::{coordinate::Coordinate, triangle::Triangle},
+};
+
+/**
+ * A utility class which creates Delaunay Triangulations
+ * from collections of points.
+ * <p>
+ * The triangulation is computed by incremental insertion.
+ * The sites are inserted in lexicographic order, so that each new site
+ * lies outside the current triangulation and is connected to
+ * the hull edges visible from it.
+ * Edges which are not locally Delaunay are then flipped
+ * until every triangle has an empty circumcircle.
+ * Both steps use robust predicates
+ * ({@link Orientation#index} and {@link Triangle#isInCircle}),
+ * so nearly collinear sites do not produce inverted or overlapping triangles.
+ * <p>
+ * Duplicate sites are removed.
+ * If all sites are collinear the triangulation is empty.
+ *
+ * @version 1.7
+ */
+pub struct DelaunayTriangulationBuilder {
+    sites: Vec<Coordinate>,
+}
+
+impl DelaunayTriangulationBuilder {
+    /**
+     * Creates a builder for the triangulation of a set of sites.
+     *
+     * @param sites the sites to triangulate
+     */
+    pub fn new(sites: &Vec<Coordinate>) -> Self {
+        Self {
+            sites: DelaunayTriangulationBuilder::unique(sites),
+        }
+    }
+
+    /**
+     * Sorts the sites and removes duplicates.
+     */
+    fn unique(sites: &[Coordinate]) -> Vec<Coordinate> {
+        let mut pts = sites.to_vec();
+        pts.sort_by(|a, b| a.compare_to(b).cmp(&0));
+        pts.dedup_by(|a, b| a.equals_2d(b));
+        return pts;
+    }
+
+    /**
+     * Gets the distinct sites of the triangulation, in lexicographic order.
+     *
+     * @return the sites of the triangulation
+     */
+    pub fn get_sites(&self) -> &Vec<Coordinate> {
+        return &self.sites;
+    }
+
+    /**
+     * Gets the triangles of the computed triangulation.
+     * All triangles are oriented counter-clockwise.
+     *
+     * @return the triangles of the triangulation
+     */
+    pub fn get_triangles(&self) -> Vec<Triangle> {
+        return self
+            .triangulate()
+            .iter()
+            .map(|t| Triangle::new(&self.sites[t[0]], &self.sites[t[1]], &self.sites[t[2]]))
+            .collect();
+    }
+
+    /**
+     * Computes the triangulation as triples of indexes into the sorted sites.
+     * The triangles are oriented counter-clockwise.
+     */
+    pub(crate) fn triangulate(&self) -> Vec<[usize; 3]> {
+        let pts = &self.sites;
+        let n = pts.len();
+        if n < 3 {
+            return vec![];
+        }
+
+        // find the first site which is not collinear with the sites before it
+        let mut k = 2;
+        while k < n && Orientation::index(&pts[0], &pts[1], &pts[k]) == Orientation::COLLINEAR {
+            k += 1;
+        }
+        if k == n {
+            return vec![];
+        }
+
+        /*
+         * The sites before k lie in order along a line,
+         * so the initial triangulation is a fan from site k.
+         * The hull is kept as a counter-clockwise ring of site indexes.
+         */
+        let mut triangles: Vec<[usize; 3]> = vec![];
+        let mut hull: Vec<usize>;
+        if Orientation::index(&pts[0], &pts[1], &pts[k]) == Orientation::COUNTERCLOCKWISE {
+            for i in 0..k - 1 {
+                triangles.push([i, i + 1, k]);
+            }
+            hull = (0..=k).collect();
+        } else {
+            for i in 0..k - 1 {
+                triangles.push([i + 1, i, k]);
+            }
+            hull = (0..k).rev().collect();
+            hull.push(k);
+        }
+
+        for q in (k + 1)..n {
+            DelaunayTriangulationBuilder::add_outside_site(pts, q, &mut hull, &mut triangles);
+        }
+        DelaunayTriangulationBuilder::flip_to_delaunay(pts, &mut triangles);
+        return triangles;
+    }
+
+    /**
+     * Adds a site lying outside the current hull,
+     * by connecting it to every hull edge it can see.
+     * The visible edges form a single chain of the hull,
+     * which is replaced by the new site.
+     */
+    fn add_outside_site(
+        pts: &[Coordinate],
+        q: usize,
+        hull: &mut Vec<usize>,
+        triangles: &mut Vec<[usize; 3]>,
+    ) {
+        let m = hull.len();
+        let visible: Vec<bool> = (0..m)
+            .map(|i| {
+                Orientation::index(&pts[hull[i]], &pts[hull[(i + 1) % m]], &pts[q])
+                    == Orientation::CLOCKWISE
+            })
+            .collect();
+        let start = (0..m)
+            .find(|&i| visible[i] && !visible[(i + m - 1) % m])
+            .expect("Site must lie outside the hull");
+
+        let mut len = 0;
+        while visible[(start + len) % m] {
+            let u = hull[(start + len) % m];
+            let v = hull[(start + len + 1) % m];
+            triangles.push([v, u, q]);
+            len += 1;
+        }
+
+        // the hull runs from the end of the visible chain round to its start, then to q
+        let mut new_hull: Vec<usize> = (0..=(m - len))
+            .map(|j| hull[(start + len + j) % m])
+            .collect();
+        new_hull.push(q);
+        *hull = new_hull;
+    }
+
+    /**
+     * Flips edges which are not locally Delaunay until none remain.
+     * An edge is flipped if the vertex opposite it in one adjacent triangle
+     * lies inside the circumcircle of the other adjacent triangle.
+     */
+    fn flip_to_delaunay(pts: &[Coordinate], triangles: &mut [[usize; 3]]) {
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut stack: Vec<(usize, usize)> = vec![];
+        for (i, t) in triangles.iter().enumerate() {
+            for e in 0..3 {
+                let edge = (t[e], t[(e + 1) % 3]);
+                edges.insert(edge, i);
+                stack.push(edge);
+            }
+        }
+
+        while let Some((a, b)) = stack.pop() {
+            // edges may be stale if their triangles have been flipped
+            let (Some(&t0), Some(&t1)) = (edges.get(&(a, b)), edges.get(&(b, a))) else {
+                continue;
+            };
+            let c = DelaunayTriangulationBuilder::opposite(&triangles[t0], a);
+            let d = DelaunayTriangulationBuilder::opposite(&triangles[t1], b);
+            if !Triangle::is_in_circle_coordinates(&pts[a], &pts[b], &pts[c], &pts[d]) {
+                continue;
+            }
+
+            for t in [t0, t1] {
+                let tri = triangles[t];
+                for e in 0..3 {
+                    edges.remove(&(tri[e], tri[(e + 1) % 3]));
+                }
+            }
+            // the quadrilateral a-d-b-c is convex, so replace diagonal a-b by c-d
+            triangles[t0] = [c, a, d];
+            triangles[t1] = [d, b, c];
+            for t in [t0, t1] {
+                let tri = triangles[t];
+                for e in 0..3 {
+                    edges.insert((tri[e], tri[(e + 1) % 3]), t);
+                }
+            }
+            stack.extend([(a, d), (d, b), (b, c), (c, a)]);
+        }
+    }
+
+    /**
+     * Gets the vertex of a triangle opposite the edge starting at a vertex.
+     */
+    fn opposite(t: &[usize; 3], start: usize) -> usize {
+        let i = t.iter().position(|&v| v == start).unwrap();
+        return t[(i + 2) % 3];
+    }
+}
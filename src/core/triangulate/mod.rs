@@ -0,0 +1,2 @@
+pub(crate) mod delaunay_triangulation_builder;
+pub(crate) mod voronoi_diagram_builder;
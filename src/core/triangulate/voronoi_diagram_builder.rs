@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use crate::core::geom::{
+    coordinate::Coordinate, envelope::Envelope, geometry_factory::GeometryFactory,
+    polygon::Polygon, triangle::Triangle,
+};
+
+use super::delaunay_triangulation_builder::DelaunayTriangulationBuilder;
+
+/**
+ * A utility class which creates Voronoi Diagrams
+ * from collections of points.
+ * <p>
+ * The diagram is computed as the dual of the Delaunay triangulation of the sites.
+ * The cell of a site surrounded by triangles is the polygon formed by
+ * the circumcentres of those triangles, taken in order around the site.
+ * The cell of a site on the hull of the triangulation is unbounded,
+ * so it is formed by clipping the diagram extent with the perpendicular bisectors
+ * between the site and its Delaunay neighbours.
+ * <p>
+ * The diagram is clipped to the envelope of the sites expanded by its larger side,
+ * and enlarged to include the clip envelope if one is supplied.
+ *
+ * @version 1.7
+ */
+pub struct VoronoiDiagramBuilder {
+    sites: Vec<Coordinate>,
+    clip_env: Option<Envelope>,
+}
+
+impl VoronoiDiagramBuilder {
+    /**
+     * Creates a builder for the Voronoi diagram of a set of sites.
+     *
+     * @param sites the sites of the diagram
+     */
+    pub fn new(sites: &Vec<Coordinate>) -> Self {
+        Self {
+            sites: sites.clone(),
+            clip_env: None,
+        }
+    }
+
+    /**
+     * Sets the envelope to clip the diagram to.
+     * The diagram will be clipped to the larger
+     * of this envelope or an envelope surrounding the sites.
+     *
+     * @param clipEnv the clip envelope.
+     */
+    pub fn set_clip_envelope(&mut self, clip_env: &Envelope) {
+        self.clip_env = Some(*clip_env);
+    }
+
+    /**
+     * Gets the faces of the computed diagram as polygons,
+     * one for each distinct site, in lexicographic order of the sites.
+     * The cell polygons are oriented counter-clockwise.
+     *
+     * @return the cells of the diagram
+     */
+    pub fn get_diagram(&self) -> Vec<Polygon> {
+        let dtb = DelaunayTriangulationBuilder::new(&self.sites);
+        let pts = dtb.get_sites();
+        if pts.is_empty() {
+            return vec![];
+        }
+        let diagram_env = self.diagram_envelope(pts);
+
+        // the triangles around each site, rotated so that the site comes first
+        let mut incident: Vec<Vec<[usize; 3]>> = vec![vec![]; pts.len()];
+        let triangles = dtb.triangulate();
+        for t in &triangles {
+            for e in 0..3 {
+                incident[t[e]].push([t[e], t[(e + 1) % 3], t[(e + 2) % 3]]);
+            }
+        }
+
+        let mut cells: Vec<Polygon> = vec![];
+        for (i, site_triangles) in incident.iter().enumerate() {
+            let cell = match VoronoiDiagramBuilder::circumcentre_ring(pts, site_triangles) {
+                Some(ring) => VoronoiDiagramBuilder::clip_to_envelope(&ring, &diagram_env),
+                None => VoronoiDiagramBuilder::clip_to_bisectors(
+                    pts,
+                    i,
+                    &VoronoiDiagramBuilder::neighbours(pts, i, site_triangles),
+                    &diagram_env,
+                ),
+            };
+            // cocircular sites produce repeated circumcentres
+            let mut ring = cell;
+            ring.dedup_by(|a, b| a.equals_2d(b));
+            if ring.len() > 1 && ring[0].equals_2d(&ring[ring.len() - 1]) {
+                ring.pop();
+            }
+            if ring.len() < 3 {
+                continue;
+            }
+            ring.push(ring[0]);
+            cells.push(GeometryFactory::create_polygon_with_coordinates(&ring));
+        }
+        return cells;
+    }
+
+    fn diagram_envelope(&self, pts: &[Coordinate]) -> Envelope {
+        let mut env = Envelope::default();
+        for p in pts {
+            env.expand_to_include_coordinate(p);
+        }
+        // add a buffer around the sites so that the hull cells are not too thin
+        let expand_by = f64::max(env.get_width(), env.get_height());
+        env.expand_by(expand_by);
+        if let Some(clip_env) = &self.clip_env {
+            env.expand_to_include_envelope(clip_env);
+        }
+        return env;
+    }
+
+    /**
+     * Computes the cell of a site which is surrounded by triangles,
+     * as the ring of triangle circumcentres in counter-clockwise order.
+     * Returns None if the triangles do not surround the site.
+     */
+    fn circumcentre_ring(
+        pts: &[Coordinate],
+        site_triangles: &[[usize; 3]],
+    ) -> Option<Vec<Coordinate>> {
+        if site_triangles.is_empty() {
+            return None;
+        }
+        // each triangle is followed by the one which starts at its last vertex
+        let next: HashMap<usize, &[usize; 3]> = site_triangles.iter().map(|t| (t[1], t)).collect();
+        if site_triangles.iter().any(|t| !next.contains_key(&t[2])) {
+            return None;
+        }
+
+        let mut ring: Vec<Coordinate> = vec![];
+        let mut t = &site_triangles[0];
+        for _ in 0..site_triangles.len() {
+            ring.push(Triangle::circumcentre_dd_coordinates(
+                &pts[t[0]], &pts[t[1]], &pts[t[2]],
+            ));
+            t = next[&t[2]];
+        }
+        return Some(ring);
+    }
+
+    /**
+     * Gets the Delaunay neighbours of a site.
+     * If the sites are collinear there are no triangles,
+     * and the neighbours are the adjacent sites along the line.
+     */
+    fn neighbours(pts: &[Coordinate], site: usize, site_triangles: &[[usize; 3]]) -> Vec<usize> {
+        let mut neighbours: Vec<usize> = vec![];
+        if site_triangles.is_empty() {
+            if site > 0 {
+                neighbours.push(site - 1);
+            }
+            if site + 1 < pts.len() {
+                neighbours.push(site + 1);
+            }
+            return neighbours;
+        }
+        for t in site_triangles {
+            neighbours.push(t[1]);
+            neighbours.push(t[2]);
+        }
+        neighbours.sort();
+        neighbours.dedup();
+        return neighbours;
+    }
+
+    /**
+     * Computes the cell of a site by clipping the diagram envelope with
+     * the half-planes nearer to the site than to each of its neighbours.
+     */
+    fn clip_to_bisectors(
+        pts: &[Coordinate],
+        site: usize,
+        neighbours: &[usize],
+        env: &Envelope,
+    ) -> Vec<Coordinate> {
+        let p = &pts[site];
+        let mut cell = VoronoiDiagramBuilder::envelope_ring(env);
+        for &n in neighbours {
+            let q = &pts[n];
+            let mid = Coordinate::new_xy((p.x + q.x) / 2., (p.y + q.y) / 2.);
+            cell = VoronoiDiagramBuilder::clip_half_plane(&cell, &mid, q.x - p.x, q.y - p.y);
+        }
+        return cell;
+    }
+
+    fn clip_to_envelope(ring: &[Coordinate], env: &Envelope) -> Vec<Coordinate> {
+        let min = Coordinate::new_xy(env.get_min_x(), env.get_min_y());
+        let max = Coordinate::new_xy(env.get_max_x(), env.get_max_y());
+        let mut cell = VoronoiDiagramBuilder::clip_half_plane(ring, &min, -1., 0.);
+        cell = VoronoiDiagramBuilder::clip_half_plane(&cell, &min, 0., -1.);
+        cell = VoronoiDiagramBuilder::clip_half_plane(&cell, &max, 1., 0.);
+        cell = VoronoiDiagramBuilder::clip_half_plane(&cell, &max, 0., 1.);
+        return cell;
+    }
+
+    fn envelope_ring(env: &Envelope) -> Vec<Coordinate> {
+        return vec![
+            Coordinate::new_xy(env.get_min_x(), env.get_min_y()),
+            Coordinate::new_xy(env.get_max_x(), env.get_min_y()),
+            Coordinate::new_xy(env.get_max_x(), env.get_max_y()),
+            Coordinate::new_xy(env.get_min_x(), env.get_max_y()),
+        ];
+    }
+
+    /**
+     * Clips a convex ring (without a closing point) to the half-plane of points
+     * on the opposite side of a line to a normal vector.
+     * This is a single step of the Sutherland-Hodgman algorithm.
+     */
+    fn clip_half_plane(
+        ring: &[Coordinate],
+        origin: &Coordinate,
+        normal_x: f64,
+        normal_y: f64,
+    ) -> Vec<Coordinate> {
+        let side = |p: &Coordinate| (p.x - origin.x) * normal_x + (p.y - origin.y) * normal_y;
+        let mut clipped: Vec<Coordinate> = vec![];
+        for i in 0..ring.len() {
+            let prev = &ring[(i + ring.len() - 1) % ring.len()];
+            let curr = &ring[i];
+            let side_prev = side(prev);
+            let side_curr = side(curr);
+            // add the crossing point when the edge enters or leaves the half-plane
+            if (side_prev > 0. && side_curr < 0.) || (side_prev < 0. && side_curr > 0.) {
+                let frac = side_prev / (side_prev - side_curr);
+                clipped.push(Coordinate::new_xy(
+                    prev.x + frac * (curr.x - prev.x),
+                    prev.y + frac * (curr.y - prev.y),
+                ));
+            }
+            if side_curr <= 0. {
+                clipped.push(*curr);
+            }
+        }
+        return clipped;
+    }
+}
@@ -293,4 +293,35 @@ mod angle_tests {
     fn p(x: f64, y: f64) -> Coordinate {
         return Coordinate::new_xy(x, y);
     }
+
+    #[test]
+    fn test_angle_between() {
+        let tail = p(0., 0.);
+        assert_eq_tol(
+            std::f64::consts::FRAC_PI_2,
+            Angle::angle_between(&p(10., 0.), &tail, &p(0., 10.)),
+        );
+        // the angle is unoriented
+        assert_eq_tol(
+            std::f64::consts::FRAC_PI_2,
+            Angle::angle_between(&p(0., 10.), &tail, &p(10., 0.)),
+        );
+        // a straight line
+        assert_eq_tol(
+            std::f64::consts::PI,
+            Angle::angle_between(&p(-10., 0.), &tail, &p(10., 0.)),
+        );
+        // a spike
+        let spike = Angle::angle_between(&p(10., 0.), &tail, &p(10., 0.01));
+        assert!(spike > 0. && spike < 0.001);
+        // the reflex side of a corner is not measured
+        assert_eq_tol(
+            std::f64::consts::FRAC_PI_4,
+            Angle::angle_between(&p(10., 0.), &tail, &p(10., -10.)),
+        );
+    }
+
+    fn assert_eq_tol(expected: f64, actual: f64) {
+        assert!((expected - actual).abs() < TOLERANCE);
+    }
 }
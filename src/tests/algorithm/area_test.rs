@@ -65,4 +65,31 @@ mod area_tests {
         let actual2 = Area::of_ring_signed_coordinate_sequence(&ring_seq);
         assert_eq!(actual2, expected_area);
     }
+
+    #[test]
+    fn test_area_vec() {
+        let square_cw: Vec<Coordinate> = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(0., 1.),
+            Coordinate::new_xy(1., 1.),
+            Coordinate::new_xy(1., 0.),
+            Coordinate::new_xy(0., 0.),
+        ];
+        assert_eq!(1., Area::of_ring_vec(&square_cw));
+        assert_eq!(1., Area::of_ring_signed_vec(&square_cw));
+
+        let mut square_ccw = square_cw.clone();
+        square_ccw.reverse();
+        assert_eq!(1., Area::of_ring_vec(&square_ccw));
+        assert_eq!(-1., Area::of_ring_signed_vec(&square_ccw));
+
+        let triangle_ccw: Vec<Coordinate> = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(4., 0.),
+            Coordinate::new_xy(0., 3.),
+            Coordinate::new_xy(0., 0.),
+        ];
+        assert_eq!(6., Area::of_ring_vec(&triangle_ccw));
+        assert_eq!(-6., Area::of_ring_signed_vec(&triangle_ccw));
+    }
 }
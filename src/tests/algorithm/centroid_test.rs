@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod centroid_tests {
-    use crate::core::geom::{
-        coordinate::Coordinate, geometry_factory::GeometryFactory, multi_polygon::MultiPolygon,
-        polygon::Polygon,
+    use crate::core::{
+        algorithm::centroid::Centroid,
+        geom::{
+            coordinate::Coordinate, geometry::Geometry, geometry_factory::GeometryFactory,
+            multi_polygon::MultiPolygon, polygon::Polygon,
+        },
     };
 
     const TOLERANCE: f64 = 1e-10;
@@ -74,4 +77,110 @@ mod centroid_tests {
             )
         );
     }
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        return GeometryFactory::create_polygon_with_coordinates(&vec![
+            Coordinate::new_xy(x, y),
+            Coordinate::new_xy(x + size, y),
+            Coordinate::new_xy(x + size, y + size),
+            Coordinate::new_xy(x, y + size),
+            Coordinate::new_xy(x, y),
+        ]);
+    }
+
+    #[test]
+    fn test_accumulator_polygon() {
+        let mut cent = Centroid::default();
+        cent.add_polygon(&square(0., 0., 2.));
+        let c = cent.get_centroid().unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(1., 1.)));
+    }
+
+    #[test]
+    fn test_accumulator_line_segment() {
+        let mut cent = Centroid::default();
+        assert!(cent.get_centroid().is_none());
+        cent.add_line_segment(&Coordinate::new_xy(0., 0.), &Coordinate::new_xy(4., 2.));
+        let c = cent.get_centroid().unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(2., 1.)));
+
+        // a zero-length segment is a point, so does not affect the line centroid
+        cent.add_line_segment(&Coordinate::new_xy(9., 9.), &Coordinate::new_xy(9., 9.));
+        let c = cent.get_centroid().unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(2., 1.)));
+    }
+
+    #[test]
+    fn test_accumulator_mixed_dimensions() {
+        let mut cent = Centroid::default();
+        cent.add_point(&Coordinate::new_xy(10., 10.));
+        cent.add_point(&Coordinate::new_xy(20., 10.));
+        let c = cent.get_centroid().unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(15., 10.)));
+
+        cent.add_line_segment(&Coordinate::new_xy(-5., 0.), &Coordinate::new_xy(-5., 4.));
+        let c = cent.get_centroid().unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(-5., 2.)));
+
+        cent.add_polygon(&square(2., 2., 2.));
+        let c = cent.get_centroid().unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(3., 3.)));
+    }
+
+    #[test]
+    fn test_geometry_collection() {
+        let line = GeometryFactory::create_line_string_coordinates(&vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(0., 10.),
+        ]);
+        let point = GeometryFactory::create_point_from_coordinate(&Coordinate::new_xy(50., 50.));
+        let gc = GeometryFactory::create_geometry_collection(&vec![
+            Geometry::Point(point.clone()),
+            Geometry::Polygon(square(10., 10., 4.)),
+            Geometry::LineString(line.clone()),
+            Geometry::Polygon(square(20., 10., 4.)),
+        ]);
+        let c = gc.get_centroid().get_coordinate().unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(17., 12.)));
+
+        let gc = GeometryFactory::create_geometry_collection(&vec![
+            Geometry::Point(point),
+            Geometry::LineString(line),
+        ]);
+        let c = gc.get_centroid().get_coordinate().unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(0., 5.)));
+
+        assert!(GeometryFactory::create_geometry_collection_empty()
+            .get_centroid()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_line_string() {
+        // segments of length 4, 2 and 2
+        let line = GeometryFactory::create_line_string_coordinates(&vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(4., 0.),
+            Coordinate::new_xy(4., 2.),
+            Coordinate::new_xy(6., 2.),
+        ]);
+        let expected = Coordinate::new_xy(
+            (4. * 2. + 2. * 4. + 2. * 5.) / 8.,
+            (4. * 0. + 2. * 1. + 2. * 2.) / 8.,
+        );
+        let c = Centroid::get_centroid_from_line_string(&line).unwrap();
+        assert!(c.equals_2d(&expected));
+
+        let zero_length = GeometryFactory::create_line_string_coordinates(&vec![
+            Coordinate::new_xy(3., 7.),
+            Coordinate::new_xy(3., 7.),
+        ]);
+        let c = Centroid::get_centroid_from_line_string(&zero_length).unwrap();
+        assert!(c.equals_2d(&Coordinate::new_xy(3., 7.)));
+
+        assert!(
+            Centroid::get_centroid_from_line_string(&GeometryFactory::create_line_string())
+                .is_none()
+        );
+    }
 }
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod distance_tests {
-    use crate::core::{algorithm::distance::Distance, geom::coordinate::Coordinate};
+    use crate::core::{
+        algorithm::distance::Distance,
+        geom::{coordinate::Coordinate, line_segment::LineSegment},
+    };
 
     #[test]
     fn test_distance_point_line_perpendicular() {
@@ -72,4 +75,53 @@ mod distance_tests {
     fn equals_with_tolerance(distance: f64, expected: f64, tolerance: f64) {
         assert!(distance >= expected - tolerance && distance <= expected + tolerance)
     }
+
+    #[test]
+    fn test_distance_point_line_perpendicular_signed() {
+        let a = Coordinate::new_xy(0., 0.);
+        let b = Coordinate::new_xy(4., 4.);
+        let seg = LineSegment::new_from_coordinates(&a, &b);
+        let pts = [
+            (Coordinate::new_xy(0., 2.), f64::sqrt(2.)),
+            (Coordinate::new_xy(2., 0.), -f64::sqrt(2.)),
+            (Coordinate::new_xy(6., 6.), 0.),
+        ];
+        for (p, expected) in &pts {
+            let dist = Distance::point_to_line_perpendicular_signed(p, &a, &b);
+            equals_with_tolerance(dist, *expected, 0.000001);
+            equals_with_tolerance(dist, seg.distance_perpendicular_oriented(p), 0.000001);
+        }
+    }
+
+    #[test]
+    fn test_distance_point_segment_3d() {
+        let a = Coordinate::new_xyz(0., 0., 0.);
+        let b = Coordinate::new_xyz(10., 0., 0.);
+        // closest to the interior of the segment
+        let dist = Distance::point_to_segment_3d(&Coordinate::new_xyz(5., 3., 4.), &a, &b);
+        equals_with_tolerance(dist, 5., 0.000001);
+        // closest to an endpoint
+        let dist = Distance::point_to_segment_3d(&Coordinate::new_xyz(12., 0., 2.), &a, &b);
+        equals_with_tolerance(dist, f64::sqrt(8.), 0.000001);
+    }
+
+    #[test]
+    fn test_distance_segment_segment_3d() {
+        // skew segments along the x and y axes, separated in z
+        let dist = Distance::segment_to_segment_3d(
+            &Coordinate::new_xyz(-5., 0., 0.),
+            &Coordinate::new_xyz(5., 0., 0.),
+            &Coordinate::new_xyz(0., -5., 3.),
+            &Coordinate::new_xyz(0., 5., 3.),
+        );
+        equals_with_tolerance(dist, 3., 0.000001);
+        // parallel segments
+        let dist = Distance::segment_to_segment_3d(
+            &Coordinate::new_xyz(0., 0., 0.),
+            &Coordinate::new_xyz(10., 0., 0.),
+            &Coordinate::new_xyz(0., 2., 2.),
+            &Coordinate::new_xyz(10., 2., 2.),
+        );
+        equals_with_tolerance(dist, f64::sqrt(8.), 0.000001);
+    }
 }
@@ -25,4 +25,26 @@ mod length_tests {
         let actual = Length::of_line(&pts);
         assert_eq!(actual, expected_len);
     }
+
+    #[test]
+    fn test_length_of_coordinates() {
+        assert_eq!(0., Length::of_line_vec(&vec![]));
+        assert_eq!(0., Length::of_line_vec(&vec![Coordinate::new_xy(1., 1.)]));
+
+        let line: Vec<Coordinate> = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(3., 4.),
+            Coordinate::new_xy(6., 8.),
+        ];
+        assert_eq!(10., Length::of_line_vec(&line));
+
+        let ring: Vec<Coordinate> = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(0., 2.),
+            Coordinate::new_xy(3., 2.),
+            Coordinate::new_xy(3., 0.),
+            Coordinate::new_xy(0., 0.),
+        ];
+        assert_eq!(10., Length::of_line_vec(&ring));
+    }
 }
@@ -6,4 +6,6 @@ mod length_tests;
 mod ray_crossing_counter_tests;
 mod point_location_tests;
 mod intersection_tests;
-mod centroid_test;
\ No newline at end of file
+mod centroid_test;
+mod orientation_tests;
+mod robust_line_intersector_tests;
\ No newline at end of file
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod orientation_tests {
+    use crate::core::{algorithm::orientation::Orientation, geom::coordinate::Coordinate};
+
+    fn ring(xy: &[(f64, f64)]) -> Vec<Coordinate> {
+        return xy.iter().map(|&(x, y)| Coordinate::new_xy(x, y)).collect();
+    }
+
+    fn reversed(pts: &Vec<Coordinate>) -> Vec<Coordinate> {
+        let mut rev = pts.clone();
+        rev.reverse();
+        return rev;
+    }
+
+    fn check_ccw_robust(pts: &Vec<Coordinate>, expected: bool) {
+        assert_eq!(expected, Orientation::is_ccw_robust(pts));
+        assert_eq!(!expected, Orientation::is_ccw_robust(&reversed(pts)));
+        assert_eq!(
+            Orientation::is_ccw_vec(pts),
+            Orientation::is_ccw_robust(pts)
+        );
+    }
+
+    #[test]
+    fn test_ccw_robust_repeated_lowest_point() {
+        let pts = ring(&[
+            (10., 0.),
+            (10., 10.),
+            (0., 10.),
+            (0., 0.),
+            (10., 0.),
+            (10., 0.),
+            (10., 0.),
+        ]);
+        check_ccw_robust(&pts, true);
+
+        let pts = ring(&[
+            (0., 0.),
+            (10., 0.),
+            (10., 0.),
+            (10., 10.),
+            (0., 10.),
+            (0., 0.),
+        ]);
+        check_ccw_robust(&pts, true);
+    }
+
+    #[test]
+    fn test_ccw_robust_collinear_bottom() {
+        let pts = ring(&[
+            (0., 0.),
+            (5., 0.),
+            (10., 0.),
+            (10., 10.),
+            (0., 10.),
+            (0., 0.),
+        ]);
+        check_ccw_robust(&pts, true);
+
+        let pts = ring(&[
+            (0., 10.),
+            (10., 10.),
+            (10., 0.),
+            (5., 0.),
+            (0., 0.),
+            (0., 10.),
+        ]);
+        check_ccw_robust(&pts, false);
+    }
+
+    #[test]
+    fn test_ccw_robust_flat() {
+        let pts = ring(&[(0., 0.), (10., 0.), (0., 0.)]);
+        assert!(!Orientation::is_ccw_robust(&pts));
+        let pts = ring(&[(1., 1.), (1., 1.), (1., 1.), (1., 1.)]);
+        assert!(!Orientation::is_ccw_robust(&pts));
+    }
+
+    /**
+     * The point is a tiny distance to the left of the line,
+     * so the naive double-precision cross product rounds to zero.
+     */
+    #[test]
+    fn test_index_dd_near_collinear() {
+        let p1 = Coordinate::new_xy(12., 12.);
+        let p2 = Coordinate::new_xy(24., 24.);
+        let q = Coordinate::new_xy(0.5, 0.5000000000000001);
+
+        let naive = (p2.x - p1.x) * (q.y - p1.y) - (p2.y - p1.y) * (q.x - p1.x);
+        assert_eq!(0., naive);
+
+        assert_eq!(
+            Orientation::COUNTERCLOCKWISE,
+            Orientation::index_dd(&p1, &p2, &q)
+        );
+        assert_eq!(Orientation::CLOCKWISE, Orientation::index_dd(&p2, &p1, &q));
+        assert_eq!(
+            Orientation::index_dd(&p1, &p2, &q),
+            Orientation::index(&p1, &p2, &q)
+        );
+        assert_eq!(
+            Orientation::COLLINEAR,
+            Orientation::index_dd(&p1, &p2, &Coordinate::new_xy(0.5, 0.5))
+        );
+    }
+}
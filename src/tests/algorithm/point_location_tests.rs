@@ -124,4 +124,32 @@ mod point_location_tests {
                 )
         );
     }
+
+    #[test]
+    fn test_in_ring() {
+        let ring: Vec<Coordinate> = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(0., 10.),
+            Coordinate::new_xy(10., 10.),
+            Coordinate::new_xy(10., 0.),
+            Coordinate::new_xy(0., 0.),
+        ];
+        assert!(PointLocation::is_in_ring(
+            &Coordinate::new_xy(5., 5.),
+            &ring
+        ));
+        // points on the boundary are in the ring
+        assert!(PointLocation::is_in_ring(
+            &Coordinate::new_xy(0., 5.),
+            &ring
+        ));
+        assert!(PointLocation::is_in_ring(
+            &Coordinate::new_xy(10., 10.),
+            &ring
+        ));
+        assert!(!PointLocation::is_in_ring(
+            &Coordinate::new_xy(11., 5.),
+            &ring
+        ));
+    }
 }
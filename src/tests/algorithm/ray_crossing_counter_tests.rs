@@ -25,4 +25,60 @@ mod ray_crossing_counter_tests {
             )
         );
     }
+
+    #[test]
+    fn test_robust_pt_near_edge() {
+        let a = Coordinate::new_xy(0.1, 0.2);
+        let b = Coordinate::new_xy(10.7, 3.9);
+        let ring: Vec<Coordinate> = vec![a, b, Coordinate::new_xy(0.1, 8.0), a];
+
+        let mut naive_interior = false;
+        let mut naive_exterior = false;
+        for i in 1..50 {
+            // points computed to lie on the edge a-b, which are not exactly representable
+            let t = i as f64 / 50.;
+            let p = Coordinate::new_xy(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y));
+
+            match RayCrossingCounter::locate_point_in_ring_vec(&p, &ring) {
+                Location::INTERIOR => naive_interior = true,
+                Location::EXTERIOR => naive_exterior = true,
+                _ => {}
+            }
+            assert_eq!(
+                Location::BOUNDARY,
+                RayCrossingCounter::locate_point_in_ring_robust(&p, &ring)
+            );
+        }
+        // the exact counter classifies the points inconsistently
+        assert!(naive_interior && naive_exterior);
+
+        assert_eq!(
+            Location::INTERIOR,
+            RayCrossingCounter::locate_point_in_ring_robust(&Coordinate::new_xy(2., 4.), &ring)
+        );
+        assert_eq!(
+            Location::EXTERIOR,
+            RayCrossingCounter::locate_point_in_ring_robust(&Coordinate::new_xy(5., 1.), &ring)
+        );
+    }
+
+    #[test]
+    fn test_locate_in_square() {
+        let ring: Vec<Coordinate> = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(10., 0.),
+            Coordinate::new_xy(10., 10.),
+            Coordinate::new_xy(0., 10.),
+            Coordinate::new_xy(0., 0.),
+        ];
+        let locate = |x: f64, y: f64| {
+            RayCrossingCounter::locate_point_in_ring_vec(&Coordinate::new_xy(x, y), &ring)
+        };
+        assert_eq!(Location::INTERIOR, locate(5., 5.));
+        assert_eq!(Location::EXTERIOR, locate(15., 5.));
+        assert_eq!(Location::EXTERIOR, locate(-5., 10.));
+        assert_eq!(Location::BOUNDARY, locate(10., 5.));
+        assert_eq!(Location::BOUNDARY, locate(5., 0.));
+        assert_eq!(Location::BOUNDARY, locate(0., 10.));
+    }
 }
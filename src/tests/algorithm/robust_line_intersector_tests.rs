@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod robust_line_intersector_tests {
+    use crate::core::{
+        algorithm::robust_line_intersector::RobustLineIntersector,
+        geom::{coordinate::Coordinate, precision_model::PrecisionModel},
+    };
+
+    fn compute(
+        p1: (f64, f64),
+        p2: (f64, f64),
+        q1: (f64, f64),
+        q2: (f64, f64),
+    ) -> RobustLineIntersector {
+        let mut li = RobustLineIntersector::default();
+        li.compute_intersection_4(
+            &Coordinate::new_xy(p1.0, p1.1),
+            &Coordinate::new_xy(p2.0, p2.1),
+            &Coordinate::new_xy(q1.0, q1.1),
+            &Coordinate::new_xy(q2.0, q2.1),
+        );
+        return li;
+    }
+
+    #[test]
+    fn test_crossing() {
+        let li = compute((0., 0.), (10., 10.), (0., 10.), (10., 0.));
+        assert_eq!(
+            RobustLineIntersector::POINT_INTERSECTION,
+            li.get_intersection_num()
+        );
+        assert!(li.is_proper());
+        assert!(li
+            .get_intersection(0)
+            .equals_2d(&Coordinate::new_xy(5., 5.)));
+    }
+
+    #[test]
+    fn test_collinear_overlap() {
+        let li = compute((0., 0.), (10., 0.), (5., 0.), (20., 0.));
+        assert_eq!(
+            RobustLineIntersector::COLLINEAR_INTERSECTION,
+            li.get_intersection_num()
+        );
+        assert!(!li.is_proper());
+    }
+
+    #[test]
+    fn test_shared_endpoint() {
+        let li = compute((0., 0.), (10., 0.), (10., 0.), (10., 10.));
+        assert_eq!(
+            RobustLineIntersector::POINT_INTERSECTION,
+            li.get_intersection_num()
+        );
+        assert!(!li.is_proper());
+        assert!(li
+            .get_intersection(0)
+            .equals_2d(&Coordinate::new_xy(10., 0.)));
+    }
+
+    #[test]
+    fn test_disjoint() {
+        let li = compute((0., 0.), (10., 0.), (0., 5.), (10., 5.));
+        assert_eq!(
+            RobustLineIntersector::NO_INTERSECTION,
+            li.get_intersection_num()
+        );
+        assert!(!li.is_proper());
+    }
+
+    #[test]
+    fn test_edge_distance_collinear() {
+        let li = compute((0., 0.), (10., 0.), (5., 0.), (20., 0.));
+        assert_eq!(5., li.get_edge_distance(0, 0));
+        assert_eq!(10., li.get_edge_distance(0, 1));
+        assert_eq!(0., li.get_edge_distance(1, 0));
+        assert_eq!(5., li.get_edge_distance(1, 1));
+    }
+
+    #[test]
+    fn test_compute_edge_distance() {
+        let p0 = Coordinate::new_xy(0., 0.);
+        let p1 = Coordinate::new_xy(10., 4.);
+        // the distance is measured along the larger ordinate range
+        let d = |x: f64, y: f64| {
+            RobustLineIntersector::compute_edge_distance(&Coordinate::new_xy(x, y), &p0, &p1)
+        };
+        assert_eq!(0., d(0., 0.));
+        assert_eq!(5., d(5., 2.));
+        assert_eq!(10., d(10., 4.));
+        // a non-endpoint always has a non-zero distance
+        assert_eq!(1., d(0., 1.));
+    }
+
+    #[test]
+    fn test_precision_model() {
+        let mut li = RobustLineIntersector::default();
+        li.set_precision_model(PrecisionModel::new_with_scale(10.));
+        li.compute_intersection_4(
+            &Coordinate::new_xy(0., 0.),
+            &Coordinate::new_xy(7., 1.),
+            &Coordinate::new_xy(0., 1.),
+            &Coordinate::new_xy(1., 0.),
+        );
+        // the exact intersection is (0.875, 0.125)
+        assert!(li
+            .get_intersection(0)
+            .equals_2d(&Coordinate::new_xy(0.9, 0.1)));
+
+        li.compute_intersection_4(
+            &Coordinate::new_xy(0., 0.),
+            &Coordinate::new_xy(10., 0.),
+            &Coordinate::new_xy(3.21, 0.),
+            &Coordinate::new_xy(20., 0.),
+        );
+        assert_eq!(
+            RobustLineIntersector::COLLINEAR_INTERSECTION,
+            li.get_intersection_num()
+        );
+        assert!(li
+            .get_intersection(0)
+            .equals_2d(&Coordinate::new_xy(3.2, 0.)));
+        assert!(li
+            .get_intersection(1)
+            .equals_2d(&Coordinate::new_xy(10., 0.)));
+    }
+
+    #[test]
+    fn test_interior_intersection() {
+        let li = compute((0., 0.), (10., 10.), (0., 10.), (10., 0.));
+        assert!(li.is_interior_intersection());
+        assert!(li.is_interior_intersection_for(0));
+        assert!(li.is_interior_intersection_for(1));
+
+        let li = compute((0., 0.), (10., 0.), (10., 0.), (10., 10.));
+        assert!(!li.is_interior_intersection());
+        assert!(!li.is_interior_intersection_for(0));
+        assert!(!li.is_interior_intersection_for(1));
+
+        // an endpoint of one segment in the interior of the other
+        let li = compute((0., 0.), (10., 0.), (5., 0.), (5., 10.));
+        assert!(li.is_interior_intersection());
+        assert!(li.is_interior_intersection_for(0));
+        assert!(!li.is_interior_intersection_for(1));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut li = RobustLineIntersector::default();
+        li.compute_intersection_4(
+            &Coordinate::new_xy(0., 0.),
+            &Coordinate::new_xy(10., 10.),
+            &Coordinate::new_xy(0., 10.),
+            &Coordinate::new_xy(10., 0.),
+        );
+        assert!(li.is_proper());
+        li.reset();
+        assert!(!li.has_intersection());
+        assert!(!li.is_proper());
+        assert_eq!(
+            RobustLineIntersector::NO_INTERSECTION,
+            li.get_intersection_num()
+        );
+    }
+}
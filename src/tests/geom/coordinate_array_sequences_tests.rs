@@ -391,4 +391,60 @@ mod coordinate_array_sequences_tests {
 
         return sequence;
     }
+
+    #[test]
+    fn test_min_coordinate_index_short_sequences() {
+        let single =
+            CoordinateArraySequence::new_with_coordinates(&vec![Coordinate::new_xy(1., 1.)]);
+        assert_eq!(
+            0,
+            CoordinateArraySequences::min_coordinate_index_sequence_from_to(&single, 0, 0)
+        );
+        assert_eq!(
+            0,
+            CoordinateArraySequences::min_coordinate_index_sequence_from_to(&single, 0, 5)
+        );
+        assert_eq!(
+            0,
+            CoordinateArraySequences::min_coordinate_index_sequence_from_to(&single, 1, 0)
+        );
+
+        let pair = CoordinateArraySequence::new_with_coordinates(&vec![
+            Coordinate::new_xy(2., 2.),
+            Coordinate::new_xy(1., 1.),
+        ]);
+        assert_eq!(
+            1,
+            CoordinateArraySequences::min_coordinate_index_sequence_from_to(&pair, 0, 1)
+        );
+        assert_eq!(
+            0,
+            CoordinateArraySequences::min_coordinate_index_sequence_from_to(&pair, 0, 0)
+        );
+
+        let empty = CoordinateArraySequence::new_with_coordinates(&vec![]);
+        assert_eq!(
+            0,
+            CoordinateArraySequences::min_coordinate_index_sequence(&empty)
+        );
+    }
+
+    #[test]
+    fn test_min_coordinate_index_ring() {
+        let ring = CoordinateArraySequence::new_with_coordinates(&vec![
+            Coordinate::new_xy(5., 5.),
+            Coordinate::new_xy(5., 0.),
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(0., 5.),
+            Coordinate::new_xy(5., 5.),
+        ]);
+        assert_eq!(
+            2,
+            CoordinateArraySequences::min_coordinate_index_sequence_from_to(
+                &ring,
+                0,
+                ring.size() - 2
+            )
+        );
+    }
 }
@@ -266,4 +266,325 @@ mod coordinate_arrays_tests {
         assert_eq!(env1.get_width(), env2.get_width());
         assert_eq!(env1.get_height(), env2.get_height());
     }
+
+    #[test]
+    fn test_remove_repeated_points_tolerance() {
+        let coords = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(10., 0.),
+            Coordinate::new_xy(10.01, 0.),
+            Coordinate::new_xy(10., 0.02),
+            Coordinate::new_xy(20., 0.),
+        ];
+        let result = CoordinateArrays::remove_repeated_points_tolerance(&coords, 0.05);
+        assert_eq!(3, result.len());
+        assert!(result[0].equals_2d(&Coordinate::new_xy(0., 0.)));
+        assert!(result[1].equals_2d(&Coordinate::new_xy(10., 0.)));
+        assert!(result[2].equals_2d(&Coordinate::new_xy(20., 0.)));
+    }
+
+    #[test]
+    fn test_remove_repeated_points_tolerance_distinct() {
+        let coords = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(1., 0.),
+            Coordinate::new_xy(1., 1.),
+        ];
+        let result = CoordinateArrays::remove_repeated_points_tolerance(&coords, 0.5);
+        assert!(CoordinateArrays::equals(&coords, &result));
+    }
+
+    #[test]
+    fn test_remove_repeated_points_tolerance_ring() {
+        let coords = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(10., 0.),
+            Coordinate::new_xy(10., 10.),
+            Coordinate::new_xy(0.01, 0.01),
+            Coordinate::new_xy(0., 0.),
+        ];
+        let result = CoordinateArrays::remove_repeated_points_tolerance(&coords, 0.1);
+        assert_eq!(4, result.len());
+        assert!(result[0].equals_2d(&result[3]));
+        assert!(result[2].equals_2d(&Coordinate::new_xy(10., 10.)));
+    }
+
+    #[test]
+    fn test_all_valid() {
+        let valid = vec![Coordinate::new_xy(0., 0.), Coordinate::new_xyz(1., 1., 1.)];
+        assert!(CoordinateArrays::all_valid(&valid));
+        assert!(CoordinateArrays::all_valid(&vec![]));
+
+        let mixed = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(f64::INFINITY, 1.),
+            Coordinate::new_xy(2., 2.),
+        ];
+        assert!(!CoordinateArrays::all_valid(&mixed));
+        let with_nan = vec![Coordinate::new_xy(0., 0.), Coordinate::new_xy(1., f64::NAN)];
+        assert!(!CoordinateArrays::all_valid(&with_nan));
+    }
+
+    fn large_array() -> Vec<Coordinate> {
+        let mut pts: Vec<Coordinate> = (0..10000)
+            .map(|i| {
+                let t = i as f64 * 0.37;
+                Coordinate::new_xy(100. * t.cos() + (i % 13) as f64, 50. * t.sin())
+            })
+            .collect();
+        pts[5000] = pts[4999];
+        pts.push(pts[0]);
+        return pts;
+    }
+
+    #[test]
+    fn test_slice_variants_match_vec() {
+        let pts = large_array();
+        let other = pts.iter().rev().copied().collect::<Vec<Coordinate>>();
+        assert!(CoordinateArrays::envelope(&pts).equals(&CoordinateArrays::envelope_slice(&pts)));
+        assert_eq!(
+            CoordinateArrays::has_repeated_points(&pts),
+            CoordinateArrays::has_repeated_points_slice(&pts)
+        );
+        assert_eq!(
+            CoordinateArrays::is_ring(&pts),
+            CoordinateArrays::is_ring_slice(&pts)
+        );
+        assert_eq!(
+            CoordinateArrays::compare(&pts, &other),
+            CoordinateArrays::compare_slice(&pts, &other)
+        );
+        assert!(CoordinateArrays::min_coordinate(&pts)
+            .unwrap()
+            .equals_2d(&CoordinateArrays::min_coordinate_slice(&pts).unwrap()));
+        assert!(CoordinateArrays::is_ring_slice(&pts));
+        assert!(CoordinateArrays::has_repeated_points_slice(&pts));
+    }
+
+    #[test]
+    fn test_slice_variants_on_sub_range() {
+        // the slice variants scan part of an array in place,
+        // where the Vec variants require copying it first
+        let pts = large_array();
+        for (start, end) in [(0, 100), (4990, 5010), (9000, 10001), (7, 7)] {
+            let sub = &pts[start..end];
+            let copy = sub.to_vec();
+            assert!(
+                CoordinateArrays::envelope(&copy).equals(&CoordinateArrays::envelope_slice(sub))
+            );
+            assert_eq!(
+                CoordinateArrays::has_repeated_points(&copy),
+                CoordinateArrays::has_repeated_points_slice(sub)
+            );
+            assert_eq!(
+                CoordinateArrays::is_ring(&copy),
+                CoordinateArrays::is_ring_slice(sub)
+            );
+            assert_eq!(0, CoordinateArrays::compare_slice(&copy, sub));
+            assert_eq!(
+                CoordinateArrays::min_coordinate(&copy).is_some(),
+                CoordinateArrays::min_coordinate_slice(sub).is_some()
+            );
+        }
+        assert!(CoordinateArrays::has_repeated_points_slice(
+            &pts[4990..5010]
+        ));
+        assert!(!CoordinateArrays::has_repeated_points_slice(&pts[0..100]));
+        assert!(CoordinateArrays::envelope_slice(&pts[7..7]).is_null());
+    }
+
+    #[test]
+    fn test_copying_functions_on_sub_range() {
+        let pts = large_array();
+        let sub = &pts[4990..5010];
+        let unique = CoordinateArrays::remove_repeated_points(sub);
+        assert_eq!(sub.len() - 1, unique.len());
+        assert!(!CoordinateArrays::has_repeated_points(&unique));
+        assert_eq!(
+            100,
+            CoordinateArrays::remove_repeated_points(&pts[0..100]).len()
+        );
+        assert_eq!(
+            20,
+            CoordinateArrays::at_least_n_coordinates_or_nothing(20, sub).len()
+        );
+        assert!(CoordinateArrays::at_least_n_coordinates_or_nothing(21, sub).is_empty());
+        let fixed = CoordinateArrays::enforce_consistency_dim_measures(sub, 3, 0);
+        assert_eq!(sub.len(), fixed.len());
+        assert!(fixed[0].get_x() == sub[0].get_x() && fixed[0].get_y() == sub[0].get_y());
+    }
+
+    #[test]
+    fn test_extract() {
+        let pts: Vec<Coordinate> = (0..5).map(|i| Coordinate::new_xy(i as f64, 0.)).collect();
+
+        let middle = CoordinateArrays::extract(&pts, 1, 3);
+        assert_eq!(3, middle.len());
+        assert_eq!(1., middle[0].x);
+        assert_eq!(3., middle[2].x);
+
+        let single = CoordinateArrays::extract(&pts, 2, 2);
+        assert_eq!(1, single.len());
+        assert_eq!(2., single[0].x);
+
+        // the end index is clamped to the array
+        let tail = CoordinateArrays::extract(&pts, 3, 10);
+        assert_eq!(2, tail.len());
+        assert_eq!(4., tail[1].x);
+    }
+
+    #[test]
+    fn test_extract_empty() {
+        let pts: Vec<Coordinate> = (0..5).map(|i| Coordinate::new_xy(i as f64, 0.)).collect();
+        assert!(CoordinateArrays::extract(&pts, 3, 2).is_empty());
+        assert!(CoordinateArrays::extract(&pts, 5, 7).is_empty());
+        assert!(CoordinateArrays::extract(&vec![], 0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_increasing_direction() {
+        let xs = |v: &[f64]| -> Vec<Coordinate> {
+            return v.iter().map(|&x| Coordinate::new_xy(x, 0.)).collect();
+        };
+        assert_eq!(-1, CoordinateArrays::increasing_direction(&xs(&[1., 2., 3., 4.])));
+        assert_eq!(1, CoordinateArrays::increasing_direction(&xs(&[4., 3., 2., 1.])));
+        // only the first differing pair from the ends is significant
+        assert_eq!(1, CoordinateArrays::increasing_direction(&xs(&[1., 9., 0., 1.])));
+        assert_eq!(-1, CoordinateArrays::increasing_direction(&xs(&[1., 0., 9., 1.])));
+        assert_eq!(1, CoordinateArrays::increasing_direction(&xs(&[1., 2., 3., 2., 1.])));
+        assert_eq!(1, CoordinateArrays::increasing_direction(&xs(&[5.])));
+        assert_eq!(1, CoordinateArrays::increasing_direction(&vec![]));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let xs = |n: usize| -> Vec<Coordinate> {
+            return (0..n).map(|i| Coordinate::new_xy(i as f64, 0.)).collect();
+        };
+        for n in [0, 1, 4, 5] {
+            let mut pts = xs(n);
+            CoordinateArrays::reverse(&mut pts);
+            let actual: Vec<f64> = pts.iter().map(|p| p.x).collect();
+            let expected: Vec<f64> = (0..n).rev().map(|i| i as f64).collect();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_remove_invalid() {
+        let pts = vec![
+            Coordinate::new_xy(1., 1.),
+            Coordinate::new_xy(f64::NAN, 2.),
+            Coordinate::new_xy(3., 3.),
+            Coordinate::new_xy(3., 3.),
+            Coordinate::new_xy(4., f64::NAN),
+            Coordinate::new_xy(5., 5.),
+        ];
+        let actual: Vec<f64> = CoordinateArrays::remove_invalid(&pts)
+            .iter()
+            .map(|p| p.x)
+            .collect();
+        // repeated points are kept
+        assert_eq!(vec![1., 3., 3., 5.], actual);
+
+        let invalid = vec![
+            Coordinate::new_xy(f64::NAN, f64::NAN),
+            Coordinate::new_xy(f64::NAN, 0.),
+        ];
+        assert!(CoordinateArrays::remove_invalid(&invalid).is_empty());
+        assert!(CoordinateArrays::remove_invalid(&vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let pts = CoordinateArrays::from_iter((0..4).map(|i| Coordinate::new_xy(i as f64, 1.)));
+        assert_eq!(4, pts.len());
+        for (i, p) in pts.iter().enumerate() {
+            assert!(p.equals_2d(&Coordinate::new_xy(i as f64, 1.)));
+        }
+        assert!(CoordinateArrays::from_iter(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_concatenate() {
+        let a = vec![Coordinate::new_xy(0., 0.), Coordinate::new_xy(1., 1.)];
+        let b = vec![Coordinate::new_xy(1., 1.), Coordinate::new_xy(2., 0.)];
+        let expected = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(1., 1.),
+            Coordinate::new_xy(1., 1.),
+            Coordinate::new_xy(2., 0.),
+        ];
+        let actual = CoordinateArrays::concatenate(&[a.clone(), b]);
+        assert!(CoordinateArrays::equals(&expected, &actual));
+        assert!(CoordinateArrays::equals(&a, &CoordinateArrays::concatenate(&[vec![], a.clone()])));
+        assert!(CoordinateArrays::concatenate(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_equals_by() {
+        let c1 = vec![Coordinate::new_xyz(1., 1., 1.), Coordinate::new_xyz(2., 2., 2.)];
+        let c2 = vec![Coordinate::new_xyz(1., 1., 5.), Coordinate::new_xyz(2., 2., 2.)];
+        let cmp_2d = |a: &Coordinate, b: &Coordinate| a.compare_to(b);
+        let cmp_3d = |a: &Coordinate, b: &Coordinate| {
+            if a.equals_3d(b) {
+                return 0;
+            }
+            return 1;
+        };
+
+        assert!(CoordinateArrays::equals_by(&c1, &c2, cmp_2d));
+        assert!(!CoordinateArrays::equals_by(&c1, &c2, cmp_3d));
+        assert!(CoordinateArrays::equals_by(&c1, &c1.clone(), cmp_3d));
+        assert!(!CoordinateArrays::equals_by(&c1, &c1[..1].to_vec(), cmp_2d));
+    }
+
+    #[test]
+    fn test_indices_intersecting() {
+        let coords = vec![
+            Coordinate::new_xy(1., 1.),
+            Coordinate::new_xy(5., 5.),
+            Coordinate::new_xy(2., 3.),
+            Coordinate::new_xy(2., 3.),
+            Coordinate::new_xy(-1., 2.),
+            Coordinate::new_xy(3., 3.),
+        ];
+        let env = Envelope::new_xy(0., 3., 0., 3.);
+        let indices = CoordinateArrays::indices_intersecting(&coords, &env);
+        assert_eq!(vec![0, 2, 3, 5], indices);
+
+        let expected = CoordinateArrays::intersection(&coords, &env);
+        let actual: Vec<Coordinate> = indices.iter().map(|&i| coords[i]).collect();
+        assert!(CoordinateArrays::equals(&expected, &actual));
+
+        assert!(CoordinateArrays::indices_intersecting(&coords, &Envelope::default()).is_empty());
+    }
+
+    #[test]
+    fn test_densify() {
+        let pts = vec![
+            Coordinate::new_xyz(0., 0., 0.),
+            Coordinate::new_xyz(10., 0., 10.),
+            Coordinate::new_xyz(10., 1., 10.),
+        ];
+        let densified = CoordinateArrays::densify(&pts, 3.);
+        // the first segment is split into 4 parts of length 2.5
+        assert_eq!(6, densified.len());
+        assert!(densified[0].equals_3d(&pts[0]));
+        assert!(densified[2].equals_3d(&Coordinate::new_xyz(5., 0., 5.)));
+        assert!(densified[4].equals_3d(&pts[1]));
+        assert!(densified[5].equals_3d(&pts[2]));
+        for i in 1..densified.len() {
+            assert!(densified[i - 1].distance(&densified[i]) <= 3.);
+        }
+
+        let short = CoordinateArrays::densify(&pts, 10.);
+        assert_eq!(3, short.len());
+        for i in 0..pts.len() {
+            assert!(short[i].equals_3d(&pts[i]));
+        }
+
+        assert!(CoordinateArrays::densify(&vec![], 1.).is_empty());
+        assert_eq!(1, CoordinateArrays::densify(&pts[..1].to_vec(), 1.).len());
+    }
 }
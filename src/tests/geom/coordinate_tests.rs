@@ -141,6 +141,28 @@ mod coordinate_tests {
         assert_eq!(distance, 229.128784747792);
     }
 
+    #[test]
+    fn test_distance_3d_missing_z() {
+        let coord1 = Coordinate::new_xyz(0.0, 0.0, 10.0);
+        let coord2 = Coordinate::new_xy(3.0, 4.0);
+        assert_eq!(5.0, coord1.distance_3d(&coord2));
+        assert_eq!(coord1.distance(&coord2), coord2.distance_3d(&coord1));
+        assert_eq!(25.0, Coordinate::new_xy(0.0, 0.0).distance_3d_squared(&coord2));
+
+        let coord3 = Coordinate::new_xyz(3.0, 4.0, -2.0);
+        assert_eq!(5.0, coord1.distance(&coord3));
+        assert_eq!(13.0, coord1.distance_3d(&coord3));
+        assert_eq!(169.0, coord1.distance_3d_squared(&coord3));
+    }
+
+    #[test]
+    fn test_distance_squared() {
+        let coord1 = Coordinate::new_xyz(1.0, 2.0, 100.0);
+        let coord2 = Coordinate::new_xyz(4.0, 6.0, 0.0);
+        assert_eq!(25.0, coord1.distance_squared(&coord2));
+        assert_eq!(10025.0, coord1.distance_3d_squared(&coord2));
+    }
+
     #[test]
     fn test_coordinatexy() {
         #[allow(unused_assignments)]
@@ -193,4 +215,50 @@ mod coordinate_tests {
         assert!(f64::is_nan(xyzm.m) && f64::is_nan(coord.m));
         assert!(xyzm.equal_in_coordinate_z(&coord, 0.000001));
     }
+
+    #[test]
+    fn test_midpoint() {
+        let coord1 = Coordinate::new_xyz(0.0, 10.0, 4.0);
+        let coord2 = Coordinate::new_xyz(100.0, 200.0, 50.0);
+        let mid = coord1.midpoint(&coord2);
+        assert_eq!(50.0, mid.x);
+        assert_eq!(105.0, mid.y);
+        assert_eq!(27.0, mid.get_z());
+
+        let mid_2d = coord1.midpoint(&Coordinate::new_xy(2.0, 2.0));
+        assert_eq!(1.0, mid_2d.x);
+        assert_eq!(6.0, mid_2d.y);
+        assert!(mid_2d.get_z().is_nan());
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let coord1 = Coordinate::new_xyz(1.0, 2.0, 3.0);
+        let coord2 = Coordinate::new_xyz(11.0, -8.0, 7.0);
+        assert!(coord1.interpolate(&coord2, 0.0).equals_3d(&coord1));
+        assert!(coord1.interpolate(&coord2, 1.0).equals_3d(&coord2));
+        assert!(coord1
+            .interpolate(&coord2, 0.5)
+            .equals_3d(&Coordinate::new_xyz(6.0, -3.0, 5.0)));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(Coordinate::new_xy(1., 2.).is_valid());
+        assert!(Coordinate::new_xyz(1., 2., 3.).is_valid());
+        assert!(!Coordinate::new_xy(f64::NAN, 2.).is_valid());
+        assert!(!Coordinate::new_xy(1., f64::NAN).is_valid());
+        assert!(!Coordinate::new_xy(f64::INFINITY, 2.).is_valid());
+        assert!(!Coordinate::new_xy(1., f64::NEG_INFINITY).is_valid());
+        // Z is not checked
+        assert!(Coordinate::new_xyz(1., 2., f64::INFINITY).is_valid());
+    }
+
+    #[test]
+    fn test_is_valid_3d() {
+        assert!(Coordinate::new_xyz(1., 2., 3.).is_valid_3d());
+        assert!(Coordinate::new_xy(1., 2.).is_valid_3d());
+        assert!(!Coordinate::new_xyz(1., 2., f64::INFINITY).is_valid_3d());
+        assert!(!Coordinate::new_xyz(f64::NAN, 2., 3.).is_valid_3d());
+    }
 }
\ No newline at end of file
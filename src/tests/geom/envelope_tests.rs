@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod envelope_tests {
+    use std::collections::HashSet;
+
     use crate::core::geom::{coordinate::Coordinate, envelope::Envelope};
 
     #[test]
@@ -317,4 +319,165 @@ mod envelope_tests {
         assert!(expected == env1.compare_to_envelope(env2));
         assert!(-expected == env2.compare_to_envelope(env1));
     }
+
+    #[test]
+    fn test_distance_to_coordinate() {
+        let env = Envelope::new_xy(0., 10., 0., 5.);
+        assert_eq!(0., env.distance_to_coordinate(&Coordinate::new_xy(5., 2.)));
+        assert_eq!(0., env.distance_to_coordinate(&Coordinate::new_xy(10., 5.)));
+        assert_eq!(3., env.distance_to_coordinate(&Coordinate::new_xy(5., 8.)));
+        assert_eq!(5., env.distance_to_coordinate(&Coordinate::new_xy(13., 9.)));
+    }
+
+    #[test]
+    fn test_hash_set() {
+        let mut set: HashSet<Envelope> = HashSet::new();
+        set.insert(Envelope::new_xy(0., 10., 0., 5.));
+        set.insert(Envelope::new_xy(10., 0., 5., 0.));
+        assert_eq!(1, set.len());
+        assert!(set.contains(&Envelope::new_xy(0., 10., 0., 5.)));
+
+        set.insert(Envelope::new_xy(0., 10., 0., 6.));
+        assert_eq!(2, set.len());
+
+        // null envelopes are all equal
+        let mut null = Envelope::new_xy(0., 1., 0., 1.);
+        null.set_to_null();
+        set.insert(null);
+        set.insert(Envelope::default());
+        assert_eq!(3, set.len());
+
+        // -0.0 is equal to 0.0
+        assert!(Envelope::new_xy(-0., 1., -0., 1.) == Envelope::new_xy(0., 1., 0., 1.));
+        set.insert(Envelope::new_xy(-0., 10., -0., 5.));
+        assert_eq!(3, set.len());
+    }
+
+    #[test]
+    fn test_buffer() {
+        let env = Envelope::new_xy(0., 10., 0., 5.);
+        assert!(env.buffer(2.) == Envelope::new_xy(-2., 12., -2., 7.));
+        assert!(env.buffer_xy(1., 3.) == Envelope::new_xy(-1., 11., -3., 8.));
+        // the original envelope is unchanged
+        assert!(env == Envelope::new_xy(0., 10., 0., 5.));
+
+        assert!(env.buffer(-2.) == Envelope::new_xy(2., 8., 2., 3.));
+        // a negative buffer which collapses the envelope gives a null envelope
+        assert!(env.buffer(-3.).is_null());
+        assert!(env.buffer_xy(-1., -2.6).is_null());
+
+        let null = Envelope::default();
+        assert!(null.buffer(5.).is_null());
+        assert!(null.buffer_xy(5., 5.).is_null());
+    }
+
+    #[test]
+    fn test_split_quadrants() {
+        let env = Envelope::new_xy(-4., 10., 1., 5.);
+        let quadrants = env.split_quadrants().unwrap();
+        assert!(quadrants[0] == Envelope::new_xy(-4., 3., 1., 3.));
+        assert!(quadrants[3] == Envelope::new_xy(3., 10., 3., 5.));
+
+        let mut union = Envelope::default();
+        for q in &quadrants {
+            assert_eq!(env.get_area() / 4., q.get_area());
+            union.expand_to_include_envelope(q);
+        }
+        assert!(union == env);
+
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                let overlap = quadrants[i].intersection_envelope(&quadrants[j]);
+                assert_eq!(0., overlap.get_area());
+            }
+        }
+
+        assert!(Envelope::default().split_quadrants().is_none());
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut envs = [
+            Envelope::new_xy(1., 2., 0., 1.),
+            Envelope::new_xy(0., 2., 3., 4.),
+            Envelope::default(),
+            Envelope::new_xy(0., 2., 0., 4.),
+            Envelope::new_xy(0., 1., 0., 4.),
+        ];
+        envs.sort();
+        assert!(envs[0].is_null());
+        assert!(envs[1] == Envelope::new_xy(0., 1., 0., 4.));
+        assert!(envs[2] == Envelope::new_xy(0., 2., 0., 4.));
+        assert!(envs[3] == Envelope::new_xy(0., 2., 3., 4.));
+        assert!(envs[4] == Envelope::new_xy(1., 2., 0., 1.));
+        assert!(Envelope::default() < Envelope::new_xy(-10., -9., -10., -9.));
+    }
+
+    #[test]
+    fn test_equals_with_tolerance() {
+        let env = Envelope::new_xy(0.1, 2.3, 0.4, 5.6);
+        let shifted = Envelope::new_xy(0.1 + 1e-12, 2.3, 0.4, 5.6 - 1e-12);
+        assert!(!env.equals(&shifted));
+        assert!(env.equals_with_tolerance(&shifted, 1e-9));
+        assert!(!env.equals_with_tolerance(&shifted, 1e-13));
+        assert!(env.equals_with_tolerance(&env, 0.));
+
+        assert!(Envelope::default().equals_with_tolerance(&Envelope::default(), 1e-9));
+        assert!(!env.equals_with_tolerance(&Envelope::default(), 1e-9));
+        assert!(!Envelope::default().equals_with_tolerance(&env, 1e-9));
+    }
+
+    #[test]
+    fn test_try_intersection() {
+        let env = Envelope::new_xy(0., 10., 0., 10.);
+
+        let overlap = env.try_intersection(&Envelope::new_xy(5., 15., -5., 5.));
+        assert!(overlap.unwrap() == Envelope::new_xy(5., 10., 0., 5.));
+
+        // touching envelopes have a zero-area intersection
+        let touch = env.try_intersection(&Envelope::new_xy(10., 20., 2., 4.)).unwrap();
+        assert!(touch == Envelope::new_xy(10., 10., 2., 4.));
+        assert_eq!(0., touch.get_area());
+
+        assert!(env.try_intersection(&Envelope::new_xy(11., 20., 0., 10.)).is_none());
+        assert!(env.try_intersection(&Envelope::default()).is_none());
+        assert!(Envelope::default().try_intersection(&env).is_none());
+    }
+
+    #[test]
+    fn test_max_distance() {
+        let unit = Envelope::new_xy(0., 1., 0., 1.);
+        let other = Envelope::new_xy(2., 3., 0., 1.);
+        // from (0, 0) to (3, 1)
+        assert_eq!(f64::sqrt(10.), unit.max_distance(&other));
+        assert_eq!(unit.max_distance(&other), other.max_distance(&unit));
+        assert_eq!(f64::sqrt(2.), unit.max_distance(&unit));
+
+        let pairs = [
+            (unit, other),
+            (unit, Envelope::new_xy(0.5, 4., -2., 0.5)),
+            (Envelope::new_xy(-5., -4., 3., 6.), Envelope::new_xy(1., 2., -1., 0.)),
+        ];
+        for (a, b) in pairs {
+            assert!(a.max_distance(&b) >= a.distance_envelope(&b));
+        }
+
+        assert_eq!(0., unit.max_distance(&Envelope::default()));
+        assert_eq!(0., Envelope::default().max_distance(&unit));
+    }
+
+    #[test]
+    fn test_to_square() {
+        let env = Envelope::new_xy(0., 10., 2., 6.);
+        assert_eq!(2.5, env.aspect_ratio());
+        let square = env.to_square();
+        assert!(square.centre().unwrap().equals_2d(&env.centre().unwrap()));
+        assert_eq!(10., square.get_width());
+        assert_eq!(10., square.get_height());
+        assert_eq!(1., square.aspect_ratio());
+        assert!(square.contains_envelope(&env));
+
+        assert!(Envelope::default().to_square().is_null());
+        assert!(Envelope::default().aspect_ratio().is_nan());
+    }
 }
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod geometry_binner_tests {
+    use crate::core::geom::{
+        coordinate::Coordinate, envelope::Envelope, geometry::Geometry,
+        geometry_factory::GeometryFactory, util::geometry_binner::GeometryBinner,
+    };
+
+    fn binner() -> GeometryBinner {
+        return GeometryBinner::new(&Envelope::new_xy(0., 100., 0., 50.), 10, 5);
+    }
+
+    fn point(x: f64, y: f64) -> Geometry {
+        return Geometry::Point(GeometryFactory::create_point_from_coordinate(
+            &Coordinate::new_xy(x, y),
+        ));
+    }
+
+    fn rectangle(min_x: f64, max_x: f64, min_y: f64, max_y: f64) -> Geometry {
+        let coords = vec![
+            Coordinate::new_xy(min_x, min_y),
+            Coordinate::new_xy(min_x, max_y),
+            Coordinate::new_xy(max_x, max_y),
+            Coordinate::new_xy(max_x, min_y),
+            Coordinate::new_xy(min_x, min_y),
+        ];
+        return Geometry::Polygon(GeometryFactory::create_polygon_with_coordinates(&coords));
+    }
+
+    #[test]
+    fn test_point_single_cell() {
+        assert_eq!(vec![(3, 2)], binner().bin(&point(35., 25.)));
+    }
+
+    #[test]
+    fn test_point_on_cell_boundary() {
+        assert_eq!(
+            vec![(3, 1), (3, 2), (4, 1), (4, 2)],
+            binner().bin(&point(40., 20.))
+        );
+    }
+
+    #[test]
+    fn test_polygon_multiple_cells() {
+        let cells = binner().bin(&rectangle(15., 38., 5., 25.));
+        let mut expected = vec![];
+        for col in 1..=3 {
+            for row in 0..=2 {
+                expected.push((col, row));
+            }
+        }
+        assert_eq!(expected, cells);
+    }
+
+    #[test]
+    fn test_outside_extent() {
+        let binner = binner();
+        assert!(binner.bin(&point(150., 25.)).is_empty());
+        // only the part inside the extent is binned
+        assert_eq!(
+            vec![(9, 0), (9, 1)],
+            binner.bin(&rectangle(95., 200., 1., 12.))
+        );
+        assert!(binner
+            .bin(&Geometry::Point(GeometryFactory::create_point()))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_cell_envelope() {
+        let binner = binner();
+        assert!(binner
+            .get_cell_envelope(9, 4)
+            .equals(&Envelope::new_xy(90., 100., 40., 50.)));
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod geometry_collection_tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::core::geom::{
+        coordinate::Coordinate, envelope::Envelope, geometry::Geometry,
+        geometry_collection::GeometryCollection, geometry_factory::GeometryFactory,
+    };
+
+    /**
+     * Creates a collection of random points and short lines.
+     */
+    fn random_collection(rng: &mut StdRng, n: usize) -> GeometryCollection {
+        let mut geometries: Vec<Geometry> = vec![];
+        for i in 0..n {
+            let p = Coordinate::new_xy(rng.gen_range(0.0..1000.0), rng.gen_range(0.0..1000.0));
+            if i % 2 == 0 {
+                geometries.push(Geometry::Point(
+                    GeometryFactory::create_point_from_coordinate(&p),
+                ));
+            } else {
+                let q = Coordinate::new_xy(
+                    p.x + rng.gen_range(-20.0..20.0),
+                    p.y + rng.gen_range(-20.0..20.0),
+                );
+                geometries.push(Geometry::LineString(
+                    GeometryFactory::create_line_string_coordinates(&vec![p, q]),
+                ));
+            }
+        }
+        // an empty member is never found
+        geometries.push(Geometry::Point(GeometryFactory::create_point()));
+        return GeometryCollection::new_with_geometries(&geometries);
+    }
+
+    fn brute_force_query(gc: &GeometryCollection, env: &Envelope) -> Vec<Geometry> {
+        return (0..gc.get_num_geometries())
+            .map(|i| gc.get_geometry_n(i))
+            .filter(|g| g.compute_envelope_internal().intersects_envelope(env))
+            .collect();
+    }
+
+    fn check_query(gc: &GeometryCollection, env: &Envelope) {
+        let expected = brute_force_query(gc, env);
+        let actual = gc.query(env);
+        assert_eq!(expected.len(), actual.len());
+        for (e, a) in expected.iter().zip(actual) {
+            assert!(e.equals_exact(a, 0.));
+        }
+    }
+
+    #[test]
+    fn test_query_with_index() {
+        let mut rng = StdRng::seed_from_u64(2204);
+        let mut gc = random_collection(&mut rng, 2000);
+        let queries = [
+            Envelope::new_xy(100., 250., 300., 420.),
+            Envelope::new_xy(0., 1000., 0., 1000.),
+            Envelope::new_xy(500., 500., 500., 500.),
+            Envelope::new_xy(2000., 3000., 0., 10.),
+        ];
+        for env in &queries {
+            check_query(&gc, env);
+        }
+        gc.build_index();
+        for env in &queries {
+            check_query(&gc, env);
+        }
+        assert!(!gc.query(&queries[0]).is_empty());
+        assert!(gc.query(&queries[3]).is_empty());
+    }
+
+    #[test]
+    fn test_index_invalidated_by_normalize() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut gc = random_collection(&mut rng, 200);
+        gc.build_index();
+        // normalizing reorders the members
+        gc.normalize();
+        check_query(&gc, &Envelope::new_xy(200., 600., 200., 600.));
+
+        let copy = gc.clone();
+        gc.build_index();
+        check_query(&copy, &Envelope::new_xy(0., 300., 700., 900.));
+        check_query(&gc.clone(), &Envelope::new_xy(0., 300., 700., 900.));
+    }
+}
@@ -0,0 +1,688 @@
+#[cfg(test)]
+mod geometry_tests {
+    use crate::core::{
+        algorithm::orientation::Orientation,
+        geom::{
+            coordinate::Coordinate, coordinate_filter::CoordinateFilter, envelope::Envelope,
+            geometry::Geometry, geometry_collection::GeometryCollection,
+            geometry_factory::GeometryFactory, line_string::LineString,
+            multi_line_string::MultiLineString, multi_point::MultiPoint, polygon::Polygon,
+            precision_model::PrecisionModel,
+        },
+    };
+
+    fn coords(xy: &[(f64, f64)]) -> Vec<Coordinate> {
+        return xy.iter().map(|&(x, y)| Coordinate::new_xy(x, y)).collect();
+    }
+
+    fn polygon(shell: &[(f64, f64)], holes: &[&[(f64, f64)]]) -> Polygon {
+        let shell = GeometryFactory::create_linear_ring_with_coordinates(&coords(shell));
+        let holes = holes
+            .iter()
+            .map(|h| GeometryFactory::create_linear_ring_with_coordinates(&coords(h)))
+            .collect();
+        return GeometryFactory::create_polygon_with_linear_ring_vec(&shell, &holes);
+    }
+
+    fn line(xy: &[(f64, f64)]) -> LineString {
+        return GeometryFactory::create_line_string_coordinates(&coords(xy));
+    }
+
+    fn multi_point(xy: &[(f64, f64)]) -> MultiPoint {
+        return GeometryFactory::create_multi_point_with_coordinates(&coords(xy));
+    }
+
+    #[test]
+    fn test_normalize_polygon_winding_and_hole_order() {
+        let hole_a: &[(f64, f64)] = &[(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)];
+        let hole_b: &[(f64, f64)] = &[(6., 6.), (7., 6.), (7., 7.), (6., 7.), (6., 6.)];
+        // clockwise shell starting at (0, 0)
+        let cw = polygon(
+            &[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)],
+            &[hole_a, hole_b],
+        );
+        // counter-clockwise shell starting elsewhere, holes reversed and swapped
+        let ccw = polygon(
+            &[(10., 10.), (0., 10.), (0., 0.), (10., 0.), (10., 10.)],
+            &[hole_b, hole_a],
+        );
+        let a = Geometry::Polygon(cw);
+        let b = Geometry::Polygon(ccw);
+        assert!(!a.equals_exact(&b, 0.));
+        assert!(a.normalized_copy().equals_exact(&b.normalized_copy(), 0.));
+        assert!(a.equals_norm(&b));
+    }
+
+    #[test]
+    fn test_normalized_copy_does_not_mutate() {
+        let g = Geometry::LineString(line(&[(5., 5.), (0., 0.)]));
+        let norm = g.normalized_copy();
+        assert!(g.equals_exact(&Geometry::LineString(line(&[(5., 5.), (0., 0.)])), 0.));
+        assert!(norm.equals_exact(&Geometry::LineString(line(&[(0., 0.), (5., 5.)])), 0.));
+    }
+
+    #[test]
+    fn test_normalize_mixed_collection() {
+        let poly = polygon(&[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)], &[]);
+        let poly_rev = polygon(
+            &[(10., 10.), (10., 0.), (0., 0.), (0., 10.), (10., 10.)],
+            &[],
+        );
+
+        let a = GeometryCollection::new_with_geometries(&vec![
+            Geometry::Polygon(poly),
+            Geometry::LineString(line(&[(3., 3.), (1., 1.)])),
+            Geometry::MultiPoint(multi_point(&[(2., 2.), (1., 1.)])),
+            Geometry::Point(GeometryFactory::create_point_from_coordinate(
+                &Coordinate::new_xy(9., 9.),
+            )),
+        ]);
+        let b = GeometryCollection::new_with_geometries(&vec![
+            Geometry::MultiPoint(multi_point(&[(1., 1.), (2., 2.)])),
+            Geometry::Point(GeometryFactory::create_point_from_coordinate(
+                &Coordinate::new_xy(9., 9.),
+            )),
+            Geometry::LineString(line(&[(1., 1.), (3., 3.)])),
+            Geometry::Polygon(poly_rev),
+        ]);
+
+        let mut a = Geometry::GeometryCollection(a);
+        let mut b = Geometry::GeometryCollection(b);
+        assert!(!a.equals_exact(&b, 0.));
+        a.normalize();
+        b.normalize();
+        assert!(a.equals_exact(&b, 0.));
+
+        // members are sorted by type code
+        if let Geometry::GeometryCollection(gc) = a {
+            let types: Vec<i32> = (0..gc.get_num_geometries())
+                .map(|i| gc.get_geometry_n(i).get_type_code())
+                .collect();
+            assert_eq!(
+                vec![
+                    Geometry::TYPECODE_POINT,
+                    Geometry::TYPECODE_MULTIPOINT,
+                    Geometry::TYPECODE_LINESTRING,
+                    Geometry::TYPECODE_POLYGON
+                ],
+                types
+            );
+        }
+    }
+
+    #[test]
+    fn test_compare_to_orders_empty_first() {
+        let empty = Geometry::LineString(GeometryFactory::create_line_string());
+        let non_empty = Geometry::LineString(line(&[(0., 0.), (1., 1.)]));
+        assert!(empty.compare_to(&non_empty) < 0);
+        assert!(non_empty.compare_to(&empty) > 0);
+        assert_eq!(0, empty.compare_to(&empty.copy()));
+    }
+
+    #[test]
+    fn test_topology_hash_equal_norm() {
+        let a = Geometry::Polygon(polygon(
+            &[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)],
+            &[&[(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)]],
+        ));
+        let b = Geometry::Polygon(polygon(
+            &[(10., 10.), (10., 0.), (0., 0.), (0., 10.), (10., 10.)],
+            &[&[(2., 2.), (2., 1.), (1., 1.), (1., 2.), (2., 2.)]],
+        ));
+        assert!(a.equals_norm(&b));
+        assert_eq!(a.topology_hash(), b.topology_hash());
+
+        let mp1 = Geometry::MultiPoint(multi_point(&[(3., 4.), (1., 2.)]));
+        let mp2 = Geometry::MultiPoint(multi_point(&[(1., 2.), (3., 4.)]));
+        assert!(mp1.equals_norm(&mp2));
+        assert_eq!(mp1.topology_hash(), mp2.topology_hash());
+    }
+
+    #[test]
+    fn test_topology_hash_distinct() {
+        let a = Geometry::LineString(line(&[(0., 0.), (10., 10.)]));
+        let b = Geometry::LineString(line(&[(0., 0.), (10., 11.)]));
+        let c = Geometry::MultiPoint(multi_point(&[(0., 0.), (10., 10.)]));
+        assert_ne!(a.topology_hash(), b.topology_hash());
+        assert_ne!(a.topology_hash(), c.topology_hash());
+    }
+
+    #[test]
+    fn test_normalize_single_point_ring() {
+        let mut ring = GeometryFactory::create_linear_ring_with_coordinates(&coords(&[(1., 1.)]));
+        Polygon::normalize(&mut ring, true);
+        assert_eq!(1, ring.get_coordinates().len());
+    }
+
+    #[test]
+    fn test_envelope_intersects_members() {
+        // two squares at opposite corners: the overall envelope covers the centre
+        let mut corners = Geometry::MultiPolygon(GeometryFactory::create_multi_polygon(&vec![
+            polygon(&[(0., 0.), (0., 2.), (2., 2.), (2., 0.), (0., 0.)], &[]),
+            polygon(&[(8., 8.), (8., 10.), (10., 10.), (10., 8.), (8., 8.)], &[]),
+        ]));
+        let mut centre = Geometry::Polygon(polygon(
+            &[(4., 4.), (4., 6.), (6., 6.), (6., 4.), (4., 4.)],
+            &[],
+        ));
+        let mut near_corner = Geometry::LineString(line(&[(1., 1.), (3., 3.)]));
+        assert!(!corners.envelope_intersects(&mut centre));
+        assert!(!centre.envelope_intersects(&mut corners));
+        assert!(corners.envelope_intersects(&mut near_corner));
+        assert!(near_corner.envelope_intersects(&mut corners));
+    }
+
+    #[test]
+    fn test_envelope_intersects_collections() {
+        let mut a = Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&vec![
+            Geometry::MultiPoint(multi_point(&[(0., 0.), (10., 0.)])),
+            Geometry::LineString(line(&[(0., 10.), (1., 10.)])),
+        ]));
+        let mut b = Geometry::MultiPoint(multi_point(&[(5., 5.), (10., 10.)]));
+        let mut c = Geometry::MultiPoint(multi_point(&[(5., 5.), (0.5, 10.)]));
+        assert!(!a.envelope_intersects(&mut b));
+        assert!(a.envelope_intersects(&mut c));
+        assert!(c.envelope_intersects(&mut a));
+    }
+
+    #[test]
+    fn test_extract_polygons() {
+        let p1 = polygon(&[(0., 0.), (0., 2.), (2., 2.), (2., 0.), (0., 0.)], &[]);
+        let p2 = polygon(&[(8., 8.), (8., 10.), (10., 10.), (10., 8.), (8., 8.)], &[]);
+        let gc = Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&vec![
+            Geometry::Point(GeometryFactory::create_point_from_coordinate(
+                &Coordinate::new_xy(5., 5.),
+            )),
+            Geometry::LineString(line(&[(0., 10.), (1., 10.)])),
+            Geometry::Polygon(p1.copy()),
+            Geometry::Polygon(p2.copy()),
+        ]));
+        let polygons = gc.extract_polygons();
+        assert_eq!(2, polygons.len());
+        assert!(polygons[0].equals_exact(&p1, 0.));
+        assert!(polygons[1].equals_exact(&p2, 0.));
+    }
+
+    #[test]
+    fn test_extract_nested() {
+        let gc = Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&vec![
+            Geometry::MultiPoint(multi_point(&[(0., 0.), (10., 0.)])),
+            Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&vec![
+                Geometry::LineString(line(&[(0., 10.), (1., 10.)])),
+                Geometry::MultiLineString(MultiLineString::new_with_line_strings(&vec![line(&[
+                    (2., 2.),
+                    (3., 3.),
+                ])])),
+            ])),
+        ]));
+        assert_eq!(2, gc.extract_points().len());
+        assert_eq!(2, gc.extract_line_strings().len());
+        assert!(gc.extract_polygons().is_empty());
+    }
+
+    #[test]
+    fn test_decimate_ring() {
+        let n = 1000;
+        let mut shell: Vec<(f64, f64)> = (0..n)
+            .map(|i| {
+                let angle = 2. * std::f64::consts::PI * i as f64 / n as f64;
+                (100. * angle.cos(), 100. * angle.sin())
+            })
+            .collect();
+        shell.push(shell[0]);
+        let circle = Geometry::Polygon(polygon(&shell, &[]));
+
+        let decimated = match circle.decimate_to(50) {
+            Geometry::Polygon(p) => p,
+            _ => panic!("expected a Polygon"),
+        };
+        let ring = decimated.get_exterior_ring().get_coordinates();
+        assert_eq!(50, ring.len());
+        assert!(ring[0].equals_2d(&ring[ring.len() - 1]));
+        assert!(ring[0].equals_2d(&Coordinate::new_xy(100., 0.)));
+        // the sampled vertices are spread around the whole circle
+        assert!(decimated.get_area() > 0.99 * circle.extract_polygons()[0].get_area());
+    }
+
+    #[test]
+    fn test_decimate_line() {
+        let xy: Vec<(f64, f64)> = (0..=100).map(|i| (i as f64, (i % 7) as f64)).collect();
+        let decimated = Geometry::LineString(line(&xy)).decimate_to(11);
+        let coords = decimated.extract_line_strings()[0].get_coordinates();
+        assert_eq!(11, coords.len());
+        assert!(coords[0].equals_2d(&Coordinate::new_xy(0., 0.)));
+        assert!(coords[10].equals_2d(&Coordinate::new_xy(100., 2.)));
+    }
+
+    #[test]
+    fn test_decimate_small_unchanged() {
+        let gc = Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&vec![
+            Geometry::Polygon(polygon(
+                &[(0., 0.), (0., 2.), (2., 2.), (2., 0.), (0., 0.)],
+                &[],
+            )),
+            Geometry::LineString(line(&[(0., 10.), (1., 10.), (2., 11.)])),
+            Geometry::MultiPoint(multi_point(&[(0., 0.), (10., 0.), (5., 5.)])),
+        ]));
+        assert!(gc.decimate_to(5).equals_exact(&gc, 0.));
+
+        // a ring keeps at least 4 points, and a line its endpoints
+        let decimated = gc.decimate_to(1);
+        let ring = decimated.extract_polygons()[0]
+            .get_exterior_ring()
+            .get_coordinates();
+        assert_eq!(4, ring.len());
+        assert!(ring[0].equals_2d(&ring[3]));
+        let line_coords = decimated.extract_line_strings()[0].get_coordinates();
+        assert_eq!(2, line_coords.len());
+        assert!(line_coords[1].equals_2d(&Coordinate::new_xy(2., 11.)));
+    }
+
+    #[test]
+    fn test_measures_of_empty_geometries() {
+        let empties = [
+            Geometry::Point(GeometryFactory::create_point()),
+            Geometry::LineString(GeometryFactory::create_line_string()),
+            Geometry::LinearRing(GeometryFactory::create_linear_ring()),
+            Geometry::Polygon(GeometryFactory::create_polygon_with_linear_ring(
+                &GeometryFactory::create_linear_ring(),
+            )),
+            Geometry::MultiPoint(multi_point(&[])),
+            Geometry::MultiLineString(MultiLineString::new_with_line_strings(&vec![])),
+            Geometry::MultiPolygon(GeometryFactory::create_multi_polygon(&vec![])),
+            Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&vec![])),
+        ];
+        for geom in &empties {
+            assert!(geom.is_empty());
+            assert_eq!(0.0, geom.get_area());
+            assert_eq!(0.0, geom.get_length());
+            assert!(geom.get_centroid().is_empty());
+            let env = geom.compute_envelope_internal();
+            assert!(env.is_null());
+            assert_eq!(0.0, env.get_area());
+            assert_eq!(0.0, env.distance_to_coordinate(&Coordinate::new_xy(1., 1.)));
+        }
+    }
+
+    #[test]
+    fn test_measures() {
+        let square = polygon(
+            &[(0., 0.), (0., 4.), (4., 4.), (4., 0.), (0., 0.)],
+            &[&[(1., 1.), (2., 1.), (2., 2.), (1., 2.), (1., 1.)]],
+        );
+        let gc = Geometry::GeometryCollection(GeometryCollection::new_with_geometries(&vec![
+            Geometry::Polygon(square),
+            Geometry::LineString(line(&[(10., 0.), (13., 4.)])),
+            Geometry::MultiPoint(multi_point(&[(20., 20.)])),
+            Geometry::LineString(GeometryFactory::create_line_string()),
+        ]));
+        assert_eq!(15.0, gc.get_area());
+        assert_eq!(16.0 + 4.0 + 5.0, gc.get_length());
+
+        // the centroid is determined by the polygonal component
+        let line_centroid = Geometry::LineString(line(&[(10., 0.), (14., 0.)])).get_centroid();
+        assert!(line_centroid
+            .get_coordinate()
+            .unwrap()
+            .equals_2d(&Coordinate::new_xy(12., 0.)));
+        let centroid = gc.get_centroid().get_coordinate().unwrap();
+        let expected = Coordinate::new_xy((16. * 2. - 1.5) / 15., (16. * 2. - 1.5) / 15.);
+        assert!(centroid.distance(&expected) < 1e-12);
+    }
+
+    #[test]
+    fn test_polygon_contains_point() {
+        let poly = polygon(
+            &[(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)],
+            &[&[(4., 4.), (6., 4.), (6., 6.), (4., 6.), (4., 4.)]],
+        );
+        assert!(poly.contains_point(&Coordinate::new_xy(2., 2.)));
+        // points on the shell, inside a hole or on a hole boundary are not contained
+        assert!(!poly.contains_point(&Coordinate::new_xy(10., 5.)));
+        assert!(!poly.contains_point(&Coordinate::new_xy(5., 5.)));
+        assert!(!poly.contains_point(&Coordinate::new_xy(4., 5.)));
+        assert!(!poly.contains_point(&Coordinate::new_xy(11., 5.)));
+        let empty = GeometryFactory::create_polygon_with_linear_ring(
+            &GeometryFactory::create_linear_ring(),
+        );
+        assert!(!empty.contains_point(&Coordinate::new_xy(0., 0.)));
+    }
+
+    #[test]
+    fn test_envelope_to_geometry() {
+        let mut null = Envelope::new_xy(0., 1., 0., 1.);
+        null.set_to_null();
+        match GeometryFactory::envelope_to_geometry(&null) {
+            Geometry::Point(point) => assert!(point.is_empty()),
+            _ => panic!("expected a point"),
+        }
+
+        match GeometryFactory::envelope_to_geometry(&Envelope::new_xy(3., 3., 4., 4.)) {
+            Geometry::Point(point) => assert_eq!(1, point.get_num_points()),
+            _ => panic!("expected a point"),
+        }
+
+        match GeometryFactory::envelope_to_geometry(&Envelope::new_xy(3., 3., 4., 9.)) {
+            Geometry::LineString(line) => {
+                let pts = line.get_coordinates();
+                assert_eq!(2, pts.len());
+                assert!(pts[0].equals_2d(&Coordinate::new_xy(3., 4.)));
+                assert!(pts[1].equals_2d(&Coordinate::new_xy(3., 9.)));
+            }
+            _ => panic!("expected a line string"),
+        }
+
+        match GeometryFactory::envelope_to_geometry(&Envelope::new_xy(0., 10., 0., 5.)) {
+            Geometry::Polygon(polygon) => {
+                let ring = polygon.get_exterior_ring().get_coordinates();
+                assert_eq!(5, ring.len());
+                assert!(ring[0].equals_2d(&ring[4]));
+                assert!(!Orientation::is_ccw_vec(&ring));
+                assert_eq!(50., polygon.get_area());
+            }
+            _ => panic!("expected a polygon"),
+        }
+    }
+
+    #[test]
+    fn test_create_geometry_collection() {
+        let point = Geometry::Point(GeometryFactory::create_point_from_coordinate(
+            &Coordinate::new_xy(1., 1.),
+        ));
+        let line =
+            Geometry::LineString(GeometryFactory::create_line_string_coordinates(&coords(&[
+                (0., 0.),
+                (10., 10.),
+            ])));
+        let collection = GeometryFactory::create_geometry_collection(&vec![point, line]);
+        assert_eq!(2, collection.get_num_geometries());
+        assert!(!collection.is_empty());
+
+        let empty = GeometryFactory::create_geometry_collection_empty();
+        assert_eq!(0, empty.get_num_geometries());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_create_multi_line_string() {
+        let lines = vec![
+            GeometryFactory::create_line_string_coordinates(&coords(&[(0., 0.), (10., 0.)])),
+            GeometryFactory::create_line_string_coordinates(&coords(&[(0., 5.), (10., 5.)])),
+        ];
+        let multi_line = GeometryFactory::create_multi_line_string(&lines);
+        assert_eq!(2, multi_line.get_num_geometries());
+        assert_eq!(20., Geometry::MultiLineString(multi_line).get_length());
+
+        let empty = GeometryFactory::create_multi_line_string_empty();
+        assert_eq!(0, empty.get_num_geometries());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_build_geometry_empty() {
+        let geom = GeometryFactory::build_geometry(vec![]);
+        assert_eq!(
+            Geometry::TYPENAME_GEOMETRYCOLLECTION,
+            geom.get_geometry_type()
+        );
+        assert!(geom.is_empty());
+    }
+
+    #[test]
+    fn test_build_geometry_single() {
+        let square = polygon(&[(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)], &[]);
+        let geom = GeometryFactory::build_geometry(vec![Geometry::Polygon(square.copy())]);
+        assert!(geom.equals_exact(&Geometry::Polygon(square), 0.));
+    }
+
+    #[test]
+    fn test_build_geometry_homogeneous() {
+        let point = |x: f64| {
+            Geometry::Point(GeometryFactory::create_point_from_coordinate(
+                &Coordinate::new_xy(x, 0.),
+            ))
+        };
+        let geom = GeometryFactory::build_geometry(vec![point(1.), point(2.), point(3.)]);
+        assert_eq!(Geometry::TYPENAME_MULTIPOINT, geom.get_geometry_type());
+        assert_eq!(3, geom.extract_points().len());
+
+        let lines = vec![
+            Geometry::LineString(GeometryFactory::create_line_string_coordinates(&coords(&[
+                (0., 0.),
+                (10., 0.),
+            ]))),
+            Geometry::LineString(GeometryFactory::create_line_string_coordinates(&coords(&[
+                (0., 5.),
+                (10., 5.),
+            ]))),
+        ];
+        let geom = GeometryFactory::build_geometry(lines);
+        assert_eq!(Geometry::TYPENAME_MULTILINESTRING, geom.get_geometry_type());
+        assert_eq!(20., geom.get_length());
+
+        let squares = vec![
+            Geometry::Polygon(polygon(
+                &[(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)],
+                &[],
+            )),
+            Geometry::Polygon(polygon(
+                &[(2., 0.), (3., 0.), (3., 1.), (2., 1.), (2., 0.)],
+                &[],
+            )),
+        ];
+        let geom = GeometryFactory::build_geometry(squares);
+        assert_eq!(Geometry::TYPENAME_MULTIPOLYGON, geom.get_geometry_type());
+        assert_eq!(2., geom.get_area());
+    }
+
+    #[test]
+    fn test_build_geometry_heterogeneous() {
+        let point = Geometry::Point(GeometryFactory::create_point_from_coordinate(
+            &Coordinate::new_xy(1., 1.),
+        ));
+        let line =
+            Geometry::LineString(GeometryFactory::create_line_string_coordinates(&coords(&[
+                (0., 0.),
+                (10., 10.),
+            ])));
+        let geom = GeometryFactory::build_geometry(vec![point.copy(), line]);
+        assert_eq!(
+            Geometry::TYPENAME_GEOMETRYCOLLECTION,
+            geom.get_geometry_type()
+        );
+
+        // a multi-geometry member is not flattened
+        let multi_point =
+            Geometry::MultiPoint(GeometryFactory::create_multi_point_from_points(&vec![
+                GeometryFactory::create_point_from_coordinate(&Coordinate::new_xy(2., 2.)),
+            ]));
+        let geom = GeometryFactory::build_geometry(vec![multi_point]);
+        assert_eq!(
+            Geometry::TYPENAME_GEOMETRYCOLLECTION,
+            geom.get_geometry_type()
+        );
+        assert_eq!(1, geom.extract_points().len());
+    }
+
+    #[test]
+    fn test_factory_precision_model() {
+        let factory = GeometryFactory::new(PrecisionModel::new_with_scale(10.), 4326);
+        assert_eq!(4326, factory.get_srid());
+        assert_eq!(10., factory.get_precision_model().get_scale());
+
+        let point = factory.create_precise_point(&Coordinate::new_xy(1.234, -5.678));
+        let pt = point.get_coordinate().unwrap();
+        assert_eq!(1.2, pt.x);
+        assert_eq!(-5.7, pt.y);
+        assert!(point
+            .get_precision_model()
+            .unwrap()
+            .equals(factory.get_precision_model()));
+
+        let line = factory.create_precise_line_string(&coords(&[(0.01, 0.04), (2.96, 3.05)]));
+        let pts = line.get_coordinates();
+        assert!(pts[0].equals_2d(&Coordinate::new_xy(0., 0.)));
+        assert!(pts[1].equals_2d(&Coordinate::new_xy(3., 3.1)));
+        assert!(line
+            .get_precision_model()
+            .unwrap()
+            .equals(factory.get_precision_model()));
+
+        let poly = factory.create_precise_polygon(
+            &coords(&[(0., 0.), (0., 9.96), (9.96, 9.96), (9.96, 0.), (0., 0.)]),
+            &[coords(&[
+                (1.01, 1.01),
+                (2.04, 1.01),
+                (2.04, 2.04),
+                (1.01, 1.01),
+            ])],
+        );
+        assert_eq!(1, poly.get_num_interior_ring());
+        assert!(
+            poly.get_exterior_ring().get_coordinates()[2].equals_2d(&Coordinate::new_xy(10., 10.))
+        );
+        assert!(
+            poly.get_interior_ring_n(0).get_coordinates()[1].equals_2d(&Coordinate::new_xy(2., 1.))
+        );
+
+        // the default factory does not round
+        let point =
+            GeometryFactory::default().create_precise_point(&Coordinate::new_xy(1.234, -5.678));
+        assert_eq!(1.234, point.get_coordinate().unwrap().x);
+        assert_eq!(0, GeometryFactory::default().get_srid());
+    }
+
+    #[test]
+    fn test_reverse_polygon_with_hole() {
+        let mut poly = polygon(
+            &[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)],
+            &[&[(2., 2.), (8., 2.), (8., 8.), (2., 8.), (2., 2.)]],
+        );
+        let env = poly.get_envelope_internal();
+        let mut reversed = poly.reverse();
+        assert_eq!(1, reversed.get_num_interior_ring());
+        assert!(!Orientation::is_ccw_vec(
+            &poly.get_exterior_ring().get_coordinates()
+        ));
+        assert!(Orientation::is_ccw_vec(
+            &reversed.get_exterior_ring().get_coordinates()
+        ));
+        assert!(Orientation::is_ccw_vec(
+            &poly.get_interior_ring_n(0).get_coordinates()
+        ));
+        assert!(!Orientation::is_ccw_vec(
+            &reversed.get_interior_ring_n(0).get_coordinates()
+        ));
+        assert!(env == reversed.get_envelope_internal());
+        assert_eq!(poly.get_area(), reversed.get_area());
+    }
+
+    struct TranslateFilter {
+        dx: f64,
+        dy: f64,
+    }
+
+    impl CoordinateFilter for TranslateFilter {
+        fn filter(&mut self, coord: &mut Coordinate) {
+            coord.x += self.dx;
+            coord.y += self.dy;
+        }
+    }
+
+    #[test]
+    fn test_apply_coordinate_filter() {
+        let mut poly = polygon(
+            &[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)],
+            &[&[(2., 2.), (8., 2.), (8., 8.), (2., 8.), (2., 2.)]],
+        );
+        assert!(poly.get_envelope_internal() == Envelope::new_xy(0., 10., 0., 10.));
+
+        poly.apply_coordinate_filter(&mut TranslateFilter { dx: 5., dy: -1. });
+        assert!(poly.get_envelope_internal() == Envelope::new_xy(5., 15., -1., 9.));
+        assert!(poly
+            .get_interior_ring_n(0)
+            .get_coordinate_at_index(0)
+            .equals_2d(&Coordinate::new_xy(7., 1.)));
+        assert_eq!(64., poly.get_area());
+    }
+
+    #[test]
+    fn test_polygon_interior_point() {
+        // the centroid of a C shape lies outside it
+        let c_shape = polygon(
+            &[
+                (0., 0.),
+                (10., 0.),
+                (10., 2.),
+                (2., 2.),
+                (2., 8.),
+                (10., 8.),
+                (10., 10.),
+                (0., 10.),
+                (0., 0.),
+            ],
+            &[],
+        );
+        let centroid = c_shape.get_centroid().get_coordinate().unwrap();
+        assert!(!c_shape.contains_point(&centroid));
+        let interior = c_shape.get_interior_point().get_coordinate().unwrap();
+        assert!(c_shape.contains_point(&interior));
+        assert!(interior.equals_2d(&Coordinate::new_xy(1., 5.)));
+
+        // the centroid of a square with a central hole lies in the hole
+        let holed = polygon(
+            &[(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)],
+            &[&[(2., 2.), (8., 2.), (8., 8.), (2., 8.), (2., 2.)]],
+        );
+        let interior = holed.get_interior_point().get_coordinate().unwrap();
+        assert!(holed.contains_point(&interior));
+
+        let empty = GeometryFactory::create_polygon_with_linear_ring(
+            &GeometryFactory::create_linear_ring(),
+        );
+        assert!(empty.get_interior_point().is_empty());
+    }
+
+    #[test]
+    fn test_polygon_is_valid() {
+        let shell: &[(f64, f64)] = &[(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)];
+        assert!(polygon(shell, &[]).is_valid());
+        assert!(polygon(shell, &[&[(4., 4.), (6., 4.), (6., 6.), (4., 4.)]]).is_valid());
+        // a hole may touch the shell
+        assert!(polygon(shell, &[&[(0., 5.), (5., 4.), (5., 6.), (0., 5.)]]).is_valid());
+        assert!(GeometryFactory::create_polygon_with_linear_ring(
+            &GeometryFactory::create_linear_ring()
+        )
+        .is_valid());
+
+        assert!(!polygon(&[(0., 0.), (10., 0.), (10., 10.), (0., 10.)], &[]).is_valid());
+        assert!(!polygon(&[(0., 0.), (10., 0.), (0., 0.)], &[]).is_valid());
+        assert!(!polygon(&[(0., 0.), (10., 0.), (10., 0.), (10., 10.), (0., 0.)], &[]).is_valid());
+        assert!(!polygon(shell, &[&[(4., 4.), (6., 4.), (6., 6.)]]).is_valid());
+        assert!(!polygon(shell, &[&[(14., 4.), (16., 4.), (16., 6.), (14., 4.)]]).is_valid());
+        assert!(!polygon(shell, &[&[(8., 4.), (12., 4.), (8., 6.), (8., 4.)]]).is_valid());
+    }
+
+    #[test]
+    fn test_multi_polygon_area_and_length() {
+        let p1 = polygon(
+            &[(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)],
+            &[&[(1., 1.), (2., 1.), (2., 2.), (1., 2.), (1., 1.)]],
+        );
+        let p2 = polygon(&[(10., 0.), (13., 0.), (13., 4.), (10., 0.)], &[]);
+        let mp = GeometryFactory::create_multi_polygon(&vec![p1.clone(), p2.clone()]);
+
+        assert_eq!(2, mp.get_num_geometries());
+        assert_eq!(p1.get_area() + p2.get_area(), mp.get_area());
+        assert_eq!(15. + 6., mp.get_area());
+        assert_eq!(p1.get_length() + p2.get_length(), mp.get_length());
+        assert_eq!(20. + 12., mp.get_length());
+        assert_eq!(
+            Geometry::MultiPolygon(mp.clone()).get_length(),
+            mp.get_length()
+        );
+        assert!(mp
+            .get_geometry_n(1)
+            .equals_exact(&Geometry::Polygon(p2), 0.));
+    }
+}
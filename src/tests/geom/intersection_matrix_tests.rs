@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod intersection_matrix_tests {
-    use crate::core::geom::{dimension::Dimension, intersection_matrix::IntersectionMatrix};
+    use crate::core::geom::{
+        dimension::Dimension, intersection_matrix::IntersectionMatrix, location::Location,
+    };
 
     #[test]
     fn test_to_string() {
@@ -171,4 +173,126 @@ mod intersection_matrix_tests {
                 .is_equals(Dimension::A, Dimension::P)
         );
     }
+
+    #[test]
+    fn test_display() {
+        let i = IntersectionMatrix::new_with_elements("212FF1FF2".to_owned());
+        assert_eq!("IM(212FF1FF2)", format!("IM({})", i));
+    }
+
+    #[test]
+    fn test_matches_predicate() {
+        let matches = |elements: &str, name: &str, dim_a: i32, dim_b: i32| {
+            return IntersectionMatrix::new_with_elements(elements.to_owned())
+                .matches_predicate(name, dim_a, dim_b);
+        };
+        assert!(matches("FF*FF****", "disjoint", Dimension::A, Dimension::A));
+        assert!(matches(
+            "0FFFFFFF2",
+            "intersects",
+            Dimension::P,
+            Dimension::A
+        ));
+        assert!(matches("FT*******", "touches", Dimension::P, Dimension::A));
+        assert!(!matches("FT*******", "touches", Dimension::P, Dimension::P));
+        assert!(matches("TFTFFFFFF", "crosses", Dimension::P, Dimension::L));
+        assert!(!matches("TFTFFFFFF", "crosses", Dimension::L, Dimension::P));
+        assert!(matches("T0F00F000", "within", Dimension::L, Dimension::L));
+        assert!(matches("T00000FF0", "contains", Dimension::L, Dimension::L));
+        assert!(matches("T00000FF0", "covers", Dimension::L, Dimension::L));
+        assert!(matches(
+            "T0F00F000",
+            "coveredBy",
+            Dimension::L,
+            Dimension::L
+        ));
+        assert!(matches("T0FF0FFF0", "equals", Dimension::L, Dimension::L));
+        assert!(!matches("T0FF0FFF0", "equals", Dimension::L, Dimension::A));
+        assert!(matches("T*T***T**", "overlaps", Dimension::P, Dimension::P));
+        assert!(matches("T*T***T**", "OVERLAPS", Dimension::A, Dimension::A));
+        assert!(!matches(
+            "T*T***T**",
+            "overlaps",
+            Dimension::L,
+            Dimension::A
+        ));
+        assert!(!matches("FF*FF****", "unknown", Dimension::A, Dimension::A));
+    }
+
+    #[test]
+    fn test_get_set_location() {
+        let mut m = IntersectionMatrix::new_with_elements("012*TF012".to_owned());
+        assert_eq!(
+            m.get(0, 1),
+            m.get_location(Location::INTERIOR, Location::BOUNDARY)
+        );
+        assert_eq!(
+            Dimension::L,
+            m.get_location(Location::INTERIOR, Location::BOUNDARY)
+        );
+        assert_eq!(
+            Dimension::A,
+            m.get_location(Location::EXTERIOR, Location::EXTERIOR)
+        );
+
+        m.set_location(Location::BOUNDARY, Location::EXTERIOR, Dimension::P);
+        assert_eq!(Dimension::P, m.get(1, 2));
+        assert_eq!("012*T0012", m.to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Location NONE does not address a matrix entry")]
+    fn test_get_location_none() {
+        IntersectionMatrix::default().get_location(Location::NONE, Location::INTERIOR);
+    }
+
+    #[test]
+    fn test_is_equals_strict() {
+        // two identical points
+        let points = IntersectionMatrix::new_with_elements("0FFFFFFF2".to_owned());
+        assert!(points.is_equals(Dimension::P, Dimension::P));
+        assert!(!points.is_equals_strict(Dimension::P, Dimension::P));
+
+        // two identical lines
+        let lines = IntersectionMatrix::new_with_elements("1FFF0FFF2".to_owned());
+        assert!(lines.is_equals(Dimension::L, Dimension::L));
+        assert!(lines.is_equals_strict(Dimension::L, Dimension::L));
+        assert!(!lines.is_equals_strict(Dimension::L, Dimension::A));
+    }
+
+    #[test]
+    fn test_is_valid_pattern() {
+        assert!(IntersectionMatrix::is_valid_pattern("T*F**F***"));
+        assert!(IntersectionMatrix::is_valid_pattern("t*f0122**"));
+        assert!(!IntersectionMatrix::is_valid_pattern("T*F**F**"));
+        assert!(!IntersectionMatrix::is_valid_pattern("T*F**F****"));
+        assert!(!IntersectionMatrix::is_valid_pattern("T*F**X***"));
+        assert!(!IntersectionMatrix::is_valid_pattern("T*F**3***"));
+        assert!(!IntersectionMatrix::is_valid_pattern(""));
+    }
+
+    #[test]
+    fn test_new_from_values() {
+        let values = [
+            [Dimension::A, Dimension::L, Dimension::FALSE],
+            [Dimension::TRUE, Dimension::P, Dimension::DONTCARE],
+            [Dimension::FALSE, Dimension::FALSE, Dimension::A],
+        ];
+        let i = IntersectionMatrix::new_from_values(values);
+        assert_eq!(values, i.to_values());
+        assert_eq!("21FT0*FF2", i.to_string());
+        assert_eq!(
+            Dimension::P,
+            i.get_location(Location::BOUNDARY, Location::BOUNDARY)
+        );
+        assert!(i.equals(&IntersectionMatrix::new_with_elements(
+            "21FT0*FF2".to_owned()
+        )));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown dimension value: 3")]
+    fn test_new_from_values_invalid() {
+        IntersectionMatrix::new_from_values([[Dimension::FALSE, Dimension::FALSE, 3]; 3]);
+    }
 }
@@ -1,6 +1,16 @@
 #[cfg(test)]
 mod line_segment_tests {
-    use crate::core::geom::{coordinate::Coordinate, line_segment::LineSegment};
+    use std::{collections::HashSet, f64::consts::PI};
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::core::{
+        algorithm::{orientation::Orientation, robust_line_intersector::RobustLineIntersector},
+        geom::{
+            coordinate::Coordinate,
+            line_segment::{LineIntersectionResult, LineSegment},
+        },
+    };
 
     const MAX_ABS_ERROR_INTERSECTION: f64 = 1e-5;
 
@@ -147,7 +157,7 @@ mod line_segment_tests {
         expected_y: f64,
     ) {
         let seg = LineSegment::new_from_xy(x0, y0, x1, y1);
-        let p = seg.point_along_offset(seg_frac, offset);
+        let p = seg.point_along_offset(seg_frac, offset).unwrap();
 
         assert!(equals_tolerance(
             &Coordinate::new_xy(expected_x, expected_y),
@@ -168,7 +178,7 @@ mod line_segment_tests {
         expected_y1: f64,
     ) {
         let seg = LineSegment::new_from_xy(x0, y0, x1, y1);
-        let actual = seg.offset(offset);
+        let actual = seg.offset(offset).unwrap();
 
         assert!(equals_tolerance(
             &Coordinate::new_xy(expected_x0, expected_y0),
@@ -265,4 +275,245 @@ mod line_segment_tests {
         let orient = seg.orientation_index_line_segment(&seg2);
         assert_eq!(expected_orient, orient);
     }
+
+    #[test]
+    fn test_buffer() {
+        let seg = LineSegment::new_from_xy(0., 0., 10., 10.);
+        let d = 2.;
+        let buf = seg.buffer(d, 32);
+        let expected_area = 2. * seg.get_length() * d + PI * d * d;
+        assert!(f64::abs(buf.get_area() - expected_area) / expected_area < 1e-3);
+
+        let ring = buf.get_exterior_ring();
+        assert!(ring.is_closed());
+        assert!(Orientation::is_ccw_vec(&ring.get_coordinates()));
+        for pt in ring.get_coordinates() {
+            assert!(f64::abs(seg.distance_coordinate(&pt) - d) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_buffer_zero_length() {
+        let seg = LineSegment::new_from_xy(5., 5., 5., 5.);
+        let d = 3.;
+        let buf = seg.buffer(d, 32);
+        let expected_area = PI * d * d;
+        assert!(f64::abs(buf.get_area() - expected_area) / expected_area < 1e-3);
+        assert!(buf.get_exterior_ring().is_closed());
+        assert_eq!(4 * 32 + 1, buf.get_num_points());
+    }
+
+    #[test]
+    fn test_buffer_non_positive_distance() {
+        let seg = LineSegment::new_from_xy(0., 0., 10., 0.);
+        assert!(seg.buffer(0., 8).is_empty());
+        assert!(seg.buffer(-1., 8).is_empty());
+    }
+
+    #[test]
+    fn test_closest_point_and_fraction() {
+        let seg = LineSegment::new_from_xy(0., 0., 10., 0.);
+
+        let (pt, frac) = seg.closest_point_and_fraction(&Coordinate::new_xy(2.5, 3.));
+        assert!(pt.equals_2d(&Coordinate::new_xy(2.5, 0.)));
+        assert_eq!(0.25, frac);
+
+        let (pt, frac) = seg.closest_point_and_fraction(&Coordinate::new_xy(-5., 1.));
+        assert!(pt.equals_2d(&Coordinate::new_xy(0., 0.)));
+        assert_eq!(0., frac);
+
+        let (pt, frac) = seg.closest_point_and_fraction(&Coordinate::new_xy(12., -1.));
+        assert!(pt.equals_2d(&Coordinate::new_xy(10., 0.)));
+        assert_eq!(1., frac);
+    }
+
+    #[test]
+    fn test_closest_point_and_fraction_matches_closest_point() {
+        let seg = LineSegment::new_from_xy(1., 2., 7., -3.);
+        for p in [
+            Coordinate::new_xy(3., 3.),
+            Coordinate::new_xy(-2., 0.),
+            Coordinate::new_xy(9., -9.),
+        ] {
+            let (pt, frac) = seg.closest_point_and_fraction(&p);
+            assert!(pt.equals_2d(&seg.closest_point(&p)));
+            assert_eq!(seg.segment_fraction(&p), frac);
+        }
+    }
+
+    #[test]
+    fn test_is_point_collinear() {
+        let seg = LineSegment::new_from_xy(0., 0., 10., 10.);
+        assert!(seg.is_point_collinear(&Coordinate::new_xy(5., 5.)));
+        // beyond the segment endpoints, but on its line
+        assert!(seg.is_point_collinear(&Coordinate::new_xy(-3., -3.)));
+        assert!(!seg.is_point_collinear(&Coordinate::new_xy(5., 5. + 1e-12)));
+    }
+
+    #[test]
+    fn test_is_point_collinear_robust() {
+        // a point which is very nearly on the line,
+        // for which the naive determinant is inaccurate
+        let seg = LineSegment::new_from_xy(0.1, 0.3, 1000.7, 3000.1);
+        assert!(!seg.is_point_collinear(&Coordinate::new_xy(500.4, 1500.2)));
+    }
+
+    #[test]
+    fn test_collinear_with() {
+        let seg = LineSegment::new_from_xy(0., 0., 10., 5.);
+        assert!(seg.collinear_with(&LineSegment::new_from_xy(20., 10., 30., 15.)));
+        assert!(seg.collinear_with(&LineSegment::new_from_xy(4., 2., -2., -1.)));
+        assert!(!seg.collinear_with(&LineSegment::new_from_xy(20., 10., 30., 16.)));
+        assert!(!seg.collinear_with(&LineSegment::new_from_xy(0., 1., 10., 6.)));
+    }
+
+    #[test]
+    fn test_to_line_string() {
+        let seg = LineSegment::new_from_xy(1., 2., 4., 6.);
+        let line = seg.to_line_string();
+        assert_eq!(2, line.get_num_points());
+        assert!(line.get_coordinate_n(0).equals_2d(&seg.p0));
+        assert!(line.get_coordinate_n(1).equals_2d(&seg.p1));
+        assert_eq!(5., line.get_length());
+    }
+
+    #[test]
+    fn test_to_string() {
+        let seg = LineSegment::new_from_xy(1., 2.5, -30., 0.125);
+        assert_eq!("LINESTRING (1 2.5, -30 0.125)", seg.to_string());
+    }
+
+    #[test]
+    fn test_hash_set() {
+        let seg = LineSegment::new_from_xy(1., 2., 3., 4.);
+        let mut set: HashSet<LineSegment> = HashSet::new();
+        set.insert(seg);
+        set.insert(LineSegment::new_from_line_segment(&seg));
+        assert_eq!(1, set.len());
+
+        // equality is sensitive to direction
+        let reversed = seg.reversed();
+        assert!(reversed.p0.equals_2d(&seg.p1));
+        assert!(reversed.p1.equals_2d(&seg.p0));
+        assert!(reversed != seg);
+        assert!(reversed.equals_topo(&seg));
+        set.insert(reversed);
+        assert_eq!(2, set.len());
+
+        // negative zero is equal to zero
+        set.insert(LineSegment::new_from_xy(0., 0., 1., 1.));
+        set.insert(LineSegment::new_from_xy(-0., 0., 1., 1.));
+        assert_eq!(3, set.len());
+    }
+
+    #[test]
+    fn test_squared_distances() {
+        let seg = LineSegment::new_from_xy(1., 1., 4., 5.);
+        assert_eq!(25., seg.get_length_squared());
+        assert_eq!(
+            seg.get_length() * seg.get_length(),
+            seg.get_length_squared()
+        );
+
+        let pts = [
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(10., 10.),
+            Coordinate::new_xy(4., 1.),
+            Coordinate::new_xy(2.5, 3.),
+        ];
+        for p in &pts {
+            let dist = seg.distance_coordinate(p);
+            assert!((dist * dist - seg.distance_squared_coordinate(p)).abs() < 1e-9);
+        }
+
+        // zero-length segment
+        let point_seg = LineSegment::new_from_xy(1., 1., 1., 1.);
+        assert_eq!(0., point_seg.get_length_squared());
+        assert_eq!(
+            25.,
+            point_seg.distance_squared_coordinate(&Coordinate::new_xy(4., 5.))
+        );
+    }
+
+    #[test]
+    fn test_is_zero_length() {
+        assert!(LineSegment::new_from_xy(1., 2., 1., 2.).is_zero_length());
+        assert!(!LineSegment::new_from_xy(1., 2., 1., 2.000001).is_zero_length());
+    }
+
+    #[test]
+    fn test_offset_point_zero_length() {
+        let seg = LineSegment::new_from_xy(1., 2., 1., 2.);
+        // a zero offset does not need a direction
+        let p = seg.point_along_offset(0.5, 0.).unwrap();
+        assert!(p.equals_2d(&Coordinate::new_xy(1., 2.)));
+
+        let seg = LineSegment::new_from_xy(0., 0., 0., 4.);
+        let p = seg.point_along_offset(0.5, 1.).unwrap();
+        assert!(p.equals_2d(&Coordinate::new_xy(-1., 2.)));
+    }
+
+    #[test]
+    fn test_offset_point_zero_length_nonzero_offset() {
+        let seg = LineSegment::new_from_xy(1., 2., 1., 2.);
+        assert!(seg.point_along_offset(0.5, 1.).is_none());
+        assert!(seg.offset(1.).is_none());
+        assert!(seg.offset(0.).is_some());
+    }
+
+    #[test]
+    fn test_intersection_detail() {
+        let seg = LineSegment::new_from_xy(0., 0., 10., 10.);
+
+        match seg.intersection_detail(&LineSegment::new_from_xy(0., 10., 10., 0.)) {
+            LineIntersectionResult::Point(p) => assert!(p.equals_2d(&Coordinate::new_xy(5., 5.))),
+            _ => panic!("Expected a point intersection"),
+        }
+
+        match seg.intersection_detail(&LineSegment::new_from_xy(5., 5., 20., 20.)) {
+            LineIntersectionResult::Collinear(p, q) => {
+                let mut pts = [p, q];
+                pts.sort_by(|a, b| a.compare_to(b).cmp(&0));
+                assert!(pts[0].equals_2d(&Coordinate::new_xy(5., 5.)));
+                assert!(pts[1].equals_2d(&Coordinate::new_xy(10., 10.)));
+            }
+            _ => panic!("Expected a collinear intersection"),
+        }
+
+        assert!(matches!(
+            seg.intersection_detail(&LineSegment::new_from_xy(0., 1., 5., 6.)),
+            LineIntersectionResult::None
+        ));
+        assert!(matches!(
+            seg.intersection_detail(&LineSegment::new_from_xy(11., 11., 20., 20.)),
+            LineIntersectionResult::None
+        ));
+    }
+
+    #[test]
+    fn test_intersection_with_reused_intersector() {
+        let mut rng = StdRng::seed_from_u64(2310);
+        let mut seg = || {
+            LineSegment::new_from_xy(
+                rng.gen_range(0.0..10.0),
+                rng.gen_range(0.0..10.0),
+                rng.gen_range(0.0..10.0),
+                rng.gen_range(0.0..10.0),
+            )
+        };
+        let mut li = RobustLineIntersector::default();
+        let mut count = 0;
+        for _ in 0..1000 {
+            let (s1, s2) = (seg(), seg());
+            match (s1.intersection(&s2), s1.intersection_with(&mut li, &s2)) {
+                (Some(p), Some(q)) => {
+                    assert!(p.equals_2d(&q));
+                    count += 1;
+                }
+                (None, None) => {}
+                _ => panic!("Reused intersector gave a different result"),
+            }
+        }
+        assert!(count > 0);
+    }
 }
@@ -3,9 +3,14 @@ mod coordinate_array_sequences_tests;
 mod coordinate_arrays_tests;
 mod coordinate_list_tests;
 mod envelope_tests;
+mod geometry_binner_tests;
+mod geometry_collection_tests;
+mod geometry_tests;
 mod line_segment_tests;
 mod packed_coordinate_sequences_tests;
+mod prepared_line_string_tests;
 mod multi_point_impl_tests;
 mod intersection_matrix_tests;
 mod triangle_tests;
-mod triangle_cercumcentre_tests;
\ No newline at end of file
+mod triangle_cercumcentre_tests;
+mod serde_tests;
\ No newline at end of file
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod prepared_line_string_tests {
+    use crate::core::{
+        algorithm::{
+            point_location::PointLocation, robust_line_intersector::RobustLineIntersector,
+        },
+        geom::{
+            coordinate::Coordinate, geometry::Geometry, geometry_factory::GeometryFactory,
+            line_string::LineString, prepared::prepared_line_string::PreparedLineString,
+        },
+    };
+
+    fn coords(xy: &[(f64, f64)]) -> Vec<Coordinate> {
+        return xy.iter().map(|&(x, y)| Coordinate::new_xy(x, y)).collect();
+    }
+
+    fn line(xy: &[(f64, f64)]) -> LineString {
+        return GeometryFactory::create_line_string_coordinates(&coords(xy));
+    }
+
+    fn zigzag() -> LineString {
+        let xy: Vec<(f64, f64)> = (0..=20)
+            .map(|i| (i as f64, if i % 2 == 0 { 0. } else { 2. }))
+            .collect();
+        return line(&xy);
+    }
+
+    fn naive_intersects(a: &Vec<Coordinate>, b: &Vec<Coordinate>) -> bool {
+        let mut li = RobustLineIntersector::default();
+        for i in 1..a.len() {
+            for j in 1..b.len() {
+                li.compute_intersection_4(&a[i - 1], &a[i], &b[j - 1], &b[j]);
+                if li.has_intersection() {
+                    return true;
+                }
+            }
+        }
+        return false;
+    }
+
+    #[test]
+    fn test_contains_points() {
+        let base = zigzag();
+        let prep = PreparedLineString::new(&base);
+        let base_coords = base.get_coordinates();
+        let mut on_line_count = 0;
+        for i in 0..=40 {
+            for j in 0..=8 {
+                let p = Coordinate::new_xy(i as f64 * 0.5, j as f64 * 0.25);
+                let expected = PointLocation::is_on_line_coordinates(&p, &base_coords);
+                assert_eq!(expected, prep.contains(&p), "point {}", p);
+                if expected {
+                    on_line_count += 1;
+                }
+            }
+        }
+        assert!(on_line_count > 0);
+    }
+
+    #[test]
+    fn test_intersects_lines() {
+        let base = zigzag();
+        let prep = PreparedLineString::new(&base);
+        let base_coords = base.get_coordinates();
+        let mut intersect_count = 0;
+        for i in 0..30 {
+            let x = i as f64 * 0.7 - 1.;
+            let y = (i % 5) as f64 * 0.6 - 0.5;
+            let target = line(&[(x, y), (x + 0.4, y + 0.3), (x + 0.9, y - 0.1)]);
+            let expected = naive_intersects(&base_coords, &target.get_coordinates());
+            assert_eq!(expected, prep.intersects(&Geometry::LineString(target)));
+            if expected {
+                intersect_count += 1;
+            }
+        }
+        assert!(intersect_count > 0 && intersect_count < 30);
+    }
+
+    #[test]
+    fn test_intersects_point_and_polygon() {
+        let prep = PreparedLineString::new(&line(&[(2., 2.), (4., 4.)]));
+        let on_line = GeometryFactory::create_point_from_coordinate(&Coordinate::new_xy(3., 3.));
+        let off_line = GeometryFactory::create_point_from_coordinate(&Coordinate::new_xy(3., 2.));
+        assert!(prep.intersects(&Geometry::Point(on_line)));
+        assert!(!prep.intersects(&Geometry::Point(off_line)));
+
+        // line lies wholly inside the polygon
+        let shell = GeometryFactory::create_linear_ring_with_coordinates(&coords(&[
+            (0., 0.),
+            (0., 10.),
+            (10., 10.),
+            (10., 0.),
+            (0., 0.),
+        ]));
+        let polygon = GeometryFactory::create_polygon_with_linear_ring(&shell);
+        assert!(prep.intersects(&Geometry::Polygon(polygon)));
+
+        // line lies wholly inside a hole
+        let hole = GeometryFactory::create_linear_ring_with_coordinates(&coords(&[
+            (1., 1.),
+            (1., 5.),
+            (5., 5.),
+            (5., 1.),
+            (1., 1.),
+        ]));
+        let with_hole = GeometryFactory::create_polygon_with_linear_ring_vec(&shell, &vec![hole]);
+        assert!(!prep.intersects(&Geometry::Polygon(with_hole)));
+    }
+}
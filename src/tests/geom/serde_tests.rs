@@ -0,0 +1,54 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use crate::core::geom::{
+        coordinate::Coordinate, envelope::Envelope, line_segment::LineSegment,
+    };
+
+    #[test]
+    fn test_coordinate() {
+        let coords = [
+            Coordinate::new_xy(1.5, -2.),
+            Coordinate::new_xyz(1., 2., 3.),
+            Coordinate::new_xyzm(1., 2., 3., 4.),
+        ];
+        for c in coords {
+            let json = serde_json::to_string(&c).unwrap();
+            let actual: Coordinate = serde_json::from_str(&json).unwrap();
+            assert!(c.equals_3d(&actual));
+            assert_eq!(c.get_m().is_nan(), actual.get_m().is_nan());
+        }
+        assert_eq!(
+            r#"{"x":1.5,"y":-2.0,"z":null,"m":null}"#,
+            serde_json::to_string(&coords[0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_envelope() {
+        let envs = [Envelope::new_xy(0., 10., -5., 5.), Envelope::default()];
+        for env in envs {
+            let json = serde_json::to_string(&env).unwrap();
+            let actual: Envelope = serde_json::from_str(&json).unwrap();
+            assert!(env.equals(&actual));
+        }
+        assert_eq!(
+            r#"{"minx":0.0,"maxx":10.0,"miny":-5.0,"maxy":5.0}"#,
+            serde_json::to_string(&envs[0]).unwrap()
+        );
+        let null: Envelope =
+            serde_json::from_str(&serde_json::to_string(&envs[1]).unwrap()).unwrap();
+        assert!(null.is_null());
+    }
+
+    #[test]
+    fn test_line_segment() {
+        let seg = LineSegment::new_from_coordinates(
+            &Coordinate::new_xy(0., 1.),
+            &Coordinate::new_xyz(2., 3., 4.),
+        );
+        let json = serde_json::to_string(&seg).unwrap();
+        let actual: LineSegment = serde_json::from_str(&json).unwrap();
+        assert!(seg.equals(&actual));
+        assert!(seg.p1.equals_3d(&actual.p1));
+    }
+}
@@ -41,4 +41,27 @@ mod triangle_cercumcentre_tests {
         let is_equal = cc1.equals_2d(cc2);
         assert!(is_equal);
     }
+
+    #[test]
+    fn test_instance_circumcentre_dd() {
+        let t = Triangle::new(
+            &Coordinate::new_xy(0., 0.),
+            &Coordinate::new_xy(10., 0.),
+            &Coordinate::new_xy(3., 7.),
+        );
+        assert!(t.circumcentre().distance(&t.circumcentre_dd()) < 1e-12);
+
+        // a sliver triangle, whose circumcentre is far from the vertices
+        let sliver = Triangle::new(
+            &Coordinate::new_xy(1.1, 1.1),
+            &Coordinate::new_xy(2.2, 2.2000001),
+            &Coordinate::new_xy(3.3, 3.3),
+        );
+        // the exact circumcentre, rounded to double precision
+        let expected = Coordinate::new_xy(12100002.219802551, -12099997.81980255);
+        let err = sliver.circumcentre().distance(&expected);
+        let err_dd = sliver.circumcentre_dd().distance(&expected);
+        assert!(err_dd < 1e-6);
+        assert!(err_dd < err);
+    }
 }
@@ -1,8 +1,12 @@
 #[cfg(test)]
 mod triangle_tests {
-    use crate::core::geom::{
-        coordinate::Coordinate, geometry_factory::GeometryFactory, line_string::LineString,
-        point::Point, polygon::Polygon, triangle::Triangle,
+    use crate::core::{
+        algorithm::hcoordinate::HCoordinate,
+        geom::{
+            coordinate::Coordinate, geometry_factory::GeometryFactory, line_string::LineString,
+            point::Point, polygon::Polygon, triangle::Triangle,
+        },
+        test_util::{assert_coordinate_eq, assert_eq_with_tolerance},
     };
 
     const TOLERANCE: f64 = 1E-5;
@@ -28,10 +32,6 @@ mod triangle_tests {
         assert_eq_with_tolerance(expected_value, z, 0.000001);
     }
 
-    fn assert_eq_with_tolerance(a: f64, b: f64, tolerance: f64) {
-        assert!(a >= (b - tolerance) && a <= (b + tolerance))
-    }
-
     #[test]
     fn test_area_3d() {
         let coords1: Vec<Coordinate> = vec![
@@ -271,14 +271,14 @@ mod triangle_tests {
 
         let mut centroid = Triangle::centroid_coordinates(&pt[0], &pt[1], &pt[2]);
         //System.out.println("(Static) centroid = " + centroid);
-        assert_eq!(expected_value.to_string(), centroid.to_string());
+        assert_coordinate_eq(expected_value, &centroid, 0.0);
 
         // Test Instance version
         //
         let t = Triangle::new(&pt[0], &pt[1], &pt[2]);
         centroid = t.centroid();
         //System.out.println("(Instance) centroid = " + centroid.toString());
-        assert_eq!(expected_value.to_string(), centroid.to_string());
+        assert_coordinate_eq(expected_value, &centroid, 0.0);
     }
 
     fn check_circum_centre(polygon: &Polygon, expected_value: &Coordinate) {
@@ -286,14 +286,14 @@ mod triangle_tests {
 
         let mut circumcentre = Triangle::circumcentre_coordinates(&pt[0], &pt[1], &pt[2]);
         //System.out.println("(Static) circumcentre = " + circumcentre);
-        assert_eq!(expected_value.to_string(), circumcentre.to_string());
+        assert_coordinate_eq(expected_value, &circumcentre, 0.0);
 
         // Test Instance version
         //
         let t = Triangle::new(&pt[0], &pt[1], &pt[2]);
         circumcentre = t.circumcentre();
         //System.out.println("(Instance) circumcentre = " + circumcentre.toString());
-        assert_eq!(expected_value.to_string(), circumcentre.to_string());
+        assert_coordinate_eq(expected_value, &circumcentre, 0.0);
     }
 
     fn check_circumradius(polygon: &Polygon) {
@@ -441,4 +441,132 @@ mod triangle_tests {
         let actual = Triangle::intersects_coordinates(&tri[0], &tri[1], &tri[2], &pt.unwrap());
         assert_eq!(expected_value, actual);
     }
+
+    #[test]
+    fn test_circumradius_dd() {
+        let a = Coordinate::new_xy(10., 10.);
+        let b = Coordinate::new_xy(20., 20.);
+        let c = Coordinate::new_xy(30., 10.);
+        let expected = Triangle::circumradius_coordinates(&a, &b, &c);
+        assert_eq_with_tolerance(10., expected, 1e-12);
+        assert_eq_with_tolerance(
+            expected,
+            Triangle::circumradius_dd_coordinates(&a, &b, &c),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_circumradius_dd_sliver() {
+        let a = Coordinate::new_xy(0.1, 0.3);
+        let b = Coordinate::new_xy(1000.7, 3000.1);
+        let c = Coordinate::new_xy(500.4, 1500.2);
+        // computed with exact rational arithmetic
+        let expected = 1.7380269257682567e19;
+        let dd = Triangle::circumradius_dd_coordinates(&a, &b, &c);
+        let naive = Triangle::circumradius_coordinates(&a, &b, &c);
+        assert!(((dd - expected) / expected).abs() < 1e-12);
+        assert!(((naive - expected) / expected).abs() > 0.1);
+    }
+
+    #[test]
+    fn test_circumradius_dd_collinear() {
+        let a = Coordinate::new_xy(0., 0.);
+        let b = Coordinate::new_xy(1., 1.);
+        let c = Coordinate::new_xy(2., 2.);
+        assert_eq!(
+            f64::INFINITY,
+            Triangle::circumradius_dd_coordinates(&a, &b, &c)
+        );
+    }
+
+    #[test]
+    fn test_is_in_circle() {
+        let a = Coordinate::new_xy(0., 0.);
+        let b = Coordinate::new_xy(10., 0.);
+        let c = Coordinate::new_xy(0., 10.);
+        let inside = Coordinate::new_xy(9., 9.);
+        let outside = Coordinate::new_xy(11., 11.);
+        let on_circle = Coordinate::new_xy(10., 10.);
+
+        assert!(Triangle::is_in_circle_coordinates(&a, &b, &c, &inside));
+        assert!(!Triangle::is_in_circle_coordinates(&a, &b, &c, &outside));
+        assert!(!Triangle::is_in_circle_coordinates(&a, &b, &c, &on_circle));
+        // the result does not depend on the triangle orientation
+        assert!(Triangle::is_in_circle_coordinates(&a, &c, &b, &inside));
+        assert!(!Triangle::is_in_circle_coordinates(&a, &c, &b, &outside));
+        assert!(Triangle::new(&c, &b, &a).is_in_circle(&inside));
+    }
+
+    #[test]
+    fn test_is_in_circle_collinear() {
+        let a = Coordinate::new_xy(0., 0.);
+        let b = Coordinate::new_xy(1., 1.);
+        let c = Coordinate::new_xy(2., 2.);
+        let p = Coordinate::new_xy(1., 0.);
+        assert!(!Triangle::is_in_circle_coordinates(&a, &b, &c, &p));
+    }
+
+    #[test]
+    fn test_perpendicular_bisector() {
+        let t = Triangle::new(
+            &Coordinate::new_xy(0., 0.),
+            &Coordinate::new_xy(10., 0.),
+            &Coordinate::new_xy(5., 8.),
+        );
+        // the bisector of the base of an isosceles triangle is its axis of symmetry
+        let bisector = t.perpendicular_bisector(0, 1);
+        let base = HCoordinate::new_with_coordinates(&t.p0, &t.p1);
+        let mid = HCoordinate::new_with_hcoordinates(&bisector, &base).get_coordinate();
+        assert_coordinate_eq(&Coordinate::new_xy(5., 0.), &mid, TOLERANCE);
+        let axis = HCoordinate::new_with_coordinates(&Coordinate::new_xy(5., -1.), &t.p2);
+        // the bisectors meet at the circumcentre
+        let centre = HCoordinate::new_with_hcoordinates(&axis, &t.perpendicular_bisector(2, 0))
+            .get_coordinate();
+        assert_coordinate_eq(&t.circumcentre(), &centre, TOLERANCE);
+    }
+
+    #[test]
+    fn test_angle_bisector() {
+        // the bisector of the apex angle of an isosceles triangle cuts the base at its midpoint
+        let t = Triangle::new(
+            &Coordinate::new_xy(0., 0.),
+            &Coordinate::new_xy(5., 8.),
+            &Coordinate::new_xy(10., 0.),
+        );
+        assert_coordinate_eq(&Coordinate::new_xy(5., 0.), &t.angle_bisector(), TOLERANCE);
+
+        // the cut point divides the opposite side in the ratio of the adjacent sides
+        let t = Triangle::new(
+            &Coordinate::new_xy(0., 3.),
+            &Coordinate::new_xy(0., 0.),
+            &Coordinate::new_xy(4., 0.),
+        );
+        assert_coordinate_eq(
+            &Coordinate::new_xy(4. * 3. / 7., 3. - 3. * 3. / 7.),
+            &t.angle_bisector(),
+            TOLERANCE,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_z_degenerate() {
+        let t = Triangle::new(
+            &Coordinate::new_xyz(0., 0., 0.),
+            &Coordinate::new_xyz(10., 0., 10.),
+            &Coordinate::new_xyz(0., 10., 20.),
+        );
+        assert!(!t.is_degenerate());
+        assert_eq_with_tolerance(8., t.interpolate_z(&Coordinate::new_xy(4., 2.)), TOLERANCE);
+
+        let collinear = Triangle::new(
+            &Coordinate::new_xyz(0., 0., 0.),
+            &Coordinate::new_xyz(5., 5., 10.),
+            &Coordinate::new_xyz(10., 10., 20.),
+        );
+        assert!(collinear.is_degenerate());
+        assert!(f64::is_nan(
+            collinear.interpolate_z(&Coordinate::new_xy(2., 2.))
+        ));
+    }
 }
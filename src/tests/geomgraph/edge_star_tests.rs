@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod edge_star_tests {
+    use crate::core::{
+        geom::coordinate::Coordinate,
+        geomgraph::{edge_end::EdgeEnd, edge_star::EdgeStar},
+    };
+
+    fn edge(x: f64, y: f64) -> EdgeEnd {
+        return EdgeEnd::new(&Coordinate::new_xy(0., 0.), &Coordinate::new_xy(x, y));
+    }
+
+    fn assert_edge(expected: &EdgeEnd, actual: Option<&EdgeEnd>) {
+        assert!(actual.is_some());
+        assert!(expected.equals(actual.unwrap()));
+    }
+
+    #[test]
+    fn test_degree_four_node() {
+        let east = edge(10., 1.);
+        let north = edge(-1., 5.);
+        let west = edge(-3., -0.5);
+        let south = edge(2., -7.);
+
+        let mut star = EdgeStar::new(&Coordinate::new_xy(0., 0.));
+        star.insert(south);
+        star.insert(east);
+        star.insert(west);
+        star.insert(north);
+        assert_eq!(4, star.get_degree());
+
+        assert_edge(&north, star.next_ccw(&east));
+        assert_edge(&west, star.next_ccw(&north));
+        assert_edge(&south, star.next_ccw(&west));
+        assert_edge(&east, star.next_ccw(&south));
+
+        assert_edge(&south, star.next_cw(&east));
+        assert_edge(&east, star.next_cw(&north));
+        assert_edge(&north, star.next_cw(&west));
+        assert_edge(&west, star.next_cw(&south));
+    }
+
+    #[test]
+    fn test_sorted_by_angle() {
+        let mut star = EdgeStar::new(&Coordinate::new_xy(0., 0.));
+        for (x, y) in [(1., 1.), (-1., -1.), (1., -1.), (-1., 1.), (1., 0.)] {
+            star.insert(edge(x, y));
+        }
+        let angles: Vec<f64> = star.get_edges().iter().map(|e| e.get_angle()).collect();
+        for i in 1..angles.len() {
+            assert!(angles[i - 1] <= angles[i]);
+        }
+    }
+
+    #[test]
+    fn test_edge_not_in_star() {
+        let mut star = EdgeStar::new(&Coordinate::new_xy(0., 0.));
+        star.insert(edge(1., 0.));
+        assert!(star.next_cw(&edge(0., 1.)).is_none());
+        assert!(star.next_ccw(&edge(0., 1.)).is_none());
+        // a single edge is its own neighbour
+        assert_edge(&edge(1., 0.), star.next_ccw(&edge(1., 0.)));
+    }
+}
@@ -0,0 +1 @@
+mod edge_star_tests;
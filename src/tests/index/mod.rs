@@ -0,0 +1,2 @@
+mod quadtree_tests;
+mod strtree_tests;
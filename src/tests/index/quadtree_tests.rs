@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod quadtree_tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::core::{
+        geom::{coordinate::Coordinate, envelope::Envelope},
+        index::quadtree::Quadtree,
+    };
+
+    fn random_envelopes(rng: &mut StdRng, n: usize) -> Vec<Envelope> {
+        let mut envs = vec![];
+        for _ in 0..n {
+            let x = rng.gen_range(-100.0..100.0);
+            let y = rng.gen_range(-100.0..100.0);
+            let w = rng.gen_range(0.0..5.0);
+            let h = rng.gen_range(0.0..5.0);
+            envs.push(Envelope::new_xy(x, x + w, y, y + h));
+        }
+        return envs;
+    }
+
+    fn build_tree(envs: &Vec<Envelope>) -> Quadtree<usize> {
+        let mut tree = Quadtree::new();
+        for (i, env) in envs.iter().enumerate() {
+            tree.insert(env, i);
+        }
+        return tree;
+    }
+
+    fn brute_force_distance(envs: &Vec<Envelope>, p: &Coordinate) -> f64 {
+        return envs
+            .iter()
+            .map(|env| env.distance_to_coordinate(p))
+            .fold(f64::INFINITY, f64::min);
+    }
+
+    #[test]
+    fn test_query() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let envs = random_envelopes(&mut rng, 200);
+        let tree = build_tree(&envs);
+        assert_eq!(200, tree.size());
+        assert_eq!(200, tree.query_all().len());
+
+        let search = Envelope::new_xy(-20., 35., 10., 40.);
+        let candidates = tree.query(&search);
+        for (i, env) in envs.iter().enumerate() {
+            if env.intersects_envelope(&search) {
+                assert!(candidates.contains(&&i));
+            }
+        }
+        assert!(candidates.len() < envs.len());
+    }
+
+    #[test]
+    fn test_nearest_random() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let envs = random_envelopes(&mut rng, 300);
+        let tree = build_tree(&envs);
+        for _ in 0..200 {
+            let p = Coordinate::new_xy(rng.gen_range(-120.0..120.0), rng.gen_range(-120.0..120.0));
+            let nearest = *tree.nearest(&p).unwrap();
+            assert_eq!(
+                brute_force_distance(&envs, &p),
+                envs[nearest].distance_to_coordinate(&p)
+            );
+        }
+    }
+
+    #[test]
+    fn test_nearest_ties() {
+        // a grid of unit cells, with points equidistant from several cells
+        let mut envs = vec![];
+        for i in 0..10 {
+            for j in 0..10 {
+                let x = i as f64 * 3.;
+                let y = j as f64 * 3.;
+                envs.push(Envelope::new_xy(x, x + 1., y, y + 1.));
+            }
+        }
+        let tree = build_tree(&envs);
+        for p in [
+            Coordinate::new_xy(2., 2.),
+            Coordinate::new_xy(11., 14.),
+            Coordinate::new_xy(-1., 13.),
+            Coordinate::new_xy(0.5, 0.5),
+        ] {
+            let nearest = *tree.nearest(&p).unwrap();
+            assert_eq!(
+                brute_force_distance(&envs, &p),
+                envs[nearest].distance_to_coordinate(&p)
+            );
+        }
+    }
+
+    #[test]
+    fn test_nearest_empty_and_null() {
+        let mut tree: Quadtree<&str> = Quadtree::new();
+        assert!(tree.nearest(&Coordinate::new_xy(0., 0.)).is_none());
+
+        tree.insert(&Envelope::new_xy(0., 1., 0., 1.), "near");
+        tree.insert(&Envelope::default(), "null");
+        assert_eq!(Some(&"null"), tree.nearest(&Coordinate::new_xy(50., 50.)));
+        assert!(tree
+            .query(&Envelope::new_xy(100., 101., 100., 101.))
+            .contains(&&"null"));
+    }
+
+    #[test]
+    fn test_zero_extent_items() {
+        let mut tree = Quadtree::new();
+        for i in 0..50 {
+            let p = Coordinate::new_xy(i as f64, (i * 7 % 13) as f64);
+            tree.insert(&Envelope::new_coordinate(&p), i);
+        }
+        let nearest = tree.nearest(&Coordinate::new_xy(20.2, 10.)).unwrap();
+        assert_eq!(20, *nearest);
+        assert!(tree
+            .query(&Envelope::new_xy(19.5, 20.5, 9.5, 10.5))
+            .contains(&&20));
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod strtree_tests {
+    use crate::core::{geom::envelope::Envelope, index::strtree::STRtree};
+
+    fn grid_envelopes(n: usize, cell: f64, size: f64, offset: f64) -> Vec<Envelope> {
+        let mut envs = vec![];
+        for i in 0..n {
+            for j in 0..n {
+                let x = offset + i as f64 * cell;
+                let y = offset + j as f64 * cell;
+                envs.push(Envelope::new_xy(x, x + size, y, y + size));
+            }
+        }
+        return envs;
+    }
+
+    fn build_tree(envs: &Vec<Envelope>, node_capacity: usize) -> STRtree<usize> {
+        let mut tree = STRtree::new_with_node_capacity(node_capacity);
+        for (i, env) in envs.iter().enumerate() {
+            tree.insert(env, i);
+        }
+        return tree;
+    }
+
+    #[test]
+    fn test_query() {
+        let envs = grid_envelopes(10, 10., 5., 0.);
+        let tree = build_tree(&envs, 4);
+        assert_eq!(100, tree.size());
+
+        let search = Envelope::new_xy(12., 33., 12., 23.);
+        let mut actual: Vec<usize> = tree.query(&search).into_iter().copied().collect();
+        actual.sort();
+        let expected: Vec<usize> = (0..envs.len())
+            .filter(|&i| envs[i].intersects_envelope(&search))
+            .collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_query_empty_tree() {
+        let tree: STRtree<usize> = STRtree::new();
+        assert!(tree.query(&Envelope::new_xy(0., 1., 0., 1.)).is_empty());
+    }
+
+    #[test]
+    fn test_spatial_join() {
+        let envs1 = grid_envelopes(8, 10., 7., 0.);
+        let envs2 = grid_envelopes(6, 13., 9., 3.5);
+        let tree1 = build_tree(&envs1, 4);
+        let tree2 = build_tree(&envs2, 3);
+
+        let mut actual: Vec<(usize, usize)> = vec![];
+        tree1.spatial_join(&tree2, |a, b| actual.push((*a, *b)));
+        actual.sort();
+
+        let mut expected: Vec<(usize, usize)> = vec![];
+        for (i, env1) in envs1.iter().enumerate() {
+            for (j, env2) in envs2.iter().enumerate() {
+                if env1.intersects_envelope(env2) {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert!(!expected.is_empty());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_spatial_join_disjoint() {
+        let tree1 = build_tree(&grid_envelopes(3, 10., 5., 0.), 4);
+        let tree2 = build_tree(&grid_envelopes(3, 10., 5., 100.), 4);
+        let mut count = 0;
+        tree1.spatial_join(&tree2, |_, _| count += 1);
+        assert_eq!(0, count);
+
+        let empty: STRtree<usize> = STRtree::new();
+        tree1.spatial_join(&empty, |_, _| count += 1);
+        assert_eq!(0, count);
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod geo_json_writer_tests {
+    use crate::core::{
+        algorithm::orientation::Orientation,
+        geom::{coordinate::Coordinate, geometry::Geometry, geometry_factory::GeometryFactory},
+        io::geojson::{geo_json_reader::GeoJsonReader, geo_json_writer::GeoJsonWriter},
+    };
+
+    // shell CCW, hole CW
+    const POLY_RHR: &str = "{\"type\":\"Polygon\",\"coordinates\":[[[0,0],[10,0],[10,10],[0,10],[0,0]],[[2,2],[2,4],[4,4],[4,2],[2,2]]]}";
+    // shell CW, hole CCW
+    const POLY_LHR: &str = "{\"type\":\"Polygon\",\"coordinates\":[[[0,0],[0,10],[10,10],[10,0],[0,0]],[[2,2],[4,2],[4,4],[2,4],[2,2]]]}";
+
+    fn read(json: &str) -> Geometry {
+        return GeoJsonReader::new().read(json).unwrap();
+    }
+
+    fn assert_ring_orientation(geom: &Geometry, is_shell_ccw: bool) {
+        if let Geometry::Polygon(poly) = geom {
+            assert_eq!(
+                is_shell_ccw,
+                Orientation::is_ccw_vec(&poly.get_exterior_ring().get_coordinates())
+            );
+            for i in 0..poly.get_num_interior_ring() {
+                assert_eq!(
+                    !is_shell_ccw,
+                    Orientation::is_ccw_vec(&poly.get_interior_ring_n(i).get_coordinates())
+                );
+            }
+        } else {
+            panic!("expected a polygon");
+        }
+    }
+
+    #[test]
+    fn test_polygon_rhr_unchanged() {
+        let geom = read(POLY_RHR);
+        assert_eq!(POLY_RHR, GeoJsonWriter::new().write(&geom).unwrap());
+    }
+
+    #[test]
+    fn test_polygon_lhr_is_reoriented() {
+        let geom = read(POLY_LHR);
+        let json = GeoJsonWriter::new().write(&geom).unwrap();
+        assert_eq!(
+            "{\"type\":\"Polygon\",\"coordinates\":[[[0,0],[10,0],[10,10],[0,10],[0,0]],[[2,2],[2,4],[4,4],[4,2],[2,2]]]}",
+            json
+        );
+        assert_ring_orientation(&read(&json), true);
+        // the writer does not modify the input geometry
+        assert_ring_orientation(&geom, false);
+    }
+
+    #[test]
+    fn test_force_ccw_disabled() {
+        let geom = read(POLY_LHR);
+        let mut writer = GeoJsonWriter::new();
+        writer.set_force_ccw(false);
+        assert_eq!(POLY_LHR, writer.write(&geom).unwrap());
+    }
+
+    #[test]
+    fn test_collection_round_trip() {
+        let json = "{\"type\":\"GeometryCollection\",\"geometries\":[{\"type\":\"Point\",\"coordinates\":[1.5,2]},{\"type\":\"LineString\",\"coordinates\":[[0,0],[1,1,3]]},{\"type\":\"MultiPolygon\",\"coordinates\":[[[[0,0],[1,0],[1,1],[0,0]]]]}]}";
+        assert_eq!(json, GeoJsonWriter::new().write(&read(json)).unwrap());
+    }
+
+    #[test]
+    fn test_read_invalid() {
+        let reader = GeoJsonReader::new();
+        assert!(reader.read("{\"type\":\"Polygon\"}").is_err());
+        assert!(reader
+            .read("{\"type\":\"Curve\",\"coordinates\":[]}")
+            .is_err());
+        assert!(reader
+            .read("{\"type\":\"Point\",\"coordinates\":[1,2]")
+            .is_err());
+        assert!(reader
+            .read("{\"type\":\"Point\",\"coordinates\":[1,2],\"valid\":t r u e}")
+            .is_err());
+        assert!(reader
+            .read("{\"type\":\"Point\",\"coordinates\":[1,2],\"valid\": true}")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_non_finite_ordinates() {
+        let writer = GeoJsonWriter::new();
+        let point = |coord: Coordinate| {
+            Geometry::Point(GeometryFactory::create_point_from_coordinate(&coord))
+        };
+        assert!(writer
+            .write(&point(Coordinate::new_xy(f64::NAN, 1.)))
+            .is_none());
+        assert!(writer
+            .write(&point(Coordinate::new_xy(1., f64::INFINITY)))
+            .is_none());
+        assert_eq!(
+            "{\"type\":\"Point\",\"coordinates\":[1,2]}",
+            writer
+                .write(&point(Coordinate::new_xyz(1., 2., f64::NEG_INFINITY)))
+                .unwrap()
+        );
+        let line = GeometryFactory::create_line_string_coordinates(&vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(f64::NAN, f64::NAN),
+        ]);
+        assert!(writer.write(&Geometry::LineString(line)).is_none());
+    }
+
+    #[test]
+    fn test_multi_point_with_empty_member_round_trip() {
+        let json = "{\"type\":\"MultiPoint\",\"coordinates\":[[1,2],[]]}";
+        let geom = read(json);
+        assert_eq!(json, GeoJsonWriter::new().write(&geom).unwrap());
+    }
+}
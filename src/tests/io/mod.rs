@@ -0,0 +1,2 @@
+mod geo_json_writer_tests;
+mod svg_writer_tests;
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod svg_writer_tests {
+    use crate::core::{
+        geom::{
+            coordinate::Coordinate, geometry::Geometry, geometry_collection::GeometryCollection,
+            geometry_factory::GeometryFactory,
+        },
+        io::svg_writer::SvgWriter,
+    };
+
+    fn coords(xy: &[(f64, f64)]) -> Vec<Coordinate> {
+        return xy.iter().map(|&(x, y)| Coordinate::new_xy(x, y)).collect();
+    }
+
+    #[test]
+    fn test_triangle() {
+        let triangle = GeometryFactory::create_polygon_with_coordinates(&coords(&[
+            (0., 0.),
+            (10., 0.),
+            (0., 20.),
+            (0., 0.),
+        ]));
+        let svg = SvgWriter::write(&Geometry::Polygon(triangle));
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("viewBox=\"-1 -21 12 22\""));
+        assert!(svg.contains("d=\"M0 0 L10 0 L0 -20 Z\""));
+        assert!(svg.contains("fill-rule=\"evenodd\""));
+    }
+
+    #[test]
+    fn test_polygon_with_hole() {
+        let shell = GeometryFactory::create_linear_ring_with_coordinates(&coords(&[
+            (0., 0.),
+            (0., 10.),
+            (10., 10.),
+            (10., 0.),
+            (0., 0.),
+        ]));
+        let hole = GeometryFactory::create_linear_ring_with_coordinates(&coords(&[
+            (2., 2.),
+            (8., 2.),
+            (8., 8.),
+            (2., 8.),
+            (2., 2.),
+        ]));
+        let polygon = GeometryFactory::create_polygon_with_linear_ring_vec(&shell, &vec![hole]);
+        let svg = SvgWriter::write(&Geometry::Polygon(polygon));
+        assert!(svg.contains("viewBox=\"-0.5 -10.5 11 11\""));
+        assert!(svg.contains("d=\"M0 0 L0 -10 L10 -10 L10 0 Z M2 -2 L8 -2 L8 -8 L2 -8 Z\""));
+        assert_eq!(1, svg.matches("<path").count());
+    }
+
+    #[test]
+    fn test_points_and_lines() {
+        let collection = GeometryCollection::new_with_geometries(&vec![
+            Geometry::Point(GeometryFactory::create_point_from_coordinate(
+                &Coordinate::new_xy(5., 5.),
+            )),
+            Geometry::LineString(GeometryFactory::create_line_string_coordinates(&coords(&[
+                (0., 0.),
+                (10., 10.),
+            ]))),
+        ]);
+        let svg = SvgWriter::write(&Geometry::GeometryCollection(collection));
+        assert!(svg.contains("<circle cx=\"5\" cy=\"-5\" r=\"0.25\""));
+        assert!(svg.contains("d=\"M0 0 L10 -10\" fill=\"none\""));
+    }
+
+    #[test]
+    fn test_single_point() {
+        let point = GeometryFactory::create_point_from_coordinate(&Coordinate::new_xy(3., 4.));
+        let svg = SvgWriter::write(&Geometry::Point(point));
+        assert!(svg.contains("viewBox=\"2 -5 2 2\""));
+        assert!(svg.contains("<circle cx=\"3\" cy=\"-4\" r=\"0.5\""));
+    }
+}
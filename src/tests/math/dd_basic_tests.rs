@@ -287,4 +287,247 @@ mod dd_basic_tests {
         }
         return pow;
     }
+
+    #[test]
+    fn test_to_string_full() {
+        let x = DD::new_hi_lo(1.0, 1.0e-20);
+        let str = x.to_string_full();
+        let num_digits = str.chars().filter(|c| c.is_ascii_digit()).count();
+        assert!(num_digits > 16);
+        assert_eq!("1.00000000000000000001", str);
+
+        let third = DD::value_of_f64(1.0).divide_f64(3.0);
+        assert!(third
+            .to_string_full()
+            .starts_with("0.333333333333333333333333333"));
+
+        assert_eq!("-1234.5", DD::value_of_f64(-1234.5).to_string_full());
+        assert_eq!("1000.0", DD::value_of_f64(1000.0).to_string_full());
+        assert_eq!("0.0", DD::value_of_f64(0.0).to_string_full());
+    }
+
+    #[test]
+    fn test_hypot() {
+        let h = DD::hypot(&DD::value_of_f64(3.0), &DD::value_of_f64(-4.0));
+        assert!(h.equals(&DD::value_of_f64(5.0)));
+
+        for (x, y) in [(1.5, 2.25), (0.1, 0.7), (123.456, 7.89), (-3.3, 9.1e-3)] {
+            let h = DD::hypot(&DD::value_of_f64(x), &DD::value_of_f64(y)).double_value();
+            assert!((h - f64::hypot(x, y)).abs() <= 1e-15 * h);
+        }
+
+        assert!(DD::hypot(&DD::value_of_f64(0.0), &DD::value_of_f64(0.0)).is_zero());
+        assert!(DD::hypot(&DD::value_of_f64(f64::NAN), &DD::value_of_f64(1.0)).is_nan());
+        // no overflow when squaring
+        let big = DD::hypot(&DD::value_of_f64(1e300), &DD::value_of_f64(1e300)).double_value();
+        assert!((big - 1e300 * std::f64::consts::SQRT_2).abs() <= 1e285);
+    }
+
+    #[test]
+    fn test_hypot_large_magnitude_difference() {
+        // sqrt(1 + 1e-18) = 1 + 5e-19 - ..., which is 1 in double precision
+        let x = 1.0;
+        let y = 1.0e-9;
+        assert_eq!(0.0, f64::hypot(x, y) - 1.0);
+        let h = DD::hypot(&DD::value_of_f64(x), &DD::value_of_f64(y));
+        let excess = h.subtract_f64(1.0).double_value();
+        assert!((excess - 5.0e-19).abs() < 1e-30);
+    }
+
+    #[test]
+    fn test_rsqrt() {
+        let r = DD::value_of_f64(4.0).rsqrt();
+        assert!(r.equals(&DD::value_of_f64(0.5)));
+
+        let x = DD::value_of_f64(2.0);
+        // rsqrt(x)^2 * x = 1
+        let err = x
+            .rsqrt()
+            .sqr()
+            .multiply_dd(&x)
+            .subtract_f64(1.0)
+            .double_value();
+        assert!(err.abs() < 1e-30);
+
+        assert!(DD::value_of_f64(-1.0).rsqrt().is_nan());
+        assert_eq!(f64::INFINITY, DD::value_of_f64(0.0).rsqrt().double_value());
+    }
+
+    #[test]
+    fn test_equals_approx() {
+        // values which differ by one ulp of the low-order component
+        let lo = 1.0e-20;
+        let a = DD::new_hi_lo(1.0, lo);
+        let b = DD::new_hi_lo(1.0, f64::from_bits(lo.to_bits() + 1));
+        assert!(!a.equals(&b));
+        assert!(a.equals_approx(&b, &DD::value_of_f64(1.0e-30)));
+        assert!(b.equals_approx(&a, &DD::value_of_f64(1.0e-30)));
+        assert!(a.equals_relative(&b, 1.0e-30));
+
+        // values which differ by one ulp of the high-order component
+        let c = DD::value_of_f64(1.0);
+        let d = DD::value_of_f64(1.0 + f64::EPSILON);
+        assert!(!c.equals(&d));
+        assert!(c.equals_approx(&d, &DD::value_of_f64(f64::EPSILON)));
+        assert!(!c.equals_approx(&d, &DD::value_of_f64(1.0e-30)));
+    }
+
+    #[test]
+    fn test_equals_relative() {
+        let a = DD::value_of_f64(1.0e20);
+        let b = a.add_f64(1.0);
+        assert!(a.equals_relative(&b, 1.0e-19));
+        assert!(!a.equals_relative(&b, 1.0e-21));
+        // an absolute tolerance must be scaled to the values
+        assert!(!a.equals_approx(&b, &DD::value_of_f64(0.5)));
+
+        let nan = DD::new_nan();
+        assert!(!nan.equals_approx(&nan, &DD::value_of_f64(1.0)));
+        assert!(!nan.equals_relative(&a, 1.0));
+    }
+
+    #[test]
+    fn test_to_sci_notation() {
+        assert_eq!("1.5E20", DD::value_of_f64(1.5e20).to_sci_notation());
+        assert_eq!(
+            "-3.0517578125E-5",
+            DD::value_of_f64(-3.0517578125e-5).to_sci_notation()
+        );
+        assert_eq!("1.2345E3", DD::value_of_f64(1234.5).to_sci_notation());
+        assert_eq!("0.0E0", DD::value_of_f64(0.0).to_sci_notation());
+    }
+
+    #[test]
+    fn test_display() {
+        // standard notation is used for magnitudes from 10^-3 to 10^8
+        assert_eq!("1234.5", DD::value_of_f64(1234.5).to_string());
+        assert_eq!("0.001953125", DD::value_of_f64(0.001953125).to_string());
+        assert_eq!("123456789.0", DD::value_of_f64(123456789.).to_string());
+        assert_eq!("1.5E20", DD::value_of_f64(1.5e20).to_string());
+        assert_eq!("9.765625E-4", DD::value_of_f64(0.0009765625).to_string());
+        assert_eq!("0.0", DD::value_of_f64(0.0).to_string());
+        assert_eq!("NaN", DD::new_nan().to_string());
+
+        // all the digits of the extended-precision value are printed
+        let pi = format!("{}", DD::new_pi());
+        assert!(pi.starts_with("3.14159265358979323846264338327"));
+    }
+
+    #[test]
+    fn test_parse() {
+        for str in [
+            "1.5e3",
+            "-0.25",
+            "+12",
+            ".5",
+            "1E-2",
+            "  42",
+            "3.14159265358979323846",
+        ] {
+            let expected: f64 = str.trim().parse().unwrap();
+            assert_eq!(expected, DD::parse(str).unwrap().double_value(), "{}", str);
+        }
+        let dd: DD = "-7.5".parse().unwrap();
+        assert_eq!(-7.5, dd.double_value());
+    }
+
+    #[test]
+    fn test_parse_extended_precision() {
+        // the low-order digits are lost in double precision
+        let x = DD::parse("1.00000000000000000001").unwrap();
+        assert_eq!(1.0, x.double_value());
+        let diff = x.subtract_f64(1.0).double_value();
+        assert!((diff - 1e-20).abs() < 1e-30);
+
+        // printed values can be read back
+        let pi = DD::new_pi();
+        let parsed = DD::parse(&pi.to_string()).unwrap();
+        assert!(parsed.equals_approx(&pi, &DD::value_of_f64(1e-30)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(DD::parse("1.2x").is_err());
+        assert!(DD::parse("1e").is_err());
+        assert!(DD::parse("1e2.5").is_err());
+        assert!(DD::parse("abc").is_err());
+        assert!(DD::parse("").is_err());
+        assert!("-".parse::<DD>().is_err());
+        // the scale of the value overflows
+        assert!(DD::parse("1.5e-2147483647").is_err());
+        assert!(DD::parse("1e-2147483648").is_err());
+    }
+
+    #[test]
+    fn test_renorm() {
+        // the low component is far larger than half an ulp of the high component
+        let mut x = DD::new_hi_lo(1.0, 0.75);
+        assert_eq!(1.0, x.hi());
+        assert_eq!(0.75, x.lo());
+        x.renorm();
+        assert_eq!(1.75, x.hi());
+        assert_eq!(0.0, x.lo());
+        assert_eq!(1.75, x.double_value());
+
+        // a low component below the precision of the high component is preserved
+        let mut y = DD::new_hi_lo(1e-20, 1.0);
+        y.renorm();
+        assert_eq!(1.0, y.hi());
+        assert_eq!(1e-20, y.lo());
+        assert!(y.lo().abs() <= 0.5 * f64::EPSILON * y.hi().abs());
+        let diff = y.subtract_f64(1.0);
+        assert_eq!(1e-20, diff.double_value());
+    }
+
+    #[test]
+    fn test_from_ratio() {
+        let third = DD::from_ratio(1, 3);
+        let err = third
+            .multiply_f64(3.)
+            .subtract_dd(&DD::value_of_f64(1.))
+            .abs();
+        assert!(err.double_value() < 1e-30);
+        // a double cannot represent a third this accurately
+        assert!(third.subtract_dd(&DD::value_of_f64(1. / 3.)).double_value() != 0.);
+
+        assert!(DD::from_ratio(-6, 4).equals(&DD::value_of_f64(-1.5)));
+        assert!(DD::from_ratio(1, 0).is_nan());
+    }
+
+    #[test]
+    fn test_value_of_i64() {
+        assert!(DD::value_of_i64(0).equals(&DD::value_of_f64(0.)));
+        assert!(DD::value_of_i64(-7).equals(&DD::value_of_f64(-7.)));
+        // exceeds the 53-bit precision of a double
+        let big = DD::value_of_i64(i64::MAX);
+        assert!(big
+            .subtract_dd(&DD::value_of_i64(i64::MAX - 1))
+            .equals(&DD::value_of_f64(1.)));
+        assert!(DD::value_of_i64(i64::MIN).equals(&DD::value_of_f64(-9223372036854775808.)));
+    }
+
+    #[test]
+    fn test_partial_ord() {
+        let third = DD::from_ratio(1, 3);
+        let two = DD::value_of_f64(2.);
+        assert!(third < two);
+        assert!(two > third);
+        assert!(third <= third);
+        assert!(third == DD::from_ratio(2, 6));
+        // differs from a third only in the low-order component
+        assert!(DD::value_of_f64(1. / 3.) != third);
+
+        let mut values = [two, DD::value_of_f64(-1.), third, DD::new_pi()];
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!(values[0] == DD::value_of_f64(-1.));
+        assert!(values[1] == third);
+        assert!(values[2] == two);
+        assert!(values[3] == DD::new_pi());
+
+        let nan = DD::create_nan();
+        assert!(nan.partial_cmp(&two).is_none());
+        assert!(two.partial_cmp(&nan).is_none());
+        assert!(nan != nan);
+        assert!(nan.partial_cmp(&nan).is_none());
+    }
 }
@@ -1,3 +1,11 @@
 mod algorithm;
 mod geom;
-mod math;
\ No newline at end of file
+mod geomgraph;
+mod index;
+mod io;
+mod math;
+mod noding;
+mod operation;
+mod simplify;
+mod test_util_tests;
+mod triangulate;
\ No newline at end of file
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod hot_pixel_tests {
+    use crate::core::{geom::coordinate::Coordinate, noding::snapround::hot_pixel::HotPixel};
+
+    fn check_intersects(
+        expected: bool,
+        px: f64,
+        py: f64,
+        scale: f64,
+        x0: f64,
+        y0: f64,
+        x1: f64,
+        y1: f64,
+    ) {
+        let hp = HotPixel::new(&Coordinate::new_xy(px, py), scale);
+        let p0 = Coordinate::new_xy(x0, y0);
+        let p1 = Coordinate::new_xy(x1, y1);
+        assert_eq!(expected, hp.intersects_segment(&p0, &p1));
+    }
+
+    #[test]
+    fn test_below() {
+        check_intersects(false, 1., 1., 100., 1., 0.98, 1.01, 0.98);
+    }
+
+    #[test]
+    fn test_above() {
+        check_intersects(false, 1., 1., 100., 1., 1.98, 1.01, 1.98);
+    }
+
+    #[test]
+    fn test_right_side_vertical_touch_above() {
+        check_intersects(false, 1.2, 1.2, 10., 1.25, 1.25, 1.25, 2.);
+    }
+
+    #[test]
+    fn test_left_side_vertical_touch_above() {
+        check_intersects(false, 1.2, 1.2, 10., 1.15, 1.25, 1.15, 2.);
+    }
+
+    #[test]
+    fn test_top_side_horizontal_touch() {
+        check_intersects(false, 1.2, 1.2, 10., 1.1, 1.25, 1.3, 1.25);
+    }
+
+    #[test]
+    fn test_bottom_side_horizontal_touch() {
+        check_intersects(true, 1.2, 1.2, 10., 1.1, 1.15, 1.3, 1.15);
+    }
+
+    #[test]
+    fn test_diagonal_crossing() {
+        check_intersects(true, 1.2, 1.2, 10., 1.0, 1.0, 1.5, 1.5);
+        check_intersects(false, 1.2, 1.2, 10., 1.0, 1.5, 1.1, 1.6);
+    }
+
+    #[test]
+    fn test_intersects_coordinate() {
+        let hp = HotPixel::new(&Coordinate::new_xy(1.2, 1.2), 10.);
+        assert!(hp.intersects_coordinate(&Coordinate::new_xy(1.15, 1.15)));
+        assert!(hp.intersects_coordinate(&Coordinate::new_xy(1.24, 1.24)));
+        assert!(!hp.intersects_coordinate(&Coordinate::new_xy(1.25, 1.2)));
+        assert!(!hp.intersects_coordinate(&Coordinate::new_xy(1.2, 1.25)));
+    }
+}
@@ -0,0 +1,2 @@
+mod hot_pixel_tests;
+mod snap_rounding_noder_tests;
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod snap_rounding_noder_tests {
+    use crate::core::{
+        algorithm::robust_line_intersector::RobustLineIntersector,
+        geom::{
+            coordinate::Coordinate, line_segment::LineSegment, precision_model::PrecisionModel,
+        },
+        noding::snapround::snap_rounding_noder::SnapRoundingNoder,
+    };
+
+    fn is_on_grid(p: &Coordinate, scale: f64) -> bool {
+        return f64::round(p.x * scale) / scale == p.x && f64::round(p.y * scale) / scale == p.y;
+    }
+
+    fn is_endpoint(p: &Coordinate, seg: &LineSegment) -> bool {
+        return p.equals_2d(&seg.p0) || p.equals_2d(&seg.p1);
+    }
+
+    /**
+     * Checks that all vertices are on the grid and that output segments
+     * only intersect at common endpoints, or are equal.
+     */
+    fn check_noded(noded: &Vec<LineSegment>, scale: f64) {
+        let mut li = RobustLineIntersector::default();
+        for (i, s0) in noded.iter().enumerate() {
+            assert!(is_on_grid(&s0.p0, scale));
+            assert!(is_on_grid(&s0.p1, scale));
+            for s1 in noded.iter().skip(i + 1) {
+                if s0.equals_topo(s1) {
+                    continue;
+                }
+                li.compute_intersection_4(&s0.p0, &s0.p1, &s1.p0, &s1.p1);
+                if li.has_intersection() {
+                    let pt = li.get_intersection(0);
+                    assert!(is_endpoint(&pt, s0) && is_endpoint(&pt, s1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nearly_coincident_crossing() {
+        let scale = 10.;
+        let segs = vec![
+            LineSegment::new_from_xy(0., 0., 10., 0.1),
+            LineSegment::new_from_xy(0., 0.05, 10., 0.),
+        ];
+        let noded =
+            SnapRoundingNoder::new(PrecisionModel::new_with_scale(scale)).compute_nodes(&segs);
+        check_noded(&noded, scale);
+
+        // both lines are split at the rounded intersection point
+        let node = Coordinate::new_xy(3.3, 0.);
+        let count = noded.iter().filter(|s| is_endpoint(&node, s)).count();
+        assert_eq!(4, count);
+        // the second line passes through the hot pixel at the origin,
+        // so its first part collapses onto the first line
+        assert_eq!(5, noded.len());
+        let shared = LineSegment::new_from_xy(0., 0., 3.3, 0.);
+        assert_eq!(2, noded.iter().filter(|s| s.equals_topo(&shared)).count());
+    }
+
+    #[test]
+    fn test_segment_snapped_to_nearby_vertex() {
+        let scale = 1.;
+        // the vertex (5, 1) lies within the pixel of the first segment's path
+        let segs = vec![
+            LineSegment::new_from_xy(0., 0.6, 10., 0.6),
+            LineSegment::new_from_xy(5., 1., 5., 8.),
+        ];
+        let noded =
+            SnapRoundingNoder::new(PrecisionModel::new_with_scale(scale)).compute_nodes(&segs);
+        check_noded(&noded, scale);
+        assert_eq!(3, noded.len());
+        assert!(noded
+            .iter()
+            .any(|s| s.equals_topo(&LineSegment::new_from_xy(1., 1., 5., 1.))
+                || s.equals_topo(&LineSegment::new_from_xy(0., 1., 5., 1.))));
+    }
+
+    #[test]
+    fn test_collapsed_segment_removed() {
+        let segs = vec![LineSegment::new_from_xy(0.01, 0.01, 0.02, 0.03)];
+        let noded = SnapRoundingNoder::new(PrecisionModel::new_with_scale(1.)).compute_nodes(&segs);
+        assert!(noded.is_empty());
+    }
+
+    #[test]
+    fn test_star_of_segments() {
+        let scale = 100.;
+        let mut segs = vec![];
+        for i in 0..12 {
+            let ang = i as f64 * 0.53;
+            segs.push(LineSegment::new_from_xy(
+                f64::cos(ang) * 3.1234,
+                f64::sin(ang) * 3.1234,
+                -f64::cos(ang * 1.7) * 2.987,
+                -f64::sin(ang * 1.3) * 2.987,
+            ));
+        }
+        let noded =
+            SnapRoundingNoder::new(PrecisionModel::new_with_scale(scale)).compute_nodes(&segs);
+        check_noded(&noded, scale);
+        assert!(noded.len() > segs.len());
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod geometry_snapper_tests {
+    use crate::core::{
+        geom::{
+            coordinate::Coordinate, coordinate_arrays::CoordinateArrays, geometry::Geometry,
+            geometry_factory::GeometryFactory, multi_line_string::MultiLineString,
+        },
+        operation::overlay::snap::geometry_snapper::GeometrySnapper,
+    };
+
+    fn coords(xy: &[(f64, f64)]) -> Vec<Coordinate> {
+        return xy.iter().map(|&(x, y)| Coordinate::new_xy(x, y)).collect();
+    }
+
+    fn polygon(shell: &[(f64, f64)]) -> Geometry {
+        return Geometry::Polygon(GeometryFactory::create_polygon_with_coordinates(&coords(
+            shell,
+        )));
+    }
+
+    fn shell_coordinates(geom: &Geometry) -> Vec<Coordinate> {
+        return geom.extract_polygons()[0]
+            .get_exterior_ring()
+            .get_coordinates();
+    }
+
+    #[test]
+    fn test_near_coincident_vertices_merged() {
+        let poly = polygon(&[
+            (0., 0.),
+            (0., 10.),
+            (10., 10.),
+            (10., 1e-12),
+            (10. + 1e-12, 0.),
+            (0., 0.),
+        ]);
+        let snapped = GeometrySnapper::snap_to_self(&poly, 1e-9);
+        let shell = shell_coordinates(&snapped);
+        assert_eq!(5, shell.len());
+        assert!(CoordinateArrays::is_ring(&shell));
+        assert!(!CoordinateArrays::has_repeated_points(&shell));
+        // the first vertex of the pair is kept
+        assert!(shell[3].equals_2d(&Coordinate::new_xy(10., 1e-12)));
+        assert!((snapped.get_area() - 100.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ring_closure_preserved() {
+        // the vertex next to the closing vertex is snapped to it
+        let poly = polygon(&[
+            (0., 0.),
+            (0., 10.),
+            (10., 10.),
+            (10., 0.),
+            (1e-12, 1e-12),
+            (0., 0.),
+        ]);
+        let shell = shell_coordinates(&GeometrySnapper::snap_to_self(&poly, 1e-9));
+        assert_eq!(5, shell.len());
+        assert!(CoordinateArrays::is_ring(&shell));
+        assert!(shell[0].equals_2d(&Coordinate::new_xy(0., 0.)));
+    }
+
+    #[test]
+    fn test_well_separated_unchanged() {
+        let poly = polygon(&[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)]);
+        let snapped = GeometrySnapper::snap_to_self(&poly, 0.1);
+        assert!(snapped.equals_exact(&poly, 0.));
+    }
+
+    #[test]
+    fn test_snap_between_components() {
+        let lines = Geometry::MultiLineString(MultiLineString::new_with_line_strings(&vec![
+            GeometryFactory::create_line_string_coordinates(&coords(&[(0., 0.), (5., 5.)])),
+            GeometryFactory::create_line_string_coordinates(&coords(&[(5.001, 5.), (10., 0.)])),
+            // collapses to a point, so is left unchanged
+            GeometryFactory::create_line_string_coordinates(&coords(&[(0., 0.), (0.001, 0.)])),
+        ]));
+        let snapped = GeometrySnapper::snap_to_self(&lines, 0.01).extract_line_strings();
+        assert!(snapped[1].get_coordinates()[0].equals_2d(&Coordinate::new_xy(5., 5.)));
+        assert_eq!(2, snapped[2].get_num_points());
+    }
+
+    #[test]
+    fn test_collapsed_ring_unchanged() {
+        let tiny = polygon(&[(0., 0.), (0., 0.01), (0.01, 0.01), (0.01, 0.), (0., 0.)]);
+        let snapped = GeometrySnapper::snap_to_self(&tiny, 1.);
+        assert!(snapped.equals_exact(&tiny, 0.));
+    }
+}
@@ -0,0 +1,3 @@
+mod geometry_snapper_tests;
+mod offset_curve_tests;
+mod overlay_op_tests;
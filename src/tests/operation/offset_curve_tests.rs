@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod offset_curve_tests {
+    use crate::core::{
+        geom::{
+            coordinate::Coordinate, geometry_factory::GeometryFactory, line_string::LineString,
+        },
+        operation::buffer::{
+            buffer_parameters::BufferParameters, offset_curve::OffsetCurveBuilder,
+        },
+        test_util::{assert_coordinate_eq, assert_eq_with_tolerance},
+    };
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn line(xy: &[(f64, f64)]) -> LineString {
+        let coords = xy.iter().map(|&(x, y)| Coordinate::new_xy(x, y)).collect();
+        return GeometryFactory::create_line_string_coordinates(&coords);
+    }
+
+    fn params(join_style: i32) -> BufferParameters {
+        let mut params = BufferParameters::default();
+        params.set_join_style(join_style);
+        return params;
+    }
+
+    fn check_curve(actual: &LineString, expected: &[(f64, f64)]) {
+        let pts = actual.get_coordinates();
+        assert_eq!(expected.len(), pts.len());
+        for (pt, &(x, y)) in pts.iter().zip(expected) {
+            assert_coordinate_eq(&Coordinate::new_xy(x, y), pt, TOLERANCE);
+        }
+    }
+
+    /**
+     * A line running east, then turning right to run south.
+     */
+    fn right_angle() -> LineString {
+        return line(&[(0., 0.), (10., 0.), (10., -10.)]);
+    }
+
+    #[test]
+    fn test_mitre_outside_corner() {
+        let curve = OffsetCurveBuilder::offset_curve(
+            &right_angle(),
+            1.,
+            &params(BufferParameters::JOIN_MITRE),
+        );
+        check_curve(&curve, &[(0., 1.), (11., 1.), (11., -10.)]);
+    }
+
+    #[test]
+    fn test_bevel_outside_corner() {
+        let curve = OffsetCurveBuilder::offset_curve(
+            &right_angle(),
+            1.,
+            &params(BufferParameters::JOIN_BEVEL),
+        );
+        check_curve(&curve, &[(0., 1.), (10., 1.), (11., 0.), (11., -10.)]);
+    }
+
+    #[test]
+    fn test_round_outside_corner() {
+        let curve = OffsetCurveBuilder::offset_curve(
+            &right_angle(),
+            1.,
+            &params(BufferParameters::JOIN_ROUND),
+        );
+        let pts = curve.get_coordinates();
+        // the quarter circle is divided into the default number of segments
+        let quad_segs = BufferParameters::DEFAULT_QUADRANT_SEGMENTS as usize;
+        assert_eq!(quad_segs + 3, pts.len());
+        assert_coordinate_eq(&Coordinate::new_xy(0., 1.), &pts[0], TOLERANCE);
+        assert_coordinate_eq(
+            &Coordinate::new_xy(11., -10.),
+            &pts[pts.len() - 1],
+            TOLERANCE,
+        );
+        let corner = Coordinate::new_xy(10., 0.);
+        for pt in &pts[1..(pts.len() - 1)] {
+            assert_eq_with_tolerance(1., pt.distance(&corner), TOLERANCE);
+            // the fillet lies outside the corner
+            assert!(pt.x >= 10. - TOLERANCE && pt.y >= -TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn test_inside_corner() {
+        for join_style in [
+            BufferParameters::JOIN_ROUND,
+            BufferParameters::JOIN_MITRE,
+            BufferParameters::JOIN_BEVEL,
+        ] {
+            let curve = OffsetCurveBuilder::offset_curve(&right_angle(), -1., &params(join_style));
+            check_curve(&curve, &[(0., -1.), (9., -1.), (9., -10.)]);
+        }
+    }
+
+    #[test]
+    fn test_mitre_limit() {
+        // a sharp corner, whose mitre would extend far from the vertex
+        let sharp = line(&[(0., 0.), (10., 0.), (0., -1.)]);
+        let mut params = params(BufferParameters::JOIN_MITRE);
+        params.set_mitre_limit(2.);
+        let curve = OffsetCurveBuilder::offset_curve(&sharp, 1., &params);
+        // bevelled, so the corner has two vertices
+        assert_eq!(4, curve.get_num_points());
+        for pt in curve.get_coordinates() {
+            assert!(pt.x <= 11.);
+        }
+    }
+
+    #[test]
+    fn test_straight_and_zero_distance() {
+        let straight = line(&[(0., 0.), (5., 0.), (5., 0.), (10., 0.)]);
+        let curve = OffsetCurveBuilder::offset_curve(&straight, -2., &BufferParameters::default());
+        check_curve(&curve, &[(0., -2.), (5., -2.), (10., -2.)]);
+
+        let curve = OffsetCurveBuilder::offset_curve(&straight, 0., &BufferParameters::default());
+        assert!(curve.equals_exact(&straight, 0.));
+    }
+}
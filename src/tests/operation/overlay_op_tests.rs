@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod overlay_op_tests {
+    use crate::core::{
+        geom::{
+            coordinate::Coordinate, geometry::Geometry, geometry_factory::GeometryFactory,
+            polygon::Polygon,
+        },
+        operation::overlay::overlay_op::OverlayOp,
+    };
+
+    fn ring(xy: &[(f64, f64)]) -> Vec<Coordinate> {
+        return xy.iter().map(|&(x, y)| Coordinate::new_xy(x, y)).collect();
+    }
+
+    fn polygon(shell: &[(f64, f64)], holes: &[&[(f64, f64)]]) -> Polygon {
+        let shell = GeometryFactory::create_linear_ring_with_coordinates(&ring(shell));
+        let holes = holes
+            .iter()
+            .map(|h| GeometryFactory::create_linear_ring_with_coordinates(&ring(h)))
+            .collect();
+        return GeometryFactory::create_polygon_with_linear_ring_vec(&shell, &holes);
+    }
+
+    fn rectangle(min_x: f64, max_x: f64, min_y: f64, max_y: f64) -> Polygon {
+        return polygon(
+            &[
+                (min_x, min_y),
+                (min_x, max_y),
+                (max_x, max_y),
+                (max_x, min_y),
+                (min_x, min_y),
+            ],
+            &[],
+        );
+    }
+
+    /**
+     * An input pair with the results of
+     * intersection, union, difference and symmetric difference.
+     */
+    struct OverlayCase {
+        a: Geometry,
+        b: Geometry,
+        results: [Geometry; 4],
+    }
+
+    const OPS: [i32; 4] = [
+        OverlayOp::INTERSECTION,
+        OverlayOp::UNION,
+        OverlayOp::DIFFERENCE,
+        OverlayOp::SYMDIFFERENCE,
+    ];
+
+    fn corner_overlap() -> OverlayCase {
+        let diff_ab = polygon(
+            &[
+                (0., 0.),
+                (0., 10.),
+                (5., 10.),
+                (5., 5.),
+                (10., 5.),
+                (10., 0.),
+                (0., 0.),
+            ],
+            &[],
+        );
+        let diff_ba = polygon(
+            &[
+                (5., 10.),
+                (5., 15.),
+                (15., 15.),
+                (15., 5.),
+                (10., 5.),
+                (10., 10.),
+                (5., 10.),
+            ],
+            &[],
+        );
+        return OverlayCase {
+            a: Geometry::Polygon(rectangle(0., 10., 0., 10.)),
+            b: Geometry::Polygon(rectangle(5., 15., 5., 15.)),
+            results: [
+                Geometry::Polygon(rectangle(5., 10., 5., 10.)),
+                Geometry::Polygon(polygon(
+                    &[
+                        (0., 0.),
+                        (0., 10.),
+                        (5., 10.),
+                        (5., 15.),
+                        (15., 15.),
+                        (15., 5.),
+                        (10., 5.),
+                        (10., 0.),
+                        (0., 0.),
+                    ],
+                    &[],
+                )),
+                Geometry::Polygon(diff_ab.copy()),
+                Geometry::MultiPolygon(GeometryFactory::create_multi_polygon(&vec![
+                    diff_ab, diff_ba,
+                ])),
+            ],
+        };
+    }
+
+    fn containment() -> OverlayCase {
+        let outer = [(0., 0.), (0., 20.), (20., 20.), (20., 0.), (0., 0.)];
+        let inner = [(5., 5.), (5., 8.), (9., 8.), (9., 5.), (5., 5.)];
+        return OverlayCase {
+            a: Geometry::Polygon(polygon(&outer, &[])),
+            b: Geometry::Polygon(polygon(&inner, &[])),
+            results: [
+                Geometry::Polygon(polygon(&inner, &[])),
+                Geometry::Polygon(polygon(&outer, &[])),
+                Geometry::Polygon(polygon(&outer, &[&inner])),
+                Geometry::Polygon(polygon(&outer, &[&inner])),
+            ],
+        };
+    }
+
+    fn edge_overlap() -> OverlayCase {
+        return OverlayCase {
+            a: Geometry::Polygon(rectangle(0., 4., 0., 3.)),
+            b: Geometry::Polygon(rectangle(1., 3., 1., 7.)),
+            results: [
+                Geometry::Polygon(rectangle(1., 3., 1., 3.)),
+                Geometry::Polygon(polygon(
+                    &[
+                        (0., 0.),
+                        (0., 3.),
+                        (1., 3.),
+                        (1., 7.),
+                        (3., 7.),
+                        (3., 3.),
+                        (4., 3.),
+                        (4., 0.),
+                        (0., 0.),
+                    ],
+                    &[],
+                )),
+                Geometry::Polygon(polygon(
+                    &[
+                        (0., 0.),
+                        (0., 3.),
+                        (1., 3.),
+                        (1., 1.),
+                        (3., 1.),
+                        (3., 3.),
+                        (4., 3.),
+                        (4., 0.),
+                        (0., 0.),
+                    ],
+                    &[],
+                )),
+                Geometry::MultiPolygon(GeometryFactory::create_multi_polygon(&vec![
+                    polygon(
+                        &[
+                            (0., 0.),
+                            (0., 3.),
+                            (1., 3.),
+                            (1., 1.),
+                            (3., 1.),
+                            (3., 3.),
+                            (4., 3.),
+                            (4., 0.),
+                            (0., 0.),
+                        ],
+                        &[],
+                    ),
+                    rectangle(1., 3., 3., 7.),
+                ])),
+            ],
+        };
+    }
+
+    #[test]
+    fn test_area_invariants() {
+        for case in [corner_overlap(), containment(), edge_overlap()] {
+            for (op, result) in OPS.iter().zip(&case.results) {
+                assert!(OverlayOp::check_area_invariant(
+                    &case.a, &case.b, result, *op
+                ));
+            }
+            let [intersection, union, difference, sym_difference] = &case.results;
+            assert!(OverlayOp::check_area_identities(
+                &case.a,
+                &case.b,
+                intersection,
+                union,
+                difference,
+                sym_difference
+            ));
+        }
+    }
+
+    #[test]
+    fn test_area_invariant_detects_error() {
+        let case = corner_overlap();
+        // the union is not a valid intersection result
+        assert!(!OverlayOp::check_area_invariant(
+            &case.a,
+            &case.b,
+            &case.results[1],
+            OverlayOp::INTERSECTION
+        ));
+        // the intersection is not a valid union result
+        assert!(!OverlayOp::check_area_invariant(
+            &case.a,
+            &case.b,
+            &case.results[0],
+            OverlayOp::UNION
+        ));
+        // a difference which is slightly too small
+        let bad_difference = Geometry::Polygon(rectangle(0., 10., 0., 7.4));
+        assert!(!OverlayOp::check_area_identities(
+            &case.a,
+            &case.b,
+            &case.results[0],
+            &case.results[1],
+            &bad_difference,
+            &case.results[3]
+        ));
+    }
+}
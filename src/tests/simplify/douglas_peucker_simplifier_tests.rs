@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod douglas_peucker_simplifier_tests {
+    use crate::core::{
+        algorithm::area::Area,
+        geom::{
+            coordinate::Coordinate, geometry::Geometry, geometry_factory::GeometryFactory,
+            polygon::Polygon,
+        },
+        simplify::douglas_peucker_simplifier::DouglasPeuckerSimplifier,
+    };
+
+    fn coords(xy: &[(f64, f64)]) -> Vec<Coordinate> {
+        return xy.iter().map(|&(x, y)| Coordinate::new_xy(x, y)).collect();
+    }
+
+    fn polygon(xy: &[(f64, f64)]) -> Polygon {
+        return GeometryFactory::create_polygon_with_coordinates(&coords(xy));
+    }
+
+    fn as_polygon(geom: Geometry) -> Polygon {
+        return match geom {
+            Geometry::Polygon(polygon) => polygon,
+            _ => panic!("Expected a Polygon"),
+        };
+    }
+
+    fn check_valid_ring(ring: &Vec<Coordinate>, min_points: usize) {
+        assert!(ring.len() >= min_points);
+        assert!(ring[0].equals_2d(&ring[ring.len() - 1]));
+        assert!(Area::of_ring_vec(ring) > 0.);
+    }
+
+    #[test]
+    fn test_simplify_line() {
+        let line = GeometryFactory::create_line_string_coordinates(&coords(&[
+            (0., 0.),
+            (5., 0.1),
+            (10., 0.),
+            (15., 5.),
+            (20., 0.),
+        ]));
+        let result = DouglasPeuckerSimplifier::simplify(&Geometry::LineString(line), 1.);
+        let expected = GeometryFactory::create_line_string_coordinates(&coords(&[
+            (0., 0.),
+            (10., 0.),
+            (15., 5.),
+            (20., 0.),
+        ]));
+        assert!(result.equals_exact(&Geometry::LineString(expected), 0.));
+    }
+
+    #[test]
+    fn test_simplify_line_huge_tolerance() {
+        let line = GeometryFactory::create_line_string_coordinates(&coords(&[
+            (0., 0.),
+            (5., 3.),
+            (10., 0.),
+        ]));
+        let result = DouglasPeuckerSimplifier::simplify(&Geometry::LineString(line), 100.);
+        match result {
+            Geometry::LineString(line) => assert_eq!(2, line.get_num_points()),
+            _ => panic!("Expected a LineString"),
+        }
+    }
+
+    #[test]
+    fn test_tiny_triangle_huge_tolerance() {
+        let triangle = polygon(&[(0., 0.), (0.001, 0.), (0., 0.001), (0., 0.)]);
+        let result = as_polygon(DouglasPeuckerSimplifier::simplify(
+            &Geometry::Polygon(triangle),
+            1000.,
+        ));
+        check_valid_ring(&result.get_exterior_ring().get_coordinates(), 4);
+    }
+
+    #[test]
+    fn test_polygon_retains_ring_points() {
+        let square = polygon(&[
+            (0., 0.),
+            (0., 5.),
+            (0., 10.),
+            (5., 10.2),
+            (10., 10.),
+            (10., 5.),
+            (10., 0.),
+            (5., -0.2),
+            (0., 0.),
+        ]);
+        let result = as_polygon(DouglasPeuckerSimplifier::simplify(
+            &Geometry::Polygon(square),
+            50.,
+        ));
+        check_valid_ring(&result.get_exterior_ring().get_coordinates(), 4);
+    }
+
+    #[test]
+    fn test_set_minimum_points() {
+        let xy: Vec<(f64, f64)> = (0..=20)
+            .map(|i| (i as f64, if i % 2 == 0 { 0. } else { 0.5 }))
+            .collect();
+        let line = GeometryFactory::create_line_string_coordinates(&coords(&xy));
+        let mut simplifier = DouglasPeuckerSimplifier::new(&Geometry::LineString(line));
+        simplifier.set_distance_tolerance(10.);
+        simplifier.set_minimum_points(5);
+        match simplifier.get_result_geometry() {
+            Geometry::LineString(line) => assert!(line.get_num_points() >= 5),
+            _ => panic!("Expected a LineString"),
+        }
+    }
+}
@@ -0,0 +1 @@
+mod douglas_peucker_simplifier_tests;
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod test_util_tests {
+    use crate::core::{
+        geom::{
+            coordinate::Coordinate, envelope::Envelope, geometry::Geometry,
+            geometry_factory::GeometryFactory,
+        },
+        test_util::{
+            assert_coordinate_eq, assert_envelope_eq, assert_eq_with_tolerance,
+            assert_geometry_eq_exact,
+        },
+    };
+
+    fn line(coords: &[(f64, f64)]) -> Geometry {
+        let coords: Vec<Coordinate> = coords
+            .iter()
+            .map(|&(x, y)| Coordinate::new_xy(x, y))
+            .collect();
+        return Geometry::LineString(GeometryFactory::create_line_string_coordinates(&coords));
+    }
+
+    #[test]
+    fn test_eq_with_tolerance() {
+        assert_eq_with_tolerance(1., 1.05, 0.1);
+        assert_eq_with_tolerance(f64::NAN, f64::NAN, 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 1 but found 1.2")]
+    fn test_eq_with_tolerance_mismatch() {
+        assert_eq_with_tolerance(1., 1.2, 0.1);
+    }
+
+    #[test]
+    fn test_coordinate_eq() {
+        assert_coordinate_eq(
+            &Coordinate::new_xy(1., 2.),
+            &Coordinate::new_xy(1.01, 1.99),
+            0.02,
+        );
+        assert_coordinate_eq(
+            &Coordinate::new_xyz(1., 2., 3.),
+            &Coordinate::new_xyz(1., 2., 3.),
+            0.,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected coordinate")]
+    fn test_coordinate_eq_mismatch() {
+        assert_coordinate_eq(
+            &Coordinate::new_xy(1., 2.),
+            &Coordinate::new_xy(1., 2.5),
+            0.1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected coordinate")]
+    fn test_coordinate_eq_z_mismatch() {
+        assert_coordinate_eq(
+            &Coordinate::new_xy(1., 2.),
+            &Coordinate::new_xyz(1., 2., 3.),
+            0.1,
+        );
+    }
+
+    #[test]
+    fn test_geometry_eq_exact() {
+        let a = line(&[(0., 0.), (10., 10.)]);
+        let b = line(&[(0., 0.001), (10., 9.999)]);
+        assert_geometry_eq_exact(&a, &a, 0.);
+        assert_geometry_eq_exact(&a, &b, 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected LineString")]
+    fn test_geometry_eq_exact_mismatch() {
+        let a = line(&[(0., 0.), (10., 10.)]);
+        let b = line(&[(0., 0.), (10., 11.)]);
+        assert_geometry_eq_exact(&a, &b, 0.1);
+    }
+
+    #[test]
+    fn test_envelope_eq() {
+        assert_envelope_eq(
+            &Envelope::new_xy(0., 10., 0., 5.),
+            &Envelope::new_xy(0.01, 10., 0., 4.99),
+            0.05,
+        );
+        assert_envelope_eq(&Envelope::default(), &Envelope::default(), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected envelope")]
+    fn test_envelope_eq_mismatch() {
+        assert_envelope_eq(
+            &Envelope::new_xy(0., 10., 0., 5.),
+            &Envelope::new_xy(0., 10., 0., 6.),
+            0.5,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected envelope")]
+    fn test_envelope_eq_null_mismatch() {
+        assert_envelope_eq(&Envelope::default(), &Envelope::new_xy(0., 1., 0., 1.), 0.);
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod delaunay_triangulation_builder_tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::core::{
+        geom::{coordinate::Coordinate, triangle::Triangle},
+        triangulate::delaunay_triangulation_builder::DelaunayTriangulationBuilder,
+    };
+
+    fn check_delaunay(triangles: &[Triangle], sites: &[Coordinate]) {
+        for t in triangles {
+            assert!(t.is_ccw());
+            for p in sites {
+                assert!(
+                    !t.is_in_circle(p),
+                    "site ({}, {}) is inside a circumcircle",
+                    p.x,
+                    p.y
+                );
+            }
+        }
+    }
+
+    fn total_area(triangles: &[Triangle]) -> f64 {
+        return triangles.iter().map(|t| t.area()).sum();
+    }
+
+    #[test]
+    fn test_square() {
+        let sites = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(10., 0.),
+            Coordinate::new_xy(10., 10.),
+            Coordinate::new_xy(0., 10.),
+        ];
+        let triangles = DelaunayTriangulationBuilder::new(&sites).get_triangles();
+        assert_eq!(2, triangles.len());
+        check_delaunay(&triangles, &sites);
+        assert_eq!(100., total_area(&triangles));
+    }
+
+    #[test]
+    fn test_random_sites() {
+        let mut rng = StdRng::seed_from_u64(2208);
+        let mut sites: Vec<Coordinate> = (0..60)
+            .map(|_| Coordinate::new_xy(rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)))
+            .collect();
+        // duplicate sites are ignored
+        sites.extend(sites[..10].to_vec());
+
+        let triangles = DelaunayTriangulationBuilder::new(&sites).get_triangles();
+        check_delaunay(&triangles, &sites);
+        // a triangulation of n sites with h on the hull has 2n - h - 2 triangles
+        assert!(triangles.len() <= 2 * 60 - 5);
+        assert!(triangles.len() >= 60);
+    }
+
+    #[test]
+    fn test_near_collinear() {
+        let mut sites: Vec<Coordinate> = (0..10)
+            .map(|i| Coordinate::new_xy(i as f64, 2. * i as f64))
+            .collect();
+        sites.push(Coordinate::new_xy(5., 10. + 1e-9));
+
+        let triangles = DelaunayTriangulationBuilder::new(&sites).get_triangles();
+        assert_eq!(9, triangles.len());
+        check_delaunay(&triangles, &sites);
+    }
+
+    #[test]
+    fn test_collinear() {
+        let sites: Vec<Coordinate> = (0..5)
+            .map(|i| Coordinate::new_xy(i as f64, i as f64))
+            .collect();
+        assert!(DelaunayTriangulationBuilder::new(&sites)
+            .get_triangles()
+            .is_empty());
+        assert!(DelaunayTriangulationBuilder::new(&sites[..2].to_vec())
+            .get_triangles()
+            .is_empty());
+    }
+}
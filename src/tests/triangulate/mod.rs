@@ -0,0 +1,2 @@
+mod delaunay_triangulation_builder_tests;
+mod voronoi_diagram_builder_tests;
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod voronoi_diagram_builder_tests {
+    use crate::core::{
+        geom::{coordinate::Coordinate, envelope::Envelope, polygon::Polygon},
+        triangulate::voronoi_diagram_builder::VoronoiDiagramBuilder,
+    };
+
+    /**
+     * Checks that each site lies in exactly one cell.
+     */
+    fn check_cells(cells: &[Polygon], sites: &[Coordinate]) {
+        assert_eq!(sites.len(), cells.len());
+        for site in sites {
+            let count = cells.iter().filter(|c| c.contains_point(site)).count();
+            assert_eq!(1, count, "site ({}, {})", site.x, site.y);
+        }
+    }
+
+    fn total_area(cells: &[Polygon]) -> f64 {
+        return cells.iter().map(|c| c.get_area()).sum();
+    }
+
+    #[test]
+    fn test_three_sites() {
+        let sites = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(10., 0.),
+            Coordinate::new_xy(5., 8.),
+        ];
+        let mut builder = VoronoiDiagramBuilder::new(&sites);
+        builder.set_clip_envelope(&Envelope::new_xy(-20., 30., -20., 30.));
+        let cells = builder.get_diagram();
+        check_cells(&cells, &sites);
+        // the unbounded cells are clipped to the box, which they cover
+        assert!((total_area(&cells) - 2500.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_square_corners() {
+        let sites = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(10., 0.),
+            Coordinate::new_xy(10., 10.),
+            Coordinate::new_xy(0., 10.),
+        ];
+        let cells = VoronoiDiagramBuilder::new(&sites).get_diagram();
+        check_cells(&cells, &sites);
+        // the diagram extent is the sites envelope expanded by 10,
+        // split into quadrants at the centre of the square
+        for cell in &cells {
+            assert_eq!(225., cell.get_area());
+        }
+    }
+
+    #[test]
+    fn test_interior_site() {
+        let sites = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(10., 0.),
+            Coordinate::new_xy(10., 10.),
+            Coordinate::new_xy(0., 10.),
+            Coordinate::new_xy(5., 5.),
+        ];
+        let cells = VoronoiDiagramBuilder::new(&sites).get_diagram();
+        check_cells(&cells, &sites);
+        // the centre cell is bounded by the circumcentres of the surrounding triangles
+        let centre = cells
+            .iter()
+            .find(|c| c.contains_point(&Coordinate::new_xy(5., 5.)))
+            .unwrap();
+        assert!((centre.get_area() - 50.).abs() < 1e-9);
+        assert_eq!(5, centre.get_num_points());
+        assert!((total_area(&cells) - 900.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_collinear_sites() {
+        let sites = vec![
+            Coordinate::new_xy(0., 0.),
+            Coordinate::new_xy(5., 0.),
+            Coordinate::new_xy(10., 0.),
+        ];
+        let cells = VoronoiDiagramBuilder::new(&sites).get_diagram();
+        check_cells(&cells, &sites);
+    }
+}